@@ -0,0 +1,4 @@
+//! Rebuild when examples are added or removed, so the days' `#[files("input/2017/examples/...")]` tests see them
+fn main() {
+    println!("cargo:rerun-if-changed=input/2017/examples");
+}
@@ -0,0 +1,6 @@
+use aoc_harness::registry;
+
+fn main() -> std::process::ExitCode {
+    let year = registry!(aoc17);
+    aoc_harness::check::main(&year)
+}
@@ -151,15 +151,19 @@ pub fn part2(input: &str) -> i64 {
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Info2 {
     weight: i64,
-    parent: Option<KeyId>,
-    children: Vec<KeyId>,
+    parent: Option<Symbol>,
+    children: Vec<Symbol>,
     total_weight: i64,
 }
 
-type Tree2<'a> = LookupMap<&'a str, Info2>;
+struct Tree2<'a> {
+    names: Interner<'a>,
+    info: IdVec<Symbol, Info2>,
+}
 
-fn parse_lum<'a>(input: &'a str, build_weights: BuildWeights) -> Tree2<'a> {
-    let mut tree: Tree2 = Tree2::new();
+fn parse_interned(input: &str, build_weights: BuildWeights) -> Tree2<'_> {
+    let mut names = Interner::new();
+    let mut info: IdVec<Symbol, Info2> = IdVec::new();
 
     // Read everyone into the tree first
     // This makes reasoning about children in the next loop easier
@@ -169,105 +173,107 @@ fn parse_lum<'a>(input: &'a str, build_weights: BuildWeights) -> Tree2<'a> {
         let weight = weight.parse().unwrap();
         debug_assert!(weight > 0);
 
-        let info: &mut Info2 = &mut tree.new_entry(name).value;
-        info.weight = weight;
+        let id = names.intern(name);
+        info.insert(
+            id,
+            Info2 {
+                weight,
+                ..Default::default()
+            },
+        );
     }
 
-    for (id, line) in input.lines().enumerate() {
-        let id = KeyId(id);
+    // Every line has a new name, so each line's id is its line number
+    for (id, line) in names.ids().zip(input.lines()) {
         let (name, rest) = line.split_once(" (").unwrap();
-        debug_assert_eq!(tree.entry(id).key, name);
+        debug_assert_eq!(names.resolve(id), name);
 
-        // println!("+ {name}");
         let mut children = vec![];
         if let Some((_, list)) = rest.split_once("->") {
             for child in list.split(",") {
-                let child = child.trim();
-                // println!("    + {child}");
-                let child_entry = tree.insert_or_entry(child);
-                child_entry.value.parent = Some(id);
-
-                children.push(child_entry.id);
+                let child = names.get(child.trim()).unwrap();
+                info[child].parent = Some(id);
+                children.push(child);
             }
         }
 
-        debug_assert!(tree.entry(id).value.children.is_empty());
-        tree.entry(id).value.children = children;
+        debug_assert!(info[id].children.is_empty());
+        info[id].children = children;
     }
 
     if build_weights.0 {
         // The *only* node without a parent is the root.
-        let root = tree
-            .entries()
-            .find(|e| e.value.parent.is_none())
-            .map(|e| e.id)
+        let root = info
+            .iter()
+            .find(|(_id, i)| i.parent.is_none())
+            .map(|(id, _i)| id)
             .unwrap();
 
-        fn build_weights(id: KeyId, tree: &mut Tree2) -> i64 {
-            let info: &Info2 = &tree.entry(id).value;
-            if info.total_weight > 0 {
-                return info.total_weight;
+        fn build_weights(id: Symbol, info: &mut IdVec<Symbol, Info2>) -> i64 {
+            if info[id].total_weight > 0 {
+                return info[id].total_weight;
             }
 
-            let mut total_weight = info.weight;
-            let mut children = info.children.clone();
+            let mut total_weight = info[id].weight;
+            let mut children = info[id].children.clone();
             for &child in &children {
-                total_weight += build_weights(child, tree);
+                total_weight += build_weights(child, info);
             }
 
             // Since the children are now fully weighted, we can sort them to simplify later checks
-            children.sort_by_key(|&c| tree.entry(c).value.total_weight);
+            children.sort_by_key(|&c| info[c].total_weight);
 
-            let info: &mut Info2 = &mut tree.entry(id).value;
-            info.total_weight = total_weight;
-            info.children = children;
+            info[id].total_weight = total_weight;
+            info[id].children = children;
 
             total_weight
         }
 
-        build_weights(root, &mut tree);
+        build_weights(root, &mut info);
     }
 
     if cfg!(debug_assertions) {
-        for entry in tree.entries() {
-            let name = entry.key;
-            let info = &entry.value;
-            let children = info.children.iter().map(|&id| tree.key(id)).collect_vec();
+        for (id, i) in info.iter() {
+            let children = i.children.iter().map(|&c| names.resolve(c)).collect_vec();
             println!(
-                "{name:?} ({}/{}) -> {:?}",
-                info.weight, info.total_weight, children
+                "{:?} ({}/{}) -> {:?}",
+                names.resolve(id),
+                i.weight,
+                i.total_weight,
+                children
             );
         }
     }
 
-    tree
+    Tree2 { names, info }
 }
 
-#[aoc(day7, part1, lum)]
-pub fn part1_lum(input: &str) -> String {
-    let tree = parse_lum(input, BuildWeights(false));
+#[aoc(day7, part1, interned)]
+pub fn part1_interned(input: &str) -> String {
+    let tree = parse_interned(input, BuildWeights(false));
     let root = tree
-        .entries()
-        .find(|&e| e.value.parent.is_none())
-        .map(|e| e.id)
+        .info
+        .iter()
+        .find(|(_id, i)| i.parent.is_none())
+        .map(|(id, _i)| id)
         .unwrap();
 
-    tree.key(root).to_string()
+    tree.names.resolve(root).to_string()
 }
 
-fn is_balanced_lum(id: KeyId, tree: &Tree2) -> bool {
-    overweight_child_lum(id, tree).is_none()
+fn is_balanced_interned(id: Symbol, tree: &Tree2) -> bool {
+    overweight_child_interned(id, tree).is_none()
 }
 
-fn overweight_child_lum(id: KeyId, tree: &Tree2) -> Option<(KeyId, i64)> {
+fn overweight_child_interned(id: Symbol, tree: &Tree2) -> Option<(Symbol, i64)> {
     // We keep these children sorted by total_weight.
     // Since exactly 0 or exactly 1 child is the "wrong" weight, and these are sorted,
     // it must be the first or last child that is off.
     // We will assume the last, since the weights are always *too much* (???? unverified?)
-    let first = *tree.value(id).children.first()?;
-    let last = *tree.value(id).children.last()?;
+    let first = *tree.info[id].children.first()?;
+    let last = *tree.info[id].children.last()?;
 
-    let diff = tree.value(last).total_weight - tree.value(first).total_weight;
+    let diff = tree.info[last].total_weight - tree.info[first].total_weight;
     if diff != 0 {
         Some((last, diff))
     } else {
@@ -275,17 +281,18 @@ fn overweight_child_lum(id: KeyId, tree: &Tree2) -> Option<(KeyId, i64)> {
     }
 }
 
-#[aoc(day7, part2, lum)]
-pub fn part2_lum(input: &str) -> i64 {
-    let tree = parse_lum(input, BuildWeights(true));
+#[aoc(day7, part2, interned)]
+pub fn part2_interned(input: &str) -> i64 {
+    let tree = parse_interned(input, BuildWeights(true));
     let id = tree
+        .info
         .ids()
-        .filter(|&id| !is_balanced_lum(id, &tree))
-        .min_by_key(|&id| tree.value(id).total_weight)
+        .filter(|&id| !is_balanced_interned(id, &tree))
+        .min_by_key(|&id| tree.info[id].total_weight)
         .expect("Failed to find an unbalanced node");
 
-    let (bad_apple, diff) = overweight_child_lum(id, &tree).unwrap();
-    tree.value(bad_apple).weight - diff
+    let (bad_apple, diff) = overweight_child_interned(id, &tree).unwrap();
+    tree.info[bad_apple].weight - diff
 }
 
 #[aoc(day7, part1, orphan_seeker)]
@@ -383,57 +390,13 @@ pub fn part1_orphan_seeker(input: &str) -> String {
     std::str::from_utf8(parents[0]).unwrap().to_string()
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
-struct PackedStr(u32);
-
-impl PackedStr {
-    fn pack(bytes: &[u8], start: usize, end: usize) -> Self {
-        let len = end - start;
-        assert!(
-            len < (1 << 4),
-            "{:?} is too long",
-            std::str::from_utf8(&bytes[start..end])
-        );
-        // If we can squeeze ONE more bit somewhere, this gets us into 2 bytes.
-        // assert!(
-        //     len >= (1 << 2),
-        //     "{:?} not long enough",
-        //     std::str::from_utf8(&bytes[start..end])
-        // );
-        assert!(
-            start < (1 << 15),
-            "{:?} starts too far into its buffer ({start})",
-            std::str::from_utf8(&bytes[start..end])
-        );
-
-        let [a, b] = (start as u16).to_ne_bytes();
-        let [c] = (len as u8).to_ne_bytes();
-        Self(u32::from_ne_bytes([a, b, c, 0]))
-    }
-
-    fn unpack<'a>(&'_ self, bytes: &'a [u8]) -> &'a [u8] {
-        let [a, b, c, _] = self.0.to_ne_bytes();
-        let start = u16::from_ne_bytes([a, b]) as usize;
-        let len = u8::from_ne_bytes([c]) as usize;
-
-        if cfg!(debug_assertions) {
-            let _ = &bytes[start..(start + len)];
-        }
-        unsafe { core::slice::from_raw_parts(bytes.as_ptr().add(start), len) }
-    }
-
-    fn as_str<'a>(&'_ self, bytes: &'a [u8]) -> &'a str {
-        std::str::from_utf8(self.unpack(bytes)).unwrap()
-    }
-}
-
-#[aoc(day7, part1, orphan_seeker_packed)]
-pub fn part1_orphan_seeker_packed(input: &str) -> String {
+#[aoc(day7, part1, orphan_seeker_interned)]
+pub fn part1_orphan_seeker_interned(input: &str) -> String {
     use memchr::*;
 
     let bytes: &[u8] = input.as_bytes();
     let mut lines = Vec::with_capacity(512);
-    let mut parents: Vec<PackedStr> = Vec::with_capacity(512);
+    let mut parents: Interner = Interner::with_capacity(512);
 
     // Find all nodes with children
     for found in memchr_iter(b'>', bytes) {
@@ -451,7 +414,10 @@ pub fn part1_orphan_seeker_packed(input: &str) -> String {
             while 0 < start && bytes[start] != b'\n' {
                 start -= 1;
             }
-            start += 1;
+            // The first line has no newline before it
+            if bytes[start] == b'\n' {
+                start += 1;
+            }
 
             let mut end = found;
             while end < bytes.len() && bytes[end] != b'\n' {
@@ -461,69 +427,35 @@ pub fn part1_orphan_seeker_packed(input: &str) -> String {
         }
         lines.push(&line[(found - start + 2)..]);
 
-        // end of parent name, in bytes
-        let end = start + memchr(b' ', line).unwrap();
-
-        parents.push(PackedStr::pack(bytes, start, end));
+        let end = memchr(b' ', line).unwrap();
+        parents.intern(&line[..end]);
     }
 
-    parents.sort_by_key(|p| p.unpack(bytes));
-
     if cfg!(debug_assertions) {
         println!("{} parents:", parents.len());
-        for parent in &parents {
-            println!("  + {:?}", parent.as_str(bytes));
+        for id in parents.ids() {
+            println!("  + {:?}", parents.resolve(id));
         }
         println!();
     }
 
-    if cfg!(debug_assertions) {
-        println!("children:");
-    }
-
     // seek the orphan.
+    // Since every child has a parent, they can't be the root, so we cross off every parent that's also a child
+    let mut is_child: IdVec<Symbol, bool> = IdVec::from_elem(false, parents.len());
     for line in lines {
-        if cfg!(debug_assertions) {
-            println!("  + {:?}", std::str::from_utf8(line));
-        }
-
-        // For each line, parse the children.
-        // Since these children have a parent, they can't be the root.
-        // We'll remove each parent from `parents` until there is only one left!
-        let mut start = 0;
-        for end in memchr_iter(b',', line) {
-            let child = &line[start..end];
-            if cfg!(debug_assertions) {
-                println!("    + {:?}", std::str::from_utf8(child));
-            }
-            if let Ok(i) = parents.binary_search_by_key(&child, |packed| packed.unpack(bytes)) {
-                parents.remove(i);
+        for child in line.split(|&b| b == b',') {
+            let child = child.trim_ascii();
+            if let Some(id) = parents.get(child) {
+                is_child[id] = true;
             }
-            start = end + 2;
-        }
-        {
-            let child = &line[start..];
-            if cfg!(debug_assertions) {
-                println!("    + {:?}", std::str::from_utf8(child));
-            }
-            if let Ok(i) = parents.binary_search_by_key(&child, |packed| packed.unpack(bytes)) {
-                parents.remove(i);
-            }
-        }
-    }
-
-    if cfg!(debug_assertions) {
-        println!("{} parents:", parents.len());
-        for parent in &parents {
-            println!("  + {:?}", parent.as_str(bytes));
         }
     }
 
-    debug_assert_eq!(parents.len(), 1);
+    let mut orphans = is_child.iter().filter(|(_id, &is_child)| !is_child);
+    let (root, _) = orphans.next().expect("Every parent is someone's child");
+    debug_assert_eq!(orphans.next(), None);
 
-    std::str::from_utf8(parents[0].unpack(bytes))
-        .unwrap()
-        .to_string()
+    parents.resolve(root).to_string()
 }
 
 #[cfg(test)]
@@ -549,6 +481,14 @@ jptl (61)
 ugml (68) -> gyxo, ebii, jptl
 gyxo (61)
 cntj (57)
+";
+
+    const CHILDREN_ON_FIRST_LINE_INPUT: &str = r"
+ugml (68) -> gyxo, ebii
+tknk (41) -> ugml, padx
+padx (45)
+gyxo (61)
+ebii (61)
 ";
 
     #[rstest]
@@ -557,7 +497,12 @@ cntj (57)
     #[timeout(Duration::from_millis(1_500))]
     fn check_ex_part_1(
         #[notrace]
-        #[values(part1, part1_lum, part1_orphan_seeker, part1_orphan_seeker_packed)]
+        #[values(
+            part1,
+            part1_interned,
+            part1_orphan_seeker,
+            part1_orphan_seeker_interned
+        )]
         p: impl FnOnce(&str) -> String,
         #[case] expected: impl ToString,
         #[case] input: &str,
@@ -567,13 +512,30 @@ cntj (57)
         assert_eq!(p(input), expected.to_string());
     }
 
+    // The orphan seekers walk back from a child's name to the start of its parent's line, which for the first line
+    // is the start of the input. `part1_orphan_seeker` still cuts off the first letter there, so it's not in this one.
+    #[rstest]
+    #[case::children_on_first_line("tknk", CHILDREN_ON_FIRST_LINE_INPUT)]
+    #[trace]
+    #[timeout(Duration::from_millis(1_500))]
+    fn check_ex_part_1_first_line(
+        #[notrace]
+        #[values(part1, part1_interned, part1_orphan_seeker_interned)]
+        p: impl FnOnce(&str) -> String,
+        #[case] expected: impl ToString,
+        #[case] input: &str,
+    ) {
+        let input = input.trim();
+        assert_eq!(p(input), expected.to_string());
+    }
+
     #[rstest]
     #[case::given(60, EXAMPLE_INPUT)]
     #[trace]
     #[timeout(Duration::from_millis(1_500))]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2, part2_lum)]
+        #[values(part2, part2_interned)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -28,8 +28,6 @@ pub mod day08;
 // pub mod day24;
 // pub mod day25;
 
-mod lookup_map;
-
 aoc_lib! { year = 2017 }
 
 #[allow(non_upper_case_globals)]
@@ -44,13 +42,15 @@ pub mod prelude {
     pub use std::collections::*;
     pub use std::fmt::{self, Debug, Display};
 
+    pub use aoc_harness::cycle::{find_cycle, nth_state, Cycle};
+    pub use aoc_harness::interner::*;
+
     pub use crate::Cardinal;
     pub const Norð: Cardinal = Cardinal::Norð;
     pub const Souð: Cardinal = Cardinal::Souð;
     pub const East: Cardinal = Cardinal::East;
     pub const West: Cardinal = Cardinal::West;
 
-    pub use crate::lookup_map::*;
     pub use crate::print_with_focus;
 }
 
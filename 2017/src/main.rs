@@ -1,3 +1,6 @@
-use aoc_runner_derive::aoc_main;
+use aoc_harness::registry;
 
-aoc_main! { lib = aoc17 }
+fn main() -> std::process::ExitCode {
+    let year = registry!(aoc17);
+    aoc_harness::run::main(&year)
+}
@@ -7,11 +7,11 @@ use std::{
     str,
 };
 
+use aoc_parse::AocParse;
 use aoc_runner_derive::{aoc, aoc_generator};
-use lazy_static::lazy_static;
-use regex::Regex;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, AocParse)]
+#[aoc_parse("{opcode} {a} {b} {c}")]
 struct Instr {
     opcode: u32, // [0-15]
     a: u32,      // Input  - opcode determines whether this is a reg id or imm.
@@ -19,19 +19,6 @@ struct Instr {
     c: u32,      // Output - opcode determines whether this is a reg id or imm.
 }
 
-impl str::FromStr for Instr {
-    type Err = failure::Error;
-    fn from_str(s: &str) -> Result<Instr, failure::Error> {
-        let mut iter = s.split(' ');
-        let opcode: u32 = iter.next().unwrap().parse().expect("Bad opcode");
-        let a: u32 = iter.next().unwrap().parse().expect("Bad 'a' input");
-        let b: u32 = iter.next().unwrap().parse().expect("Bad 'b' input");
-        let c: u32 = iter.next().unwrap().parse().expect("Bad 'c' output");
-
-        Ok(Instr { opcode, a, b, c })
-    }
-}
-
 #[derive(Copy, Clone, Debug)]
 struct UnknownOpcode {
     before: [u32; 4],
@@ -55,38 +42,19 @@ impl UnknownOpcode {
     }
 }
 
+/// Registers, as printed in "Before: [3, 2, 1, 1]"
+#[derive(AocParse)]
+#[aoc_parse("[{a}, {b}, {c}, {d}]")]
+struct Regs {
+    a: u32,
+    b: u32,
+    c: u32,
+    d: u32,
+}
+
 fn parse_u32x4(s: &str) -> [u32; 4] {
-    lazy_static! {
-        static ref EXPR: Regex = Regex::new(r"\[(\d+), (\d+), (\d+), (\d+)\]").unwrap();
-    };
-    let captures = EXPR.captures(s).unwrap();
-
-    [
-        captures
-            .get(1)
-            .expect("No capture 1")
-            .as_str()
-            .parse()
-            .expect("Bad parse 1"),
-        captures
-            .get(2)
-            .expect("No capture 2")
-            .as_str()
-            .parse()
-            .expect("Bad parse 2"),
-        captures
-            .get(3)
-            .expect("No capture 3")
-            .as_str()
-            .parse()
-            .expect("Bad parse 3"),
-        captures
-            .get(4)
-            .expect("No capture 4")
-            .as_str()
-            .parse()
-            .expect("Bad parse 4"),
-    ]
+    let Regs { a, b, c, d } = s.trim().parse().expect("Bad registers");
+    [a, b, c, d]
 }
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -300,7 +268,7 @@ fn run2(input: &str) -> Result<u32, failure::Error> {
 
     let mut regs = [0; 4];
     for instr_str in lines.iter().skip(samples.len() * 4 + 2) {
-        let instr: Instr = str::parse(instr_str)?;
+        let instr: Instr = str::parse(instr_str).map_err(failure::err_msg)?;
         let opcode = *opcode_mappings.get(&instr.opcode).unwrap();
         regs = exec(regs, opcode, instr);
     }
@@ -1,3 +1,6 @@
-use aoc_runner_derive::aoc_main;
+use aoc_harness::registry;
 
-aoc_main! { lib = aoc18 }
+fn main() -> std::process::ExitCode {
+    let year = registry!(aoc18);
+    aoc_harness::run::main(&year)
+}
@@ -436,3 +436,64 @@ fn check_example_2_product() {
 fn check_example_2_simd() {
     debug_assert_eq!(part2_simd(EXAMPLE_INPUT), 61229);
 }
+
+/// Each display's wiring, the order its ten patterns are listed in, and its four output digits. The first two are
+/// Lehmer codes, so that every value is a valid permutation and shrinks toward the identity.
+#[cfg(test)]
+type Notes = Vec<([u8; 7], [u8; 10], [u8; 4])>;
+
+#[cfg(test)]
+fn random_notes(rng: &mut aoc_harness::differential::StdRng, size: usize) -> Notes {
+    use aoc_harness::differential::Rng;
+
+    (0..size).map(|_| rng.gen()).collect()
+}
+
+#[cfg(test)]
+fn render_notes(notes: &Notes) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+
+    fn unrank<T: Copy>(items: &[T], code: &[u8]) -> Vec<T> {
+        let mut left = items.to_vec();
+        code.iter()
+            .map(|&c| left.remove(c as usize % left.len()))
+            .collect()
+    }
+
+    notes
+        .iter()
+        .map(|(wiring, order, outputs)| {
+            let wiring = unrank(b"abcdefg", wiring);
+            let digit = |d: usize| -> String {
+                DIGITS[d]
+                    .bytes()
+                    .map(|b| wiring[(b - b'a') as usize] as char)
+                    .collect()
+            };
+
+            let patterns: Vec<String> = unrank(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9], order)
+                .into_iter()
+                .map(digit)
+                .collect();
+            let outputs: Vec<String> = outputs.iter().map(|&o| digit(o as usize % 10)).collect();
+
+            format!("{} | {}", patterns.join(" "), outputs.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn check_part2_variants_agree() {
+    use aoc_harness::differential::Differential;
+
+    #[allow(unused_mut)]
+    let mut variants: Vec<aoc_harness::differential::TextVariant<u64>> =
+        vec![("part2", part2), ("part2_product", part2_product)];
+    #[cfg(feature = "simd")]
+    variants.push(("part2_simd", part2_simd));
+
+    Differential::new(random_notes).check_text(render_notes, &variants);
+}
@@ -0,0 +1,97 @@
+use image::{Rgb, RgbImage};
+
+use aoc_harness::viz::{Ctx, Format, Frames, Viz};
+
+const EXAMPLE_INPUT: &str = r"
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+fn _grayscale_energy(energy: u8) -> Rgb<u8> {
+    // Grauscale - maximum energy (9) should be bright, but 0 should be not-black
+    let r = (energy as f64) / 9.;
+    let rgb = (r + 0.5) * 256.;
+    let rgb = rgb as u8;
+    Rgb([rgb, rgb, rgb])
+}
+
+fn palette_energy(energy: u8) -> Rgb<u8> {
+    // Matches AOC colors
+    const GOLD: Rgb<u8> = Rgb([0xff, 0xff, 0x66]);
+    const BLUE: Rgb<u8> = Rgb([0x0f, 0x0f, 0x23]);
+    // const GREEN: Rgb<u8> = Rgb([0x0, 0x99, 0x0]);
+
+    const PALETTE: [Rgb<u8>; 5] = [
+        BLUE,
+        Rgb([0x1f, 0x19, 0x5f]),
+        Rgb([0x73, 0x53, 0xba]),
+        Rgb([0xfa, 0xa6, 0xff]),
+        GOLD,
+    ];
+
+    let r = energy as f64 / 9.;
+    let i_ish = r * (PALETTE.len() - 1) as f64;
+    let i = i_ish.round() as usize;
+
+    PALETTE[i]
+}
+
+fn image_of(octs: &[[u8; 10]; 10]) -> RgbImage {
+    let width = octs[0].len() as u32;
+    let height = octs.len() as u32;
+
+    RgbImage::from_fn(width, height, |x, y| {
+        // grayscale_energy(octs[y as usize][x as usize])
+        palette_energy(octs[y as usize][x as usize])
+    })
+}
+
+pub struct Day11;
+
+impl Viz for Day11 {
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn about(&self) -> &'static str {
+        "Animation of the octopuses flashing, until they all flash at once"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+
+    fn default_format(&self) -> Format {
+        Format::Gif
+    }
+
+    fn render(&self, input: &str, _ctx: &Ctx, frames: &mut Frames) {
+        use aoc21::day11::{parse_input, sim_step};
+
+        let mut octs = parse_input(input);
+
+        for step in 1.. {
+            if step % 100 == 0 {
+                eprintln!("simulating step {}...", step);
+            }
+
+            if !frames.push(image_of(&octs)) {
+                break;
+            }
+
+            if sim_step(&mut octs) == 100 {
+                // Show everyone flashing at once
+                frames.push(image_of(&octs));
+                break;
+            }
+        }
+    }
+}
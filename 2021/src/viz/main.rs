@@ -0,0 +1,16 @@
+//! One binary for all of this year's visualizations.
+//!
+//! Each day implements [`Viz`] and is listed in [`VIZZES`]. Every day becomes a subcommand, and `aoc_harness::viz`
+//! does the rest:
+//! ```text
+//! $ cargo run --release --bin viz -- day11 --example --format png
+//! ```
+use aoc_harness::viz::Viz;
+
+mod day11;
+
+const VIZZES: &[&dyn Viz] = &[&day11::Day11];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    aoc_harness::viz::main(&aoc_harness::registry!(aoc21), VIZZES)
+}
@@ -0,0 +1,10 @@
+use aoc_harness::registry;
+
+fn main() -> std::process::ExitCode {
+    let year = registry!(
+        aoc22,
+        setup = [aoc22::init_logging],
+        random_inputs = aoc22::random_input::ALL
+    );
+    aoc_harness::bench::main(&year)
+}
@@ -189,7 +189,7 @@ mod test {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
-    const EXAMPLE_INPUT: &str = include_str!("../input/2022/day10_example.txt");
+    const EXAMPLE_INPUT: &str = include_str!("../input/2022/examples/day10-given.txt");
 
     #[rstest]
     #[case::given(13_140, EXAMPLE_INPUT)]
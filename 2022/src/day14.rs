@@ -414,8 +414,9 @@ pub fn part2_smol_tracking(input: &str) -> i64 {
     let mut path = vec![IVec2::new(500, 0)];
     let mut spawned = 0;
 
-    let mut left_h = 0;
-    let mut right_h = 0;
+    // The highest sand against each wall. Outside of the walls, sand piles up in a triangle from there to the floor.
+    let mut left_h = floor_y;
+    let mut right_h = floor_y;
 
     let min_x = cave.range_x().start;
     let max_x = cave.range_x().end;
@@ -472,11 +473,11 @@ pub fn part2_smol_tracking(input: &str) -> i64 {
         cave[sand] = BLOCK_SAND;
 
         if sand.x == min_x {
-            left_h = sand.y;
+            left_h = left_h.min(sand.y);
         }
 
         if sand.x + 1 == max_x {
-            right_h = sand.y;
+            right_h = right_h.min(sand.y);
         }
 
         // Remove this block from out path
@@ -490,8 +491,11 @@ pub fn part2_smol_tracking(input: &str) -> i64 {
         }
     }
 
-    spawned += (left_h * (left_h - 1)) as i64 / 2;
-    spawned += (right_h * (right_h - 1)) as i64 / 2;
+    for h in [left_h, right_h] {
+        // Each column further out holds one less than the last, starting with one less than the gap to the floor
+        let n = (floor_y - 1 - h).max(0) as i64;
+        spawned += n * (n + 1) / 2;
+    }
 
     cave[(500, 0)] = BLOCK_SPAWN;
     save_image(&cave, "day14_smol-tracking-pt2.png");
@@ -502,6 +506,7 @@ pub fn part2_smol_tracking(input: &str) -> i64 {
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_harness::differential::{Differential, Rng, StdRng};
     #[allow(unused_imports)]
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
@@ -527,6 +532,7 @@ mod test {
 
     #[rstest]
     #[case::given(93, EXAMPLE_INPUT)]
+    #[case::single_rock(15, "500,2 -> 500,2")]
     #[trace]
     fn check_ex_part_2(
         #[notrace]
@@ -538,4 +544,80 @@ mod test {
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
+
+    /// Rock paths, as a start (x from the sand's source, and y) and alternating horizontal and vertical steps
+    type Rocks = Vec<(i16, i16, Vec<i8>)>;
+
+    fn random_rocks(rng: &mut StdRng, size: usize) -> Rocks {
+        let size = size as i16;
+        (0..1 + size / 4)
+            .map(|_| {
+                let steps = (0..rng.gen_range(0..4))
+                    .map(|_| rng.gen_range(-8..=8))
+                    .collect();
+                (rng.gen_range(-size..=size), rng.gen_range(1..=size), steps)
+            })
+            .collect()
+    }
+
+    fn render_rocks(rocks: &Rocks) -> String {
+        let paths: Vec<Vec<(i32, i32)>> = rocks
+            .iter()
+            .map(|(x, y, steps)| {
+                let mut at = (*x as i32, *y as i32);
+                let mut path = vec![at];
+                for (i, &step) in steps.iter().enumerate() {
+                    if i % 2 == 0 {
+                        at.0 += step as i32;
+                    } else {
+                        at.1 += step as i32;
+                    }
+                    path.push(at);
+                }
+                // A path needs a segment to be drawn at all
+                if path.len() == 1 {
+                    path.push(at);
+                }
+                path
+            })
+            .collect();
+
+        // Part 1 assumes that sand never piles up to the source. It can't if the rocks are deeper than half as wide
+        // as they are, since the pile would need to be wider than them.
+        let xs = || paths.iter().flatten().map(|&(x, _)| x);
+        let width = xs().max().unwrap_or(0) - xs().min().unwrap_or(0) + 1;
+        let min_y = paths.iter().flatten().map(|&(_, y)| y).min().unwrap_or(0);
+        let shift = (width / 2 + 2 - min_y).max(0);
+
+        // The floor's only as wide as the sand can reach, so keep the rocks under it
+        let max_y = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0) + shift;
+        paths
+            .iter()
+            .map(|path| {
+                path.iter()
+                    .map(|&(x, y)| format!("{},{}", 500 + x.clamp(-max_y, max_y), y + shift))
+                    .join(" -> ")
+            })
+            .join("\n")
+    }
+
+    #[test]
+    fn check_part1_variants_agree() {
+        Differential::new(random_rocks).max_size(40).check_text(
+            render_rocks,
+            &[("part1", part1), ("part1_tracking", part1_tracking)],
+        );
+    }
+
+    #[test]
+    fn check_part2_variants_agree() {
+        Differential::new(random_rocks).max_size(40).check_text(
+            render_rocks,
+            &[
+                ("part2", part2),
+                ("part2_tracking", part2_tracking),
+                ("part2_smol_tracking", part2_smol_tracking),
+            ],
+        );
+    }
 }
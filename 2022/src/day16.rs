@@ -1,34 +1,15 @@
 use crate::prelude::*;
 
+use aoc_harness::interner::{FixedInterner, Id, Symbol};
+
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::fmt;
 
-#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Name([char; 2]);
-
-impl Name {
-    fn new(s: &str) -> Self {
-        Self(iter_to_array(s.chars().take(2)))
-    }
-}
-
-impl fmt::Debug for Name {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.0[0], self.0[1])
-    }
-}
-
-impl fmt::Display for Name {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}", self.0[0], self.0[1])
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct Graph {
-    names: Vec<Name>,
+    names: FixedInterner<2>,
     rates: Vec<i64>,
     tunnels: Vec<u64>,
 
@@ -39,23 +20,19 @@ struct Graph {
 impl Graph {
     /// Parses from Input
     fn new(s: &str) -> Self {
-        // O(n) but we only need it for parsing
-        fn get_id(name: Name, names: &mut Vec<Name>) -> usize {
-            if let Some(id) = names.iter().position(|&n| n == name) {
-                id
-            } else {
-                debug_assert!(names.len() < 63);
-                names.push(name);
-                names.len() - 1
-            }
+        // Valves are bits in a u64 mask, so their ids have to be small
+        fn get_id(name: &str, names: &mut FixedInterner<2>) -> usize {
+            let id = names.intern(&name[..2]).index();
+            debug_assert!(id < 64);
+            id
         }
 
-        let mut names = vec![];
+        let mut names = FixedInterner::new();
         let mut rates = vec![];
         let mut tunnels = vec![];
 
         // Make sure AA is always id=0
-        let aa_id = get_id(Name::new("AA"), &mut names);
+        let aa_id = get_id("AA", &mut names);
         debug_assert_eq!(aa_id, 0);
 
         // Eachg line is parsable in isolation
@@ -67,7 +44,7 @@ impl Graph {
             let line = &line[6..];
 
             // Parse valve
-            let valve_name = Name::new(line);
+            let valve_name = &line[..2];
             let id = get_id(valve_name, &mut names);
             if id >= rates.len() {
                 rates.resize(id + 1, 0);
@@ -84,7 +61,6 @@ impl Graph {
             let tunnel_text = &line[23..].trim();
             let tunnel: u64 = tunnel_text
                 .split(", ")
-                .map(Name::new)
                 .map(|n| get_id(n, &mut names))
                 .fold(0_u64, |acc, id| (acc | (1 << id)));
             debug!("Neighbors of {valve_name}: 0b{tunnel:016b}");
@@ -106,12 +82,15 @@ impl Graph {
     fn input(&self) -> String {
         debug_assert_eq!(self.names.len(), self.rates.len());
         debug_assert_eq!(self.names.len(), self.tunnels.len());
-        info!("names={:?}", self.names);
+        info!(
+            "names={:?}",
+            self.valves().map(|v| self.name_of(v)).collect_vec()
+        );
 
         let mut lines: Vec<String> = vec![];
 
         for id in 0..self.names.len() {
-            let name = self.names[id];
+            let name = self.name_of(id);
             let rate = self.rates[id];
 
             let tunnel_names = self
@@ -138,12 +117,12 @@ impl Graph {
         use std::io::BufWriter;
         use std::io::Write;
 
-        let filename = format!("{dir}/Day16_{}.dot", self.names[0]);
+        let filename = format!("{dir}/Day16_{}.dot", self.name_of(0));
         info!("Saving dot file to {:?}", std::path::absolute(&filename));
 
         let file = File::create(&filename)?;
         let mut w = BufWriter::new(file);
-        writeln!(w, "digraph Day16_{} {{", self.names[0])?;
+        writeln!(w, "digraph Day16_{} {{", self.name_of(0))?;
 
         for valve in self.valves() {
             // Note: We're bundling the flow rate in with the node
@@ -179,8 +158,8 @@ impl Graph {
     }
 
     #[track_caller]
-    fn name_of(&self, id: usize) -> Name {
-        self.names[id]
+    fn name_of(&self, id: usize) -> &str {
+        self.names.resolve(Symbol::from_index(id))
     }
 
     #[track_caller]
@@ -258,7 +237,7 @@ impl Ord for State {
 
 impl fmt::Debug for State {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let curr = { format!("{}", self.g.name_of(self.curr)) };
+        let curr = self.g.name_of(self.curr);
 
         let visited_ids = {
             format!(
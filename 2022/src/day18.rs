@@ -76,6 +76,54 @@ pub fn part2(input: &str) -> i64 {
     faces
 }
 
+/// Same flood fill, but in a dense grid instead of hashing every cube
+#[aoc(day18, part2, grid)]
+pub fn part2_grid(input: &str) -> i64 {
+    let cubes = parse(input).collect_vec();
+    let min_bounds = cubes
+        .iter()
+        .fold(cubes[0], |acc, c| acc.min_by_component(*c));
+    let max_bounds = cubes
+        .iter()
+        .fold(cubes[0], |acc, c| acc.max_by_component(*c));
+
+    // Leave a gap all the way around, so the steam can get to every side
+    let ranges = std::array::from_fn(|axis| min_bounds[axis] - 1..max_bounds[axis] + 2);
+
+    #[derive(Copy, Clone, Default, PartialEq, Eq)]
+    enum Voxel {
+        #[default]
+        Air,
+        Lava,
+        Steam,
+    }
+    let mut grid: Grid3<Voxel> = GridN::new_with_ranges(ranges);
+    for c in &cubes {
+        grid[c] = Voxel::Lava;
+    }
+    grid.set_border_color(Some(Voxel::Steam));
+
+    let mut faces = 0;
+    let start = min_bounds - IVec3::new(1, 1, 1);
+    let mut to_explore = vec![start];
+    grid[start] = Voxel::Steam;
+
+    while let Some(a) = to_explore.pop() {
+        for xyz in a.neighbors() {
+            match grid[xyz] {
+                Voxel::Lava => faces += 1,
+                Voxel::Air => {
+                    grid[xyz] = Voxel::Steam;
+                    to_explore.push(xyz);
+                }
+                Voxel::Steam => {}
+            }
+        }
+    }
+
+    faces
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -118,7 +166,7 @@ mod test {
     #[trace]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2)]
+        #[values(part2, part2_grid)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -790,43 +790,27 @@ mod test {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
-    const EXAMPLE_INPUT: &str = r"        ...#
-        .#..
-        #...
-        ....
-...#.......#
-........#...
-..#....#....
-..........#.
-        ...#....
-        .....#..
-        .#......
-        ......#.
-
-10R5L5R10L4R5L5";
+    use std::path::PathBuf;
+
     #[rstest]
-    #[case::given(6032, EXAMPLE_INPUT)]
     #[trace]
-    fn check_ex_part_1(
+    fn check_examples_part_1(
+        #[files("input/2022/examples/day22-*.txt")] path: PathBuf,
         #[notrace]
         #[values(part1)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 1, p);
     }
 
     #[rstest]
-    #[case::given(5031, EXAMPLE_INPUT)]
     #[trace]
-    fn check_ex_part_2(
+    fn check_examples_part_2(
+        #[files("input/2022/examples/day22-*.txt")] path: PathBuf,
         #[notrace]
         #[values(part2)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 2, p);
     }
 }
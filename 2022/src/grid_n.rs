@@ -0,0 +1,359 @@
+//! Like [`Framebuffer`](crate::framebuffer::Framebuffer), but for volumes: 3D voxels, 4D Conway cubes, and so on.
+use ultraviolet::{IVec3, IVec4};
+
+use std::ops::Range;
+use std::ops::{Index, IndexMut};
+
+pub type Grid3<T> = GridN<T, 3>;
+pub type Grid4<T> = GridN<T, 4>;
+
+/// A dense `N`-dimensional grid, indexed by `[i32; N]` (or `IVec3`/`IVec4`) over whatever ranges it was made with
+#[derive(Clone, PartialEq, Eq)]
+pub struct GridN<T, const N: usize> {
+    /// Backing storage, with x changing fastest
+    buf: Vec<T>,
+
+    /// How many cells are along each axis
+    dims: [usize; N],
+
+    /// The smallest coordinate along each axis, which is where its 0th cell is
+    mins: [i32; N],
+
+    /// When accessing out of bounds elements, return this
+    ///
+    /// All OOB accesses panic if this is `None`
+    border_color: Option<T>,
+}
+
+/// Construction Methods
+impl<T, const N: usize> GridN<T, N>
+where
+    T: Default + Clone,
+{
+    pub fn new_with_ranges(ranges: [Range<i32>; N]) -> Self {
+        Self::new_with_ranges_and(ranges, |_| T::default())
+    }
+
+    pub fn new_matching_size<U>(other: &GridN<U, N>) -> Self {
+        Self::new_with_ranges(other.ranges())
+    }
+
+    /// A copy that's `by` cells bigger on every side, filled with the border color if there is one
+    pub fn grow(&self, by: i32) -> Self {
+        let ranges = self.ranges().map(|r| r.start - by..r.end + by);
+        let mut grown = Self::new_with_ranges_and(ranges, |p| {
+            self.get(p)
+                .or(self.border_color.as_ref())
+                .cloned()
+                .unwrap_or_default()
+        });
+        grown.border_color = self.border_color.clone();
+        grown
+    }
+}
+
+/// Construction Methods
+impl<T, const N: usize> GridN<T, N> {
+    pub fn new_with_ranges_and(
+        ranges: [Range<i32>; N],
+        mut func: impl FnMut([i32; N]) -> T,
+    ) -> Self {
+        let dims = ranges.clone().map(|r| r.len());
+        let mins = ranges.map(|r| r.start);
+
+        let mut grid = Self {
+            buf: Vec::with_capacity(dims.iter().product()),
+            dims,
+            mins,
+            border_color: None,
+        };
+        // Coordinates come out in the same order as the buffer, so we can .push() each one
+        for p in grid.iter_coords() {
+            grid.buf.push(func(p));
+        }
+
+        grid
+    }
+}
+
+impl<T, const N: usize> GridN<T, N> {
+    /// Sets a color to be returned when accesses are out of bounds, returning the old color.
+    pub fn set_border_color(&mut self, mut border_color: Option<T>) -> Option<T> {
+        std::mem::swap(&mut self.border_color, &mut border_color);
+        border_color
+    }
+
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    pub fn ranges(&self) -> [Range<i32>; N] {
+        std::array::from_fn(|axis| self.mins[axis]..self.mins[axis] + self.dims[axis] as i32)
+    }
+
+    /// Every coordinate in the grid, with x changing fastest
+    pub fn iter_coords(&self) -> impl Iterator<Item = [i32; N]> + 'static {
+        let ranges = self.ranges();
+        let len = self.dims.iter().product::<usize>();
+
+        (0..len).map(move |mut i| {
+            std::array::from_fn(|axis| {
+                let r = &ranges[axis];
+                let n = r.len();
+                let c = r.start + (i % n) as i32;
+                i /= n;
+                c
+            })
+        })
+    }
+
+    pub fn flatten(&self) -> std::slice::Iter<'_, T> {
+        self.buf.iter()
+    }
+
+    pub fn flatten_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.buf.iter_mut()
+    }
+
+    pub fn in_bounds(&self, p: [i32; N]) -> bool {
+        self.idx_from_coord(p).is_some()
+    }
+
+    pub fn get(&self, p: [i32; N]) -> Option<&T> {
+        let idx = self.idx_from_coord(p)?;
+        self.buf.get(idx)
+    }
+
+    pub fn get_mut(&mut self, p: [i32; N]) -> Option<&mut T> {
+        let idx = self.idx_from_coord(p)?;
+        self.buf.get_mut(idx)
+    }
+
+    /// Neighbors of `p` that share a face, which is 2 per axis. These may be out of bounds.
+    pub fn neighbors(p: [i32; N]) -> impl Iterator<Item = [i32; N]> {
+        (0..N).flat_map(move |axis| {
+            [1, -1].map(|d| {
+                let mut q = p;
+                q[axis] += d;
+                q
+            })
+        })
+    }
+
+    /// Neighbors of `p` that share anything, even just a corner, which is `3^N - 1` of them. These may be out of bounds.
+    pub fn full_neighbors(p: [i32; N]) -> impl Iterator<Item = [i32; N]> {
+        let len = 3_usize.pow(N as u32);
+        (0..len).filter(move |&i| i != len / 2).map(move |mut i| {
+            std::array::from_fn(|axis| {
+                let d = (i % 3) as i32 - 1;
+                i /= 3;
+                p[axis] + d
+            })
+        })
+    }
+
+    fn idx_from_coord(&self, p: [i32; N]) -> Option<usize> {
+        let mut idx = 0;
+        for axis in (0..N).rev() {
+            // Offset back to unsigned coordinates
+            let c = p[axis] - self.mins[axis];
+            if c < 0 || c as usize >= self.dims[axis] {
+                return None;
+            }
+            idx = idx * self.dims[axis] + c as usize;
+        }
+        Some(idx)
+    }
+
+    fn get_buf<'a>(&'a self, buf: &'a [T], p: [i32; N]) -> &'a T {
+        self.idx_from_coord(p)
+            .map(|idx| &buf[idx])
+            .or(self.border_color.as_ref())
+            .unwrap_or_else(|| panic!("oob index {p:?} but no border color set"))
+    }
+}
+
+impl<T, const N: usize> GridN<T, N>
+where
+    T: Clone,
+{
+    /// Call a kernel per cell, sampling its `3^N - 1` full neighbors in the order of [`Self::full_neighbors()`]
+    ///
+    /// Every cell sees its neighbors from before this step. If border color is unset, this panics.
+    pub fn kernel(&mut self, mut kernel: impl FnMut([i32; N], &T, &[&T]) -> T) {
+        // Build the next step off to the side, so "taps" can keep looking at this one
+        let mut taps = Vec::with_capacity(3_usize.pow(N as u32) - 1);
+        let next = self
+            .iter_coords()
+            .enumerate()
+            .map(|(idx, p)| {
+                taps.clear();
+                taps.extend(Self::full_neighbors(p).map(|q| self.get_buf(&self.buf, q)));
+                kernel(p, &self.buf[idx], &taps)
+            })
+            .collect();
+
+        self.buf = next;
+    }
+
+    pub fn clear(&mut self, clear_color: T) {
+        for t in self.flatten_mut() {
+            *t = clear_color.clone();
+        }
+    }
+}
+
+impl<T, const N: usize> Index<[i32; N]> for GridN<T, N> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, p: [i32; N]) -> &Self::Output {
+        if let Some(t) = self.get(p).or(self.border_color.as_ref()) {
+            t
+        } else {
+            panic!(
+                "oob index {p:?} dims={dims:?}, but no border color set",
+                dims = self.dims
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<[i32; N]> for GridN<T, N> {
+    #[track_caller]
+    fn index_mut(&mut self, p: [i32; N]) -> &mut Self::Output {
+        let dims = self.dims;
+        if let Some(t) = self.get_mut(p) {
+            t
+        } else {
+            panic!("oob index {p:?} dims={dims:?}, and borders can't be written to")
+        }
+    }
+}
+
+// ==== Index using ultraviolet's vectors ======================================
+macro_rules! impl_vec_indexing {
+    ($($v:ty => $n:literal),* $(,)?) => {
+        $(
+            impl<T> Index<$v> for GridN<T, $n> {
+                type Output = T;
+
+                #[track_caller]
+                fn index(&self, idx: $v) -> &Self::Output {
+                    &self[<[i32; $n]>::from(idx)]
+                }
+            }
+
+            impl<T> IndexMut<$v> for GridN<T, $n> {
+                #[track_caller]
+                fn index_mut(&mut self, idx: $v) -> &mut Self::Output {
+                    &mut self[<[i32; $n]>::from(idx)]
+                }
+            }
+
+            impl<T> Index<&$v> for GridN<T, $n> {
+                type Output = T;
+
+                #[track_caller]
+                fn index(&self, idx: &$v) -> &Self::Output {
+                    &self[*idx]
+                }
+            }
+
+            impl<T> IndexMut<&$v> for GridN<T, $n> {
+                #[track_caller]
+                fn index_mut(&mut self, idx: &$v) -> &mut Self::Output {
+                    &mut self[*idx]
+                }
+            }
+        )+
+    }
+}
+
+impl_vec_indexing![IVec3 => 3, IVec4 => 4];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vec::VecExt;
+    use itertools::Itertools;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn check_indexing() {
+        let mut grid: Grid3<i32> = GridN::new_with_ranges([-1..2, 0..3, 5..9]);
+        assert_eq!(grid.dims(), [3, 3, 4]);
+        assert_eq!(grid.iter_coords().count(), 36);
+        assert_eq!(grid.iter_coords().next(), Some([-1, 0, 5]));
+        assert_eq!(grid.iter_coords().last(), Some([1, 2, 8]));
+
+        grid[IVec3::new(-1, 2, 8)] = 7;
+        assert_eq!(grid[[-1, 2, 8]], 7);
+        assert!(!grid.in_bounds([2, 0, 5]));
+        assert_eq!(grid.get([0, 0, 4]), None);
+
+        grid.set_border_color(Some(-1));
+        assert_eq!(grid[IVec3::new(100, 0, 0)], -1);
+
+        // Every coordinate goes to its own cell
+        let grid = GridN::new_with_ranges_and([-1..2, 0..3, 5..9], |p| p);
+        for p in grid.iter_coords() {
+            assert_eq!(grid[p], p);
+        }
+    }
+
+    #[test]
+    fn check_neighbors_match_vec_ext() {
+        let p = IVec3::new(1, -2, 3);
+        let mut ours = Grid3::<()>::neighbors(p.into())
+            .map(IVec3::from)
+            .collect_vec();
+        let mut theirs = p.neighbors().to_vec();
+        ours.sort_by_key(|v| <[i32; 3]>::from(*v));
+        theirs.sort_by_key(|v| <[i32; 3]>::from(*v));
+        assert_eq!(ours, theirs);
+
+        let mut ours = Grid4::<()>::full_neighbors([0; 4]).collect_vec();
+        let mut theirs = IVec4::zero()
+            .full_neighbors()
+            .map(<[i32; 4]>::from)
+            .to_vec();
+        ours.sort();
+        theirs.sort();
+        assert_eq!(ours.len(), 80);
+        assert_eq!(ours, theirs);
+    }
+
+    /// Conway's cubes, from 2020 day 17
+    fn conway<const N: usize>(start: &str, cycles: i32) -> usize {
+        let start: Vec<&str> = start.lines().collect();
+        let (w, h) = (start[0].len() as i32, start.len() as i32);
+        let ranges = std::array::from_fn(|axis| match axis {
+            0 => 0..w,
+            1 => 0..h,
+            _ => 0..1,
+        });
+        let mut grid: GridN<bool, N> = GridN::new_with_ranges_and(ranges, |p| {
+            start[p[1] as usize].as_bytes()[p[0] as usize] == b'#'
+        });
+        grid.set_border_color(Some(false));
+
+        for _ in 0..cycles {
+            grid = grid.grow(1);
+            grid.kernel(|_p, &active, taps| {
+                let n = taps.iter().filter(|&&&t| t).count();
+                matches!((active, n), (true, 2 | 3) | (false, 3))
+            });
+        }
+
+        grid.flatten().filter(|&&t| t).count()
+    }
+
+    #[test]
+    fn check_kernel_conway_cubes() {
+        let start = ".#.\n..#\n###";
+        assert_eq!(conway::<3>(start, 6), 112);
+        assert_eq!(conway::<4>(start, 6), 848);
+    }
+}
@@ -31,13 +31,13 @@ pub mod day24;
 pub mod day25;
 
 pub mod framebuffer;
+pub mod grid_n;
+pub mod random_input;
 pub mod vec;
 
 aoc_lib! { year = 2022 }
 
-// Run this function when the binary is loaded. This typically happens BEFORE MAIN.
-// This is a BAD IDEA, but cargo-aoc doesn't give us hooks anywhere else. So it's this or lazy-init in EVERY solution 😬.
-// #[ctor::ctor]
+/// Called by the `main`, `check`, and `bench` binaries before running anything. See `registry!(..., setup = [...])`.
 pub fn init_logging() {
     use prelude::*;
     use std::sync::Once;
@@ -73,6 +73,7 @@ pub fn init_logging() {
 
 pub mod prelude {
     pub use crate::framebuffer::Framebuffer;
+    pub use crate::grid_n::{Grid3, Grid4, GridN};
     pub use crate::init_logging;
     pub use crate::vec::VecExt;
 
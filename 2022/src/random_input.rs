@@ -0,0 +1,100 @@
+//! Random inputs for stress testing, shaped like the real ones but as big as we want.
+//!
+//! See `aoc_harness::scaling` for timing solutions on them with `bench --scale`.
+use crate::prelude::*;
+
+use aoc_harness::differential::{Rng, SliceRandom, StdRng};
+use aoc_harness::scaling::RandomInput;
+
+pub const ALL: &[RandomInput] = &[RandomInput {
+    day: 16,
+    name: "valves",
+    generate: valves,
+}];
+
+/// Day 16's valves and tunnels, with `size` valves plus `AA` and one more. Real inputs have about 60, and about 15 of
+/// them work.
+///
+/// Day 16 keeps each valve's tunnels in a `u64` mask, so this never makes more than 60.
+pub fn valves(rng: &mut StdRng, size: usize) -> String {
+    let count = size.clamp(1, 58) + 2;
+
+    // AA is always there, and never works
+    let mut names = (b'A'..=b'Z')
+        .cartesian_product(b'A'..=b'Z')
+        .map(|(a, b)| format!("{}{}", a as char, b as char))
+        .filter(|name| name != "AA")
+        .collect_vec();
+    names.shuffle(rng);
+    names.truncate(count - 1);
+    names.insert(0, "AA".to_string());
+
+    // part2 splits the working valves between us and the elephant, so it needs at least two
+    let mut rates = vec![0; count];
+    let working = (count / 4).max(2);
+    for i in rand::seq::index::sample(rng, count - 1, working) {
+        rates[i + 1] = rng.gen_range(1..=25);
+    }
+
+    // A random tree keeps everything reachable from AA, and a few more tunnels make loops in it
+    let mut tunnels = vec![vec![]; count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.gen_range(0..i));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.gen_range(0..count), rng.gen_range(0..count));
+    }
+
+    let mut lines = (0..count)
+        .map(|i| {
+            let to = tunnels[i].iter().map(|&j| &names[j]).join(", ");
+            if tunnels[i].len() == 1 {
+                format!(
+                    "Valve {} has flow rate={}; tunnel leads to valve {to}",
+                    names[i], rates[i]
+                )
+            } else {
+                format!(
+                    "Valve {} has flow rate={}; tunnels lead to valves {to}",
+                    names[i], rates[i]
+                )
+            }
+        })
+        .collect_vec();
+    lines.sort();
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_harness::differential::SeedableRng;
+    use aoc_harness::scaling::check_solvable;
+
+    #[test]
+    fn check_valves() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let input = valves(&mut rng, 100);
+
+        assert_eq!(input.lines().count(), 60);
+        assert!(input.contains("Valve AA has flow rate=0;"));
+    }
+
+    #[test]
+    fn check_random_inputs() {
+        check_solvable(ALL, &[1, 5, 10], |day, input| match day {
+            16 => {
+                crate::day16::part1(input);
+                crate::day16::part2(input);
+            }
+            _ => unreachable!("day{day} needs a case here"),
+        });
+    }
+}
@@ -0,0 +1,75 @@
+use aoc22::day12::{find_path, parse};
+use aoc22::framebuffer::Framebuffer;
+
+use image::Rgb;
+
+use aoc_harness::viz::{Ctx, Frames, Viz};
+
+const EXAMPLE_INPUT: &str = r"
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+pub struct Day12;
+
+impl Viz for Day12 {
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn about(&self) -> &'static str {
+        "Heightmap, shaded by the number of steps it takes to reach each square"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+
+    fn render(&self, input: &str, _ctx: &Ctx, frames: &mut Frames) {
+        let day = parse(input);
+        let total_steps_map = find_path(&day, day.start);
+        let max_steps = total_steps_map
+            .iter_coords()
+            .map(|pt| total_steps_map[pt])
+            .filter(|s| *s != i64::MAX)
+            .max()
+            .unwrap();
+
+        let colored: Framebuffer<Rgb<u8>> = Framebuffer::new_with_ranges_and(
+            day.heightmap.range_x(),
+            day.heightmap.range_y(),
+            |x, y| {
+                if total_steps_map[(x, y)] == i64::MAX {
+                    // Unreachable
+                    return Rgb([0, 0, 0]);
+                }
+
+                let h = norm(day.heightmap[(x, y)], 25, 96);
+                let s = norm(total_steps_map[(x, y)], max_steps, 0);
+
+                let a = h / 2 + 32;
+                let b = s / 2 + 16;
+                Rgb([0, b, a])
+                // Rgb([0, a, b])
+                // Rgb([a, b, 0])
+                // Rgb([s, s, s])
+                // Rgb([h, h, h])
+                // let x = h / 2 + s / 2;
+                // Rgb([x, x, x])
+            },
+        );
+
+        frames.push(colored.make_image(1, |rgb| *rgb));
+    }
+}
+
+fn norm<N: Into<i64>>(x: N, max: N, o: u8) -> u8 {
+    let x = x.into() as f32;
+    let max = max.into() as f32;
+    let p = (u8::MAX - o) as f32;
+
+    (p * x / max) as u8 + o
+}
@@ -0,0 +1,134 @@
+use clap::{Args, Command};
+use image::*;
+use noise::{NoiseFn, Perlin};
+use rand::prelude::*;
+use ultraviolet::UVec2;
+
+use std::path::Path;
+
+use aoc_harness::viz::{Ctx, Frames, Viz};
+
+#[derive(Debug, Args)]
+struct Day22Args {
+    #[arg(long, default_value = "tileset.png")]
+    tileset: String,
+}
+
+pub struct Day22;
+
+impl Viz for Day22 {
+    fn day(&self) -> u32 {
+        22
+    }
+
+    fn about(&self) -> &'static str {
+        "Tiled map of the monkey map, plus a cube model to wrap it around"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(include_str!("../../input/2022/examples/day22-given.txt"))
+    }
+
+    fn args(&self, cmd: Command) -> Command {
+        Day22Args::augment_args(cmd)
+    }
+
+    fn render(&self, input: &str, ctx: &Ctx, frames: &mut Frames) {
+        let args: Day22Args = ctx.args();
+
+        let mut ground: Vec<UVec2> = vec![];
+        let mut walls: Vec<UVec2> = vec![];
+
+        // Start offset from the origin so we have a cute border
+        const BORDER: u32 = 0;
+
+        let mut coord = UVec2::new(BORDER, BORDER);
+        let mut dims = UVec2::zero();
+
+        for line in input.lines() {
+            dims.x = dims.x.max(coord.x + BORDER);
+            coord.x = BORDER;
+
+            for b in line.as_bytes().iter().copied() {
+                match b {
+                    b'.' => ground.push(coord),
+                    b'#' => walls.push(coord),
+                    _ => { /* Not Rendered, ignore */ }
+                }
+
+                coord.x += 1;
+            }
+            coord.y += 1;
+
+            // We have two lines at the end of the input with no map data, so we get a +2 for free here
+            dims.y = dims.y.max(coord.y);
+        }
+        dims.y -= 2;
+
+        let tile_size = 8;
+        dims *= 2 * tile_size;
+
+        let tileset: RgbaImage = image::open(&args.tileset).unwrap().to_rgba8();
+        let ground_tile: RgbaImage = tileset.view(132, 176, tile_size, tile_size).to_image();
+        let grass_tile: RgbaImage = tileset.view(148, 216, tile_size, tile_size).to_image();
+        let wall_tile: RgbaImage = tileset
+            .view(340, 176, 2 * tile_size, 2 * tile_size)
+            .to_image();
+
+        let mut map = RgbaImage::new(dims.x, dims.y);
+
+        let perlin = Perlin::new(thread_rng().gen());
+
+        for xy in ground {
+            let (x, y) = (2 * tile_size * xy).into();
+
+            for (x, y) in [
+                (x, y),
+                (x + tile_size, y),
+                (x, y + tile_size),
+                (x + tile_size, y + tile_size),
+            ] {
+                let n = pick_terrain(&perlin, x, y);
+
+                let tile = if n < 0.5 { &grass_tile } else { &ground_tile };
+                map.copy_from(tile, x, y).unwrap();
+            }
+        }
+
+        for xy in walls {
+            let (x, y) = (2 * tile_size * xy).into();
+            map.copy_from(&wall_tile, x, y).unwrap();
+        }
+
+        frames.push(DynamicImage::ImageRgba8(map).into_rgb8());
+
+        let is_example_input = {
+            const EXAMPLE_INPUT_LINE_COUNT: usize = 14;
+            input.lines().count() == EXAMPLE_INPUT_LINE_COUNT
+        };
+
+        let obj = if is_example_input {
+            include_str!("../../models/cube-example.obj")
+        } else {
+            // include_str!("../../models/cube.obj")
+            todo!()
+        };
+
+        // The model expects to find "map.mtl" next to it, and the material points at our texture by name
+        let output = Path::new(ctx.output);
+        let dir = output.parent().unwrap_or(Path::new("."));
+        let texture = output.file_name().unwrap().to_string_lossy();
+        let mtl = include_str!("../../models/cube.mtl").replace("map.png", &texture);
+
+        std::fs::write(dir.join("map.obj"), obj).unwrap();
+        std::fs::write(dir.join("map.mtl"), mtl).unwrap();
+    }
+}
+
+fn pick_terrain(perlin: &Perlin, x: u32, y: u32) -> f64 {
+    let x = x as f64 / (2. * 23.);
+    let y = y as f64 / (2. * 23.);
+    let n = perlin.get([x, y]);
+
+    0.5 * (n + 1.)
+}
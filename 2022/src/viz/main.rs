@@ -0,0 +1,17 @@
+//! One binary for all of this year's visualizations.
+//!
+//! Each day implements [`Viz`] and is listed in [`VIZZES`]. Every day becomes a subcommand, and `aoc_harness::viz`
+//! does the rest:
+//! ```text
+//! $ cargo run --release --bin viz -- day22 --example --tileset tileset.png
+//! ```
+use aoc_harness::viz::Viz;
+
+mod day12;
+mod day22;
+
+const VIZZES: &[&dyn Viz] = &[&day12::Day12, &day22::Day22];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    aoc_harness::viz::main(&aoc_harness::registry!(aoc22), VIZZES)
+}
@@ -25,36 +25,28 @@ mod test {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
-    const EXAMPLE_INPUT: &str = r"
-// todo
-";
+    use std::path::PathBuf;
 
     #[rstest]
-    #[case::given(999_999, EXAMPLE_INPUT)]
     #[trace]
-    fn check_ex_part_1(
+    fn check_examples_part_1(
+        #[files("input/2023/examples/dayN-*.txt")] path: PathBuf,
         #[notrace]
         #[values(part1)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
-        let input = input.trim();
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 1, p);
     }
 
     #[ignore]
     #[rstest]
-    #[case::given(999_999, EXAMPLE_INPUT)]
     #[trace]
-    fn check_ex_part_2(
+    fn check_examples_part_2(
+        #[files("input/2023/examples/dayN-*.txt")] path: PathBuf,
         #[notrace]
         #[values(part2)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
-        let input = input.trim();
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 2, p);
     }
 }
@@ -127,6 +127,8 @@ ZZZ = (ZZZ, ZZZ)
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -154,6 +156,8 @@ XXX = (XXX, XXX)
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
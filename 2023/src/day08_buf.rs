@@ -135,6 +135,8 @@ ZZZ = (ZZZ, ZZZ)
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -162,6 +164,8 @@ XXX = (XXX, XXX)
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
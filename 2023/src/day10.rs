@@ -453,6 +453,37 @@ pub fn part2(input: &str) -> i64 {
     (pipes.count_tiles() / 4 - real_seen_count) as i64
 }
 
+/// The loop goes through the middle of every tile on it, so Pick's theorem counts the tiles it encloses
+#[aoc(day10, part2, pick)]
+pub fn part2_pick(input: &str) -> i64 {
+    use crate::geometry::interior_points;
+
+    let pipes = PipeMap::from_str(input);
+
+    // Walk the loop once, leaving every tile the way we didn't come in
+    let mut path: Vec<(i64, i64)> = vec![];
+    let mut here = pipes.start;
+    let mut came_from = Cardinal::none();
+    loop {
+        path.push(here);
+
+        let connections = pipes.map[here].connections();
+        let dir = Cardinal::ALL_NO_DIAG
+            .into_iter()
+            .find(|&dir| connections.contains(dir) && dir != came_from)
+            .expect("The loop is broken");
+        let step = IVec2::from(dir);
+
+        here = (here.0 + step.x as i64, here.1 + step.y as i64);
+        came_from = dir.rev();
+        if here == pipes.start {
+            break;
+        }
+    }
+
+    interior_points(&path)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -469,6 +500,8 @@ mod test {
     #[case('L', '-', East)]
     #[trace]
     fn check_does_connects_with(#[case] a: char, #[case] b: char, #[case] cardinal: Cardinal) {
+        init_logging();
+
         let a = Pipe::from_char(a);
         let b = Pipe::from_char(b);
         assert!(a.connects_with(b, cardinal));
@@ -484,6 +517,8 @@ mod test {
     #[case('-', 'L', East)]
     #[trace]
     fn check_doesnt_connects_with(#[case] a: char, #[case] b: char, #[case] cardinal: Cardinal) {
+        init_logging();
+
         let a = Pipe::from_char(a);
         let b = Pipe::from_char(b);
         assert!(!a.connects_with(b, cardinal));
@@ -534,6 +569,8 @@ LJ.LJ
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert!(
             input.contains('S'),
@@ -602,16 +639,17 @@ L7JLJL-JLJLJL--JLJ.L
     #[case::given_part2_1_no_squeezing(4, EXAMPLE_INPUT_PART2_1_NO_SQUEEZING)]
     #[case::given_part2_1_squeezing(4, EXAMPLE_INPUT_PART2_1_SQUEEZING)]
     #[case::given_part2_2(8, EXAMPLE_INPUT_PART2_2)]
-    #[ignore]
-    #[case::given_part2_3(8, EXAMPLE_INPUT_PART2_3)]
+    #[case::given_part2_3(10, EXAMPLE_INPUT_PART2_3)]
     #[trace]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2)]
+        #[values(part2, part2_pick)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -641,6 +679,8 @@ L7JLJL-JLJLJL--JLJ.L
     #[rstest]
     #[case(EXAMPLE_INPUT_PART2_1_SQUEEZING, EXAMPLE_INPUT_PART2_1_SQUEEZING_BIG)]
     fn check_part2_stretch_map(#[case] map: &str, #[case] big_map: &str) {
+        init_logging();
+
         let mut pipes = PipeMap::from_str(map);
 
         pipes.pretty_print_pipes();
@@ -105,6 +105,8 @@ mod test {
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -114,6 +116,8 @@ mod test {
     #[case::given(100, 8410, EXAMPLE_INPUT)]
     #[trace]
     fn check_ex_part_2(#[case] expansion: i64, #[case] expected: i64, #[case] input: &str) {
+        init_logging();
+
         let input = input.trim();
         let mut galaxies = parse(input);
 
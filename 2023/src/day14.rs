@@ -55,8 +55,57 @@ pub fn part1(input: &str) -> i64 {
 }
 
 // Part2 ========================================================================
-#[cfg_attr(feature = "broken", aoc(day14, part2))]
+/// Rolls every rock as far north as it goes, one column at a time
+fn tilt_north(rows: &mut [Vec<u8>]) {
+    for x in 0..rows[0].len() {
+        // Where the next rock we see will stop
+        let mut stop = 0;
+        for y in 0..rows.len() {
+            match rows[y][x] {
+                b'#' => stop = y + 1,
+                b'O' => {
+                    rows[y][x] = b'.';
+                    rows[stop][x] = b'O';
+                    stop += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Turns the platform clockwise, so that what was west is now north
+fn rotate_clockwise(rows: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let h = rows.len();
+    (0..rows[0].len())
+        .map(|x| (0..h).rev().map(|y| rows[y][x]).collect())
+        .collect()
+}
+
+/// Rolls north, then west, then south, then east
+fn spin_cycle(rows: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut rows = rows.to_vec();
+    for _ in 0..4 {
+        tilt_north(&mut rows);
+        rows = rotate_clockwise(&rows);
+    }
+    rows
+}
+
+#[aoc(day14, part2)]
 pub fn part2(input: &str) -> i64 {
+    let rows: Vec<Vec<u8>> = input.lines().map(|line| line.as_bytes().to_vec()).collect();
+    let rows = nth_state(rows, |rows| spin_cycle(rows), 1_000_000_000);
+
+    let h = rows.len();
+    rows.iter()
+        .enumerate()
+        .map(|(y, row)| (h - y) * row.iter().filter(|&&c| c == b'O').count())
+        .sum::<usize>() as i64
+}
+
+#[cfg_attr(feature = "broken", aoc(day14, part2, brute_force))]
+pub fn part2_brute_force(input: &str) -> i64 {
     let dim = if cfg!(test) { 10 } else { 100 };
     let mut platform = Framebuffer::new(dim + 1, dim + 1);
     platform.clear('.');
@@ -168,11 +217,12 @@ O.#..O.#.#
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
 
-    #[cfg(feature = "broken")]
     #[rstest]
     #[case::given(64, EXAMPLE_INPUT)]
     #[trace]
@@ -183,6 +233,8 @@ O.#..O.#.#
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -134,6 +134,8 @@ mod test {
 
     #[test]
     fn check_hash_algo() {
+        init_logging();
+
         assert_eq!(do_hash(*b"HASH"), 52);
     }
 
@@ -149,6 +151,8 @@ mod test {
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -163,6 +167,8 @@ mod test {
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
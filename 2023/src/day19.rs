@@ -281,6 +281,8 @@ mod test {
     #[case("{x=2461,m=1339,a=466,s=291}", [2461,1339,466,291])]
     #[case("{x=2127,m=1623,a=2188,s=1013}", [2127,1623,2188,1013])]
     fn check_part_parsing(#[case] input: &str, #[case] expected: Part) {
+        init_logging();
+
         assert_eq!(parse_part(input), expected);
     }
 
@@ -294,6 +296,8 @@ mod test {
     #[case("x<1416:A", (X, Lt(1416), "A"))]
 
     fn check_rule_parsing(#[case] input: &str, #[case] expected: Rule) {
+        init_logging();
+
         assert_eq!(parse_rule(input), expected);
     }
 
@@ -325,6 +329,8 @@ hdj{m>838:A,pv}
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -339,6 +345,8 @@ hdj{m>838:A,pv}
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
@@ -275,6 +275,8 @@ mod test {
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(do_part1(steps, input), expected);
     }
@@ -299,6 +301,8 @@ mod test {
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         let res = do_part2(steps, input);
         assert_eq!(res, expected, "Off by {}", res - expected);
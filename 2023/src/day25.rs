@@ -178,6 +178,8 @@ frs: qnr lhk lsr";
         #[case] expected: i64,
         #[case] input: &str,
     ) {
+        init_logging();
+
         let input = input.trim();
         assert_eq!(p(input), expected);
     }
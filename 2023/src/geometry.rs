@@ -0,0 +1,95 @@
+//! Polygons on the integer lattice, and how many lattice points are inside of them
+//!
+//! Every polygon is a list of its corners in order, going either way around, and closes back up to the first one.
+//!
+//! ```
+//! use aoc23::geometry::interior_points;
+//!
+//! // Tiles enclosed by a loop that goes through the middle of every tile it touches
+//! let path = [[0, 0], [2, 0], [2, 2], [0, 2]];
+//! assert_eq!(interior_points(&path), 1);
+//! ```
+use ultraviolet::IVec2;
+
+/// Anything with integer `x` and `y` coordinates
+pub trait LatticePoint: Copy {
+    fn xy(self) -> [i64; 2];
+}
+
+impl LatticePoint for IVec2 {
+    fn xy(self) -> [i64; 2] {
+        [self.x as i64, self.y as i64]
+    }
+}
+
+impl LatticePoint for [i64; 2] {
+    fn xy(self) -> [i64; 2] {
+        self
+    }
+}
+
+impl LatticePoint for (i64, i64) {
+    fn xy(self) -> [i64; 2] {
+        [self.0, self.1]
+    }
+}
+
+/// Every edge of `poly`, including the one from the last corner back to the first
+fn edges<P: LatticePoint>(poly: &[P]) -> impl Iterator<Item = ([i64; 2], [i64; 2])> + '_ {
+    let next = poly.iter().skip(1).chain(poly.first());
+    poly.iter().zip(next).map(|(&a, &b)| (a.xy(), b.xy()))
+}
+
+/// Twice the area of `poly`, which is positive if it goes counter-clockwise (with +y going up)
+///
+/// This is the shoelace formula, and it's doubled so that it's always a whole number.
+pub fn twice_signed_area<P: LatticePoint>(poly: &[P]) -> i64 {
+    edges(poly)
+        .map(|([ax, ay], [bx, by])| ax * by - bx * ay)
+        .sum()
+}
+
+/// Twice the area of `poly`, whichever way it goes around
+pub fn twice_area<P: LatticePoint>(poly: &[P]) -> i64 {
+    twice_signed_area(poly).abs()
+}
+
+/// How many lattice points are on the edges of `poly`
+pub fn boundary_points<P: LatticePoint>(poly: &[P]) -> i64 {
+    edges(poly)
+        .map(|([ax, ay], [bx, by])| num::integer::gcd(bx - ax, by - ay))
+        .sum()
+}
+
+/// How many lattice points are strictly inside `poly`, by Pick's theorem
+///
+/// Pick's says `A = I + B/2 - 1`, so `I = A - B/2 + 1`. That's only true if `poly` doesn't cross itself.
+pub fn interior_points<P: LatticePoint>(poly: &[P]) -> i64 {
+    (twice_area(poly) - boundary_points(poly)) / 2 + 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    #[rstest]
+    #[case::unit_square(2, 4, 0, &[[0, 0], [1, 0], [1, 1], [0, 1]])]
+    #[case::clockwise_square(-2, 4, 0, &[[0, 0], [0, 1], [1, 1], [1, 0]])]
+    #[case::triangle(12, 8, 3, &[[0, 0], [4, 0], [0, 3]])]
+    #[case::collinear_corners(8, 8, 1, &[[0, 0], [1, 0], [2, 0], [2, 2], [0, 2]])]
+    #[trace]
+    fn check_area_and_picks(
+        #[case] expected_twice_signed: i64,
+        #[case] expected_boundary: i64,
+        #[case] expected_interior: i64,
+        #[case] poly: &[[i64; 2]],
+    ) {
+        assert_eq!(twice_signed_area(poly), expected_twice_signed);
+        assert_eq!(twice_area(poly), expected_twice_signed.abs());
+        assert_eq!(boundary_points(poly), expected_boundary);
+        assert_eq!(interior_points(poly), expected_interior);
+    }
+}
@@ -40,31 +40,35 @@ pub mod day22;
 pub mod day25;
 
 mod framebuffer;
+pub mod geometry;
+pub mod random_input;
 
 aoc_lib! { year = 2023 }
 
-// Run this function when the binary is loaded. This typically happens BEFORE MAIN.
-// This is a BAD IDEA, but cargo-aoc doesn't give us hooks anywhere else. So it's this or lazy-init in EVERY solution 😬.
-#[ctor::ctor]
-fn init_logging() {
-    use env_logger::{Builder, Env};
-
-    let mut env = Env::default();
-    if cfg!(test) || cfg!(debug_assertions) {
-        // Debug and test builds should log MORE
-        env = env.default_filter_or("debug");
-    } else {
-        // Everyone else can log warn and above
-        env = env.default_filter_or("warn");
-    }
+/// Called by the `main`, `check`, and `bench` binaries before running anything. See `registry!(..., setup = [...])`.
+pub fn init_logging() {
+    static LOGGING: std::sync::Once = std::sync::Once::new();
+
+    LOGGING.call_once(|| {
+        use env_logger::{Builder, Env};
+
+        let mut env = Env::default();
+        if cfg!(test) || cfg!(debug_assertions) {
+            // Debug and test builds should log MORE
+            env = env.default_filter_or("debug");
+        } else {
+            // Everyone else can log warn and above
+            env = env.default_filter_or("warn");
+        }
 
-    Builder::from_env(env)
-        .is_test(cfg!(test))
-        .format_timestamp(None)
-        .format_module_path(false)
-        .format_target(false)
-        .format_indent(Some(4))
-        .init();
+        Builder::from_env(env)
+            .is_test(cfg!(test))
+            .format_timestamp(None)
+            .format_module_path(false)
+            .format_target(false)
+            .format_indent(Some(4))
+            .init();
+    });
 }
 
 #[allow(unused_imports, non_upper_case_globals)]
@@ -86,6 +90,7 @@ mod prelude {
 
     pub use crate::framebuffer::Framebuffer;
     pub use crate::Cardinal;
+    pub use aoc_harness::cycle::{find_cycle, find_cycle_brent, nth_state, Cycle};
     pub const Norð: Cardinal = Cardinal::Norð;
     pub const Souð: Cardinal = Cardinal::Souð;
     pub const East: Cardinal = Cardinal::East;
@@ -94,6 +99,7 @@ mod prelude {
     pub use crate::fast_parse_u32;
     pub use crate::fast_parse_u64;
     pub use crate::fast_parse_u8;
+    pub use crate::init_logging;
     pub use crate::parse_list;
 }
 
@@ -0,0 +1,224 @@
+//! Random inputs for stress testing, shaped like the real ones but as big as we want.
+//!
+//! See `aoc_harness::scaling` for timing solutions on them with `bench --scale`.
+use crate::prelude::*;
+
+use aoc_harness::differential::{Rng, SliceRandom, StdRng};
+use aoc_harness::scaling::RandomInput;
+
+pub const ALL: &[RandomInput] = &[
+    RandomInput {
+        day: 10,
+        name: "pipe_loop",
+        generate: pipe_loop,
+    },
+    RandomInput {
+        day: 22,
+        name: "bricks",
+        generate: bricks,
+    },
+];
+
+/// Day 10's pipes, along with what the answers should be
+#[derive(Clone, Debug)]
+pub struct PipeLoop {
+    pub text: String,
+    /// How far the far side of the loop is from `S`
+    pub farthest: i64,
+    /// How many tiles are inside of the loop
+    pub enclosed: i64,
+}
+
+/// Day 10's pipes, with one big loop through `S` and junk pipes everywhere else. Real inputs are 140 tiles across, which is about size 35.
+pub fn pipe_loop(rng: &mut StdRng, size: usize) -> String {
+    pipe_loop_with_answers(rng, size).text
+}
+
+/// Day 10's pipes, built so that we know the answers without solving it
+///
+/// The loop wraps tightly around a random tree, like tracing around a maze's walls, which makes it twist around a
+/// lot. Everything inside of the loop is the tree, so that's what it encloses, and going around it is even.
+pub fn pipe_loop_with_answers(rng: &mut StdRng, size: usize) -> PipeLoop {
+    // The tree's nodes are `size` by `size`, and 4 tiles apart so the loop never touches itself
+    let k = size.max(1);
+    let dim = 4 * k + 1;
+    let node = |i: usize| 4 * i + 2;
+
+    let mut tree = vec![vec![false; dim]; dim];
+    let mut seen = vec![vec![false; k]; k];
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    seen[0][0] = true;
+    tree[node(0)][node(0)] = true;
+    while let Some(&(i, j)) = stack.last() {
+        let mut dirs = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+        dirs.shuffle(rng);
+
+        let next = dirs.into_iter().find_map(|(di, dj)| {
+            let i = i.checked_add_signed(di).filter(|&i| i < k)?;
+            let j = j.checked_add_signed(dj).filter(|&j| j < k)?;
+            (!seen[j][i]).then_some((i, j))
+        });
+        match next {
+            Some((ni, nj)) => {
+                // Fill in the corridor between them, which is in a straight line
+                let (x0, x1) = (node(i.min(ni)), node(i.max(ni)));
+                let (y0, y1) = (node(j.min(nj)), node(j.max(nj)));
+                for row in &mut tree[y0..=y1] {
+                    row[x0..=x1].fill(true);
+                }
+                seen[nj][ni] = true;
+                stack.push((ni, nj));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // The loop is every tile that touches the tree, even diagonally
+    let mut on_loop = vec![vec![false; dim]; dim];
+    for y in 1..dim - 1 {
+        for x in 1..dim - 1 {
+            on_loop[y][x] =
+                !tree[y][x] && (y - 1..=y + 1).any(|yy| (x - 1..=x + 1).any(|xx| tree[yy][xx]));
+        }
+    }
+
+    const JUNK: &[u8] = b"|-LJ7F.";
+    let mut text = vec![vec![b'.'; dim]; dim];
+    let mut loop_tiles = vec![];
+    for y in 0..dim {
+        for x in 0..dim {
+            if !on_loop[y][x] {
+                text[y][x] = *JUNK.choose(rng).unwrap();
+                continue;
+            }
+
+            let n = on_loop[y - 1][x];
+            let s = on_loop[y + 1][x];
+            let e = on_loop[y][x + 1];
+            let w = on_loop[y][x - 1];
+            text[y][x] = match (n, s, e, w) {
+                (true, true, false, false) => b'|',
+                (false, false, true, true) => b'-',
+                (true, false, true, false) => b'L',
+                (true, false, false, true) => b'J',
+                (false, true, false, true) => b'7',
+                (false, true, true, false) => b'F',
+                _ => unreachable!("({x}, {y}) isn't a simple loop: {:?}", (n, s, e, w)),
+            };
+            loop_tiles.push((x, y));
+        }
+    }
+
+    // Day 10 works out what `S` is from its neighbors, so junk can't point at it
+    let &(sx, sy) = loop_tiles.choose(rng).unwrap();
+    text[sy][sx] = b'S';
+    for (x, y) in [(sx + 1, sy), (sx - 1, sy), (sx, sy + 1), (sx, sy - 1)] {
+        if !on_loop[y][x] {
+            text[y][x] = b'.';
+        }
+    }
+
+    let enclosed = tree.iter().flatten().filter(|&&t| t).count();
+    PipeLoop {
+        text: text
+            .into_iter()
+            .map(|row| String::from_utf8(row).unwrap())
+            .join("\n"),
+        farthest: loop_tiles.len() as i64 / 2,
+        enclosed: enclosed as i64,
+    }
+}
+
+/// Day 22's bricks, with `size` of them stacked over a 10x10 floor. Real inputs have about 1,200.
+///
+/// Bricks are 1 to 4 cubes long in one direction, and don't overlap. Like the real ones, they're spread out over a tower
+/// about a quarter as tall as there are bricks, so everything has a ways to fall.
+pub fn bricks(rng: &mut StdRng, size: usize) -> String {
+    let count = size.max(1);
+    let top = count as i32 / 4 + 10;
+
+    let mut filled: HashSet<IVec3> = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < count {
+        let len = rng.gen_range(0..4);
+        let dir = match rng.gen_range(0..3) {
+            0 => IVec3::new(1, 0, 0),
+            1 => IVec3::new(0, 1, 0),
+            _ => IVec3::new(0, 0, 1),
+        };
+        let extent = dir * len;
+        let a = IVec3::new(
+            rng.gen_range(0..10 - extent.x),
+            rng.gen_range(0..10 - extent.y),
+            rng.gen_range(1..=top),
+        );
+        let b = a + extent;
+
+        let cubes = (0..=len).map(|t| a + dir * t).collect_vec();
+        if cubes.iter().any(|c| filled.contains(c)) {
+            continue;
+        }
+        filled.extend(cubes);
+
+        lines.push(format!("{},{},{}~{},{},{}", a.x, a.y, a.z, b.x, b.y, b.z));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_harness::differential::SeedableRng;
+    use aoc_harness::scaling::check_solvable;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn check_pipe_loop_answers() {
+        for size in 1..=8 {
+            let mut rng = StdRng::seed_from_u64(size as u64);
+            let pipes = pipe_loop_with_answers(&mut rng, size);
+
+            // The tree has a node per size^2, and each of its edges adds 3 tiles between them
+            let size = size as i64;
+            assert_eq!(pipes.enclosed, 4 * size * size - 3);
+
+            assert_eq!(crate::day10::part1(&pipes.text), pipes.farthest);
+            assert_eq!(crate::day10::part2(&pipes.text), pipes.enclosed);
+        }
+    }
+
+    #[test]
+    fn check_bricks() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let input = bricks(&mut rng, 100);
+        assert_eq!(input.lines().count(), 100);
+
+        for line in input.lines() {
+            let (a, b) = line.split_once('~').unwrap();
+            let a: [i32; 3] = parse_list(a, ",");
+            let b: [i32; 3] = parse_list(b, ",");
+            let diff = (0..3).filter(|&i| a[i] != b[i]).count();
+            assert!(diff <= 1, "{line} isn't in a line");
+            assert!(a[2] >= 1, "{line} is in the floor");
+        }
+    }
+
+    #[test]
+    fn check_random_inputs() {
+        check_solvable(ALL, &[1, 5, 10], |day, input| match day {
+            10 => {
+                crate::day10::part1(input);
+                crate::day10::part2(input);
+            }
+            22 => {
+                crate::day22::part1(input);
+                crate::day22::part2(input);
+            }
+            _ => unreachable!("day{day} needs a case here"),
+        });
+    }
+}
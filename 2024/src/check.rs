@@ -0,0 +1,6 @@
+use aoc_harness::registry;
+
+fn main() -> std::process::ExitCode {
+    let year = registry!(aoc24, setup = [aoc24::init_logging]);
+    aoc_harness::check::main(&year)
+}
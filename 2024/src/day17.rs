@@ -148,6 +148,7 @@ fn do_sim(mut regs: [i64; 3], program: &[u8]) -> SmallVec<u8> {
 mod test {
 
     use super::*;
+    use aoc_harness::differential::{Differential, Rng};
     #[allow(unused_imports)]
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
@@ -217,6 +218,18 @@ Program: 0,3,5,4,3,0
         }
     }
 
+    #[test]
+    fn check_jit_sim_random() {
+        const PROGRAM: [u8; 16] = [2, 4, 1, 1, 7, 5, 1, 5, 4, 0, 5, 5, 0, 3, 3, 0];
+        let sim = |&a: &i64| do_sim([a, 0, 0], &PROGRAM).to_vec();
+        let jit = |&a: &i64| do_sim__2_4__1_1__7_5__1_5__4_0__5_5__0_3__3_0(a).to_vec();
+
+        // Each output needs 3 more bits of A, up to the 16 outputs that part2 looks for
+        Differential::new(|rng, size| rng.gen_range(0..1_i64 << (3 * size.min(16))))
+            .max_size(16)
+            .check(&[("do_sim", &sim), ("do_sim__2_4__...", &jit)]);
+    }
+
     #[rstest]
     #[case::given(concat(&[4,6,3,5,6,3,5,2,1,0]), EXAMPLE_INPUT)]
     #[case::mini_ex_2(concat(&[0,1,2]), MINI_EX_2)]
@@ -16,7 +16,6 @@
     clippy::never_loop
 )]
 
-use std::mem::MaybeUninit;
 use std::str::FromStr;
 
 use aoc_runner_derive::aoc_lib;
@@ -47,16 +46,18 @@ pub mod day23;
 // pub mod day24;
 pub mod day25;
 
+pub mod random_input;
 pub mod utils;
 pub use utils::*;
 
+use utils::combinators::{short_type_name, Input, ParseError};
+
 aoc_lib! { year = 2024 }
 
 pub fn init_logging() {
     static LOGGING: std::sync::Once = std::sync::Once::new();
 
     LOGGING.call_once(|| {
-        println!("logging init'd");
         use env_logger::{Builder, Env};
 
         let mut env = Env::default();
@@ -120,6 +121,8 @@ pub mod prelude {
     pub use crate::parse_list;
     pub use crate::parse_list_whitespace;
     pub use crate::parse_or_fail;
+    pub use crate::try_parse_list;
+    pub use crate::try_parse_list_whitespace;
 
     pub use crate::IntParsable;
     pub use crate::Tally;
@@ -297,68 +300,84 @@ impl IntParsable for [u8] {
 }
 
 // TODO: Use Pattern when it's stable, https://doc.rust-lang.org/std/str/pattern/index.html?
+#[track_caller]
 pub fn parse_list<const N: usize, T>(s: &str, pattern: &str) -> [T; N]
 where
-    T: FromStr + Copy + Sized,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    let ty_name = std::any::type_name::<T>();
-    let mut list: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
-    let mut iter = s.split(pattern).enumerate();
-    for (i, t_s) in (&mut iter).take(N) {
-        list[i] = match t_s.parse() {
-            Ok(t) => MaybeUninit::new(t),
-            Err(e) => {
-                error!("While splitting \"{s}\" by \"{pattern}\", failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}");
-                unreachable!("While splitting \"{s}\" by \"{pattern}\", failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}");
-            }
-        };
-    }
+    try_parse_list(s, pattern).unwrap_or_else(|e| {
+        error!("While splitting {s:?} by {pattern:?}: {e}");
+        panic!("While splitting {s:?} by {pattern:?}: {e}");
+    })
+}
 
-    let rem = iter.count();
-    if rem != 0 {
-        error!(
-            str=s,
-            pattern=pattern;
-                "Trying to parse exactly {N} values of {ty_name}, but found {rem} more!",
-        );
-        unreachable!("Trying to parse exactly {N} values of {ty_name}, but found {rem} more!");
-    }
+#[track_caller]
+pub fn parse_list_whitespace<const N: usize, T>(s: &str) -> [T; N]
+where
+    T: FromStr,
+{
+    try_parse_list_whitespace(s).unwrap_or_else(|e| {
+        error!("While splitting {s:?} by whitespace: {e}");
+        panic!("While splitting {s:?} by whitespace: {e}");
+    })
+}
 
-    unsafe { std::mem::transmute_copy::<_, [T; N]>(&list) }
+pub fn try_parse_list<const N: usize, T>(s: &str, pattern: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+{
+    parse_pieces(s, s.split(pattern))
 }
 
-pub fn parse_list_whitespace<const N: usize, T>(s: &str) -> [T; N]
+pub fn try_parse_list_whitespace<const N: usize, T>(s: &str) -> Result<[T; N], ParseError>
 where
-    T: FromStr + Copy + Sized,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    let ty_name = std::any::type_name::<T>();
-    let mut list: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
-    let mut iter = s.split_whitespace().enumerate();
-    for (i, t_s) in (&mut iter).take(N) {
-        list[i] = match t_s.parse() {
-            Ok(t) => MaybeUninit::new(t),
-            Err(e) => {
-                error!("While splitting \"{s}\" by whitespace, failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}");
-                unreachable!("While splitting \"{s}\" by whitespace, failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}");
-            }
-        };
-    }
+    parse_pieces(s, s.split_whitespace())
+}
 
-    let rem = iter.count();
-    if rem != 0 {
-        error!(
-            str=s,
-            pattern="whitespace";
-                "Trying to parse exactly {N} values of {ty_name}, but found {rem} more!",
-        );
-        unreachable!("Trying to parse exactly {N} values of {ty_name}, but found {rem} more!");
+/// Parse exactly `N` pieces of `s`, reporting errors at the offending piece
+fn parse_pieces<'a, const N: usize, T>(
+    s: &'a str,
+    pieces: impl Iterator<Item = &'a str>,
+) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+{
+    let ty_name = short_type_name::<T>();
+    let error_at = |piece: &str, expected: String| {
+        let (line, col) = Input::new(s)
+            .advance(piece.as_ptr() as usize - s.as_ptr() as usize)
+            .line_col();
+        ParseError {
+            line,
+            col,
+            expected,
+            found: format!("{piece:?}"),
+        }
+    };
+
+    let mut list = Vec::with_capacity(N);
+    for (i, piece) in pieces.enumerate() {
+        if i == N {
+            return Err(error_at(piece, format!("exactly {N} values of {ty_name}")));
+        }
+        match piece.parse() {
+            Ok(t) => list.push(t),
+            Err(_) => return Err(error_at(piece, ty_name.to_string())),
+        }
     }
 
-    unsafe { std::mem::transmute_copy::<_, [T; N]>(&list) }
+    let found = list.len();
+    list.try_into().map_err(|_| {
+        let (line, col) = Input::new(s).advance(s.len()).line_col();
+        ParseError {
+            line,
+            col,
+            expected: format!("exactly {N} values of {ty_name}"),
+            found: format!("{found}"),
+        }
+    })
 }
 
 pub trait Tally<T>
@@ -384,7 +403,7 @@ where
 
 pub fn parse_or_fail<T: FromStr>(s: impl AsRef<str>) -> T {
     let s: &str = s.as_ref();
-    match s.parse() {
+    match try_parse(s) {
         Ok(t) => t,
         Err(_err) => panic!(
             "Failed to parse \"{s}\" as a {}",
@@ -393,6 +412,20 @@ pub fn parse_or_fail<T: FromStr>(s: impl AsRef<str>) -> T {
     }
 }
 
+/// Like [`parse_or_fail()`], but for input that might not parse
+pub fn try_parse<T: FromStr>(s: impl AsRef<str>) -> Result<T, ParseError> {
+    let s: &str = s.as_ref();
+    s.parse().map_err(|_| {
+        let (line, col) = Input::new(s).line_col();
+        ParseError {
+            line,
+            col,
+            expected: short_type_name::<T>().to_string(),
+            found: format!("{s:?}"),
+        }
+    })
+}
+
 #[cfg(test)]
 mod util_tests {
     use super::*;
@@ -400,6 +433,27 @@ mod util_tests {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
+    #[test]
+    fn check_try_parse() {
+        assert_eq!(try_parse::<u32>("1234"), Ok(1234));
+        assert_eq!(
+            try_parse::<u32>("-1").unwrap_err().to_string(),
+            "line 1 col 1: expected u32, found \"-1\""
+        );
+    }
+
+    #[rstest]
+    #[case::bad_elem("10,x,1", "line 1 col 4: expected i32, found \"x\"")]
+    #[case::too_many(
+        "10,2,1,4",
+        "line 1 col 8: expected exactly 3 values of i32, found \"4\""
+    )]
+    #[case::too_few("10,2", "line 1 col 5: expected exactly 3 values of i32, found 2")]
+    fn check_try_parse_list_errors(#[case] s: &str, #[case] expected: &str) {
+        let err = try_parse_list::<3, i32>(s, ",").unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
     #[test]
     fn check_parse_list() {
         {
@@ -0,0 +1,142 @@
+//! Random inputs for stress testing, shaped like the real ones but as big as we want.
+//!
+//! See `aoc_harness::scaling` for timing solutions on them with `bench --scale`.
+use crate::prelude::*;
+
+use aoc_harness::differential::{Rng, SliceRandom, StdRng};
+use aoc_harness::scaling::RandomInput;
+
+pub const ALL: &[RandomInput] = &[
+    RandomInput {
+        day: 9,
+        name: "disk_map",
+        generate: disk_map,
+    },
+    RandomInput {
+        day: 16,
+        name: "maze",
+        generate: reindeer_maze,
+    },
+];
+
+/// A maze of `#` and `.` for [`AocGridGraph`], with odd coordinates as its cells and a wall all the way around.
+///
+/// It starts as a perfect maze, with exactly one path between any two cells, and then `loops` walls are knocked out
+/// so that there's more than one way around.
+pub fn maze(rng: &mut StdRng, cells: usize, loops: usize) -> Framebuffer<char> {
+    let dim = 2 * cells as u32 + 1;
+    let mut map = Framebuffer::new(dim, dim);
+    map.clear('#');
+    map.set_border_color(Some('#'));
+
+    // Randomized depth-first search, carving out the wall between each cell and the next
+    let mut stack = vec![IVec2::new(1, 1)];
+    map[(1, 1)] = '.';
+    while let Some(&curr) = stack.last() {
+        let mut dirs = [
+            IVec2::new(2, 0),
+            IVec2::new(-2, 0),
+            IVec2::new(0, 2),
+            IVec2::new(0, -2),
+        ];
+        dirs.shuffle(rng);
+
+        let next = dirs
+            .into_iter()
+            .map(|d| curr + d)
+            .find(|&next| map.in_bounds(next) && map[next] == '#');
+        match next {
+            Some(next) => {
+                map[(curr + next) / 2] = '.';
+                map[next] = '.';
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    // Walls between two cells are at one odd and one even coordinate
+    for _ in 0..loops {
+        let x = rng.gen_range(1..dim as i32 - 1);
+        let y = rng.gen_range(1..dim as i32 - 1);
+        if (x + y) % 2 == 1 {
+            map[(x, y)] = '.';
+        }
+    }
+
+    map
+}
+
+fn to_text(map: &Framebuffer<char>) -> String {
+    map.range_y()
+        .map(|y| map.range_x().map(|x| map[(x, y)]).collect::<String>())
+        .join("\n")
+}
+
+/// Day 16's maze, from `S` in the bottom left to `E` in the top right. My input's is about size 70.
+pub fn reindeer_maze(rng: &mut StdRng, size: usize) -> String {
+    let cells = size.max(2);
+    let mut map = maze(rng, cells, cells * cells / 10);
+
+    let far = 2 * cells as i32 - 1;
+    map[(1, far)] = 'S';
+    map[(far, 1)] = 'E';
+
+    to_text(&map)
+}
+
+/// Day 9's disk map, alternating files and free space and ending with a file. My input has 10,000 files.
+pub fn disk_map(rng: &mut StdRng, size: usize) -> String {
+    // part1 keeps file ids in an i16
+    let files = size.clamp(1, i16::MAX as usize);
+
+    let mut map = String::with_capacity(2 * files);
+    for i in 0..files {
+        if i != 0 {
+            map.push(char::from(b'0' + rng.gen_range(0..=9)));
+        }
+        map.push(char::from(b'0' + rng.gen_range(1..=9)));
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_harness::differential::SeedableRng;
+    use aoc_harness::scaling::check_solvable;
+
+    #[test]
+    fn check_maze() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let map = maze(&mut rng, 10, 0);
+        assert_eq!(map.width(), 21);
+
+        // A perfect maze on 10x10 cells is a tree, so it has 99 passages between them
+        let open = map.flatten().filter(|&&c| c == '.').count();
+        assert_eq!(open, 100 + 99);
+
+        let mut graph = AocGridGraph::new(map);
+        let far = IVec2::new(19, 19);
+        assert!(dijkstra(&mut graph, IVec2::new(1, 1), Some(far)).is_some());
+    }
+
+    #[test]
+    fn check_random_inputs() {
+        // day9 prints its blocks in tests, one digit per file id
+        check_solvable(ALL, &[1, 5, 9], |day, input| match day {
+            9 => {
+                crate::day09::part1(input);
+                crate::day09::part2(input);
+            }
+            16 => {
+                crate::day16::part1(input);
+                crate::day16::part2(input);
+            }
+            _ => unreachable!("day{day} needs a case here"),
+        });
+    }
+}
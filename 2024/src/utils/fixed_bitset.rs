@@ -20,7 +20,7 @@ impl Backing for u32 {}
 impl Backing for u64 {}
 impl Backing for u128 {}
 
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
 pub struct FixedBitset<N: Backing> {
     bits: N,
 }
@@ -76,6 +76,67 @@ impl<N: Backing> FixedBitset<N> {
         (0..Self::bit_width()).filter(|&i| self.contains(i))
     }
 
+    /// Everything in either set
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Everything in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Everything in this set that isn't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Everything in exactly one of the sets
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits ^ other.bits,
+        }
+    }
+
+    /// Whether everything in this set is also in `other`
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Whether nothing is in both sets
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Every subset of this set, from all of it down to none of it. There are `2^self.len()` of them.
+    pub fn subsets(&self) -> FixedBitsetSubsets<N> {
+        FixedBitsetSubsets {
+            set: self.bits,
+            next: Some(self.bits),
+        }
+    }
+
+    /// Every subset of this set with `k` things in it, in lexicographic order of what's picked
+    pub fn combinations(&self, k: usize) -> FixedBitsetCombinations<N> {
+        let elems: Vec<u32> = self.iter().collect();
+        FixedBitsetCombinations {
+            done: k > elems.len(),
+            picked: (0..k).collect(),
+            elems,
+            _set: std::marker::PhantomData,
+        }
+    }
+
     fn bit_width() -> u32 {
         N::ZERO.count_zeros()
     }
@@ -132,6 +193,112 @@ impl<N: Backing> IntoIterator for FixedBitset<N> {
     }
 }
 
+/// See [`FixedBitset::subsets()`]
+#[derive(Copy, Clone, Debug)]
+pub struct FixedBitsetSubsets<N: Backing> {
+    set: N,
+    next: Option<N>,
+}
+
+impl<N: Backing> Iterator for FixedBitsetSubsets<N> {
+    type Item = FixedBitset<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = self.next?;
+
+        // The submask trick: subtracting 1 clears the lowest bit and sets all of the ones below it, and then the set
+        // keeps just its own
+        self.next = if curr == N::ZERO {
+            None
+        } else {
+            Some((curr - N::ONE) & self.set)
+        };
+
+        Some(FixedBitset { bits: curr })
+    }
+}
+
+/// See [`FixedBitset::combinations()`]
+#[derive(Clone, Debug)]
+pub struct FixedBitsetCombinations<N: Backing> {
+    elems: Vec<u32>,
+    /// Indexes into `elems`, always increasing
+    picked: Vec<usize>,
+    done: bool,
+    _set: std::marker::PhantomData<N>,
+}
+
+impl<N: Backing> Iterator for FixedBitsetCombinations<N> {
+    type Item = FixedBitset<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut curr = FixedBitset::new();
+        for &i in &self.picked {
+            curr.insert(self.elems[i]);
+        }
+
+        // Bump the last pick that still has room, and restart everything after it right behind it
+        let (n, k) = (self.elems.len(), self.picked.len());
+        match (0..k).rev().find(|&i| self.picked[i] < n - k + i) {
+            Some(i) => {
+                self.picked[i] += 1;
+                for j in i + 1..k {
+                    self.picked[j] = self.picked[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(curr)
+    }
+}
+
+impl<N: Backing> ops::BitOr for FixedBitset<N> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl<N: Backing> ops::BitAnd for FixedBitset<N> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl<N: Backing> ops::BitXor for FixedBitset<N> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl<N: Backing> ops::Sub for FixedBitset<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+impl<N: Backing> FromIterator<u32> for FixedBitset<N> {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
 pub type Bitset128 = FixedBitset<u128>;
 pub type Bitset64 = FixedBitset<u64>;
 pub type Bitset32 = FixedBitset<u32>;
@@ -143,6 +310,66 @@ mod test {
     use super::*;
     use itertools::Itertools;
 
+    #[test]
+    fn check_set_algebra() {
+        let evens: Bitset64 = (0..64).step_by(2).collect();
+        let threes: Bitset64 = (0..64).step_by(3).collect();
+        let fours: Bitset64 = (0..64).step_by(4).collect();
+
+        assert_eq!(
+            (evens & threes).iter().collect_vec(),
+            (0..64).step_by(6).collect_vec()
+        );
+        assert_eq!(
+            (evens | threes).iter().collect_vec(),
+            (0..64).filter(|i| i % 2 == 0 || i % 3 == 0).collect_vec()
+        );
+        assert_eq!(
+            (evens - threes).iter().collect_vec(),
+            (0..64).filter(|i| i % 2 == 0 && i % 3 != 0).collect_vec()
+        );
+        assert_eq!(
+            (evens ^ threes).iter().collect_vec(),
+            (0..64)
+                .filter(|i| (i % 2 == 0) != (i % 3 == 0))
+                .collect_vec()
+        );
+
+        assert!(fours.is_subset(&evens));
+        assert!(evens.is_superset(&fours));
+        assert!(!evens.is_subset(&threes));
+        assert!((evens - threes).is_disjoint(&threes));
+    }
+
+    #[test]
+    fn check_subsets() {
+        let set: Bitset16 = [0, 3, 4, 15].into_iter().collect();
+        let subsets = set.subsets().collect_vec();
+
+        assert_eq!(subsets.len(), 16);
+        assert_eq!(subsets.first(), Some(&set));
+        assert_eq!(subsets.last(), Some(&Bitset16::new()));
+        assert!(subsets.iter().all(|sub| sub.is_subset(&set)));
+        assert_eq!(subsets.iter().unique().count(), 16);
+    }
+
+    #[test]
+    fn check_combinations() {
+        let set: Bitset128 = [3, 70, 100, 120, 127].into_iter().collect();
+
+        let pairs = set.combinations(2).collect_vec();
+        assert_eq!(pairs.len(), 10);
+        assert!(pairs
+            .iter()
+            .all(|pair| pair.len() == 2 && pair.is_subset(&set)));
+        assert_eq!(pairs.iter().unique().count(), 10);
+        assert_eq!(pairs[0].iter().collect_vec(), [3, 70]);
+
+        assert_eq!(set.combinations(0).collect_vec(), [Bitset128::new()]);
+        assert_eq!(set.combinations(5).collect_vec(), [set]);
+        assert_eq!(set.combinations(6).count(), 0);
+    }
+
     #[allow(clippy::bool_assert_comparison)]
     #[generic_tests::define]
     mod bitset_generic_tests {
@@ -1,3 +1,4 @@
+pub mod combinators;
 pub mod fixed_bitset;
 pub mod framebuffer;
 pub mod graph;
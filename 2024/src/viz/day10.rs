@@ -0,0 +1,49 @@
+use aoc24::framebuffer::Framebuffer;
+use image::Rgb;
+
+use aoc_harness::viz::{Ctx, Frames, Viz};
+
+const EXAMPLE_JUST_9: &str = r"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+pub struct Day10;
+
+impl Viz for Day10 {
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn about(&self) -> &'static str {
+        "Heightmap of the hiking trails, with trailheads and peaks highlighted"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(EXAMPLE_JUST_9)
+    }
+
+    fn render(&self, input: &str, _ctx: &Ctx, frames: &mut Frames) {
+        let heights = Framebuffer::parse_grid(input, |c| c as u8 - b'0');
+
+        frames.push(heights.make_image(1, |&h| {
+            let x: f32 = (h as f32 + 2.) / 12.;
+            let x = x + 0.3;
+            let r: u8 = (0x26 as f32 * x) as u8;
+            let g: u8 = (0x46 as f32 * x) as u8;
+            let b: u8 = (0x53 as f32 * x) as u8;
+
+            match h {
+                0 => Rgb([0, 0, 0]),
+                9 => Rgb([0xff, 0xff, 0x66]),
+                _ => Rgb([r, g, b]),
+            }
+        }));
+    }
+}
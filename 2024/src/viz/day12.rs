@@ -0,0 +1,94 @@
+use aoc24::day12::*;
+use aoc24::prelude::*;
+
+use clap::{Args, Command};
+use image::Rgb;
+
+use aoc_harness::viz::{Ctx, Frames, Viz};
+
+const EXAMPLE_INPUT: &str = r"
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+#[derive(Debug, Args)]
+struct Day12Args {
+    /// Only draw the plots that are on the edge of their region
+    #[arg(long)]
+    edges: bool,
+}
+
+pub struct Day12;
+
+impl Viz for Day12 {
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn about(&self) -> &'static str {
+        "Garden plots, colored by plant type"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+
+    fn args(&self, cmd: Command) -> Command {
+        Day12Args::augment_args(cmd)
+    }
+
+    fn render(&self, input: &str, ctx: &Ctx, frames: &mut Frames) {
+        let args: Day12Args = ctx.args();
+
+        let mut plots = Framebuffer::parse_grid_char(input);
+        plots.set_border_color(Some('@'));
+
+        if args.edges {
+            let mut edges = plots.clone();
+            for p in edges.iter_coords() {
+                if !is_edge(&plots, p.into()) {
+                    edges[p] = '.';
+                }
+            }
+            plots = edges;
+        }
+
+        frames.push(plots.make_image(1, |&plot| {
+            if plot == '.' {
+                Rgb([0_u8, 0, 0])
+            } else {
+                Rgb(make_color(plot as u8, 26))
+            }
+        }));
+    }
+}
+
+fn make_color(i: u8, n: usize) -> [u8; 3] {
+    let hue = (i as f32 * 360.0 / n as f32) % 360.0;
+    let c = 0.63;
+    let x = c * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = 0.27;
+
+    let (r, g, b) = match hue as u32 {
+        0..=59 => (c, x, 0.0),
+        60..=119 => (x, c, 0.0),
+        120..=179 => (0.0, c, x),
+        180..=239 => (0.0, x, c),
+        240..=299 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    [
+        ((r + m) * 255.0) as u8,
+        ((g + m) * 255.0) as u8,
+        ((b + m) * 255.0) as u8,
+    ]
+}
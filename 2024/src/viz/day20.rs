@@ -0,0 +1,121 @@
+use aoc24::prelude::*;
+
+use clap::{Args, Command};
+use image::imageops;
+
+use aoc_harness::viz::{Ctx, Frames, Viz};
+
+const EXAMPLE_INPUT: &str = r"
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+#[derive(Debug, Args)]
+struct Day20Args {
+    /// When set, the generated image will have a shortest path colored.
+    #[arg(long)]
+    solve: bool,
+}
+
+pub struct Day20;
+
+impl Viz for Day20 {
+    fn day(&self) -> u32 {
+        20
+    }
+
+    fn about(&self) -> &'static str {
+        "The racetrack, and optionally the path through it"
+    }
+
+    fn example(&self) -> Option<&'static str> {
+        Some(EXAMPLE_INPUT)
+    }
+
+    fn args(&self, cmd: Command) -> Command {
+        Day20Args::augment_args(cmd)
+    }
+
+    fn render(&self, input: &str, ctx: &Ctx, frames: &mut Frames) {
+        let args: Day20Args = ctx.args();
+
+        let mut start = IVec2::zero();
+        let mut end = IVec2::zero();
+        let mut map = Framebuffer::parse_grid2(input, |ParsingInfo { c, x, y }| {
+            match c {
+                'S' => start = IVec2::new(x, y),
+                'E' => end = IVec2::new(x, y),
+                '.' => {}
+                '#' => {}
+                _ => unreachable!(),
+            }
+            c
+        });
+
+        if args.solve {
+            println!("Attempting to solve...");
+
+            let mut path = vec![start];
+            let mut i = 0;
+            'search: loop {
+                let curr = path[i];
+                i += 1;
+
+                for next in curr.neighbors() {
+                    if i > 2 && path[i - 2] == next {
+                        continue;
+                    }
+
+                    match map[next] {
+                        '#' => continue,
+                        '.' => {
+                            path.push(next);
+                            break;
+                        }
+                        'S' => unreachable!(),
+                        'E' => {
+                            path.push(next);
+                            break 'search;
+                        }
+                        c => unreachable!("Unrecognized map character {c}"),
+                    }
+                }
+            }
+
+            println!("Found path of length {}", path.len());
+            for &p in &path {
+                if p == start {
+                    continue;
+                }
+                if p == end {
+                    continue;
+                }
+                map[p] = 'O';
+            }
+        }
+
+        let mut palette = [Rgb([0, 0, 0_u8]); 128];
+        palette[b'#' as usize] = AOC_DARK_GRAY;
+        palette[b'.' as usize] = AOC_BLUE;
+        palette[b'S' as usize] = START_GREEN;
+        palette[b'E' as usize] = FINAL_RED;
+        palette[b'O' as usize] = AOC_GOLD;
+
+        let mut img = map.make_image(1, |&c| palette[c as usize]);
+        imageops::flip_vertical_in_place(&mut img);
+        frames.push(img);
+    }
+}
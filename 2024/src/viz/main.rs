@@ -0,0 +1,18 @@
+//! One binary for all of this year's visualizations.
+//!
+//! Each day implements [`Viz`] and is listed in [`VIZZES`]. Every day becomes a subcommand, and `aoc_harness::viz`
+//! does the rest:
+//! ```text
+//! $ cargo run --release --bin viz -- day20 --example --solve --format svg
+//! ```
+use aoc_harness::viz::Viz;
+
+mod day10;
+mod day12;
+mod day20;
+
+const VIZZES: &[&dyn Viz] = &[&day10::Day10, &day12::Day12, &day20::Day20];
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    aoc_harness::viz::main(&aoc_harness::registry!(aoc24), VIZZES)
+}
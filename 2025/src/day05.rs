@@ -9,9 +9,7 @@ pub fn part1(input: &str) -> i64 {
     let n = memmem::find(input, b"\n\n").expect("Couldn't find \\n\\n");
     let (ranges_text, ids_text) = input.split_at(n);
 
-    let mut ranges: Vec<(i64, i64)> = ranges_text.i64s().tuples().map(|(a, b)| (a, -b)).collect();
-    ranges.sort();
-    let ranges = merge_ranges(ranges);
+    let ranges: IntervalSet = ranges_text.i64s().tuples().map(|(a, b)| a..=-b).collect();
 
     let ids: Vec<i64> = ids_text.i64s().collect();
 
@@ -19,16 +17,7 @@ pub fn part1(input: &str) -> i64 {
         println!("{:>8}={:?}", "ranges", ranges);
         println!("{:>8}={:?}", "ids", ids);
     }
-    ids.into_iter()
-        .filter(|id| {
-            for (a, b) in &ranges {
-                if (a..=b).contains(&id) {
-                    return true;
-                }
-            }
-            false
-        })
-        .count() as i64
+    ids.into_iter().filter(|&id| ranges.contains(id)).count() as i64
 }
 
 // Part2 ========================================================================
@@ -38,13 +27,8 @@ pub fn part2(input: &str) -> i64 {
     let n = memmem::find(input, b"\n\n").expect("Couldn't find \\n\\n");
     let input = &input[..n];
 
-    let mut ranges: Vec<(i64, i64)> = input.i64s().tuples().map(|(a, b)| (a, -b)).collect();
-    ranges.sort();
-
-    merge_ranges(ranges)
-        .into_iter()
-        .map(|(a, b)| b - a + 1)
-        .sum()
+    let ranges: IntervalSet = input.i64s().tuples().map(|(a, b)| a..=-b).collect();
+    ranges.total_len()
 }
 
 #[aoc(day5, part2, flat)]
@@ -34,6 +34,35 @@ pub fn part1(input: &str) -> i64 {
     splits
 }
 
+#[aoc(day7, part1, grid_view)]
+pub fn part1_grid_view(input: &str) -> i64 {
+    let grid = GridView::new(input);
+    let mut beams = Bitset256::new();
+    beams.insert(grid.find(b'S').unwrap().x as u32);
+
+    let mut splits = 0;
+
+    for y in (0..grid.height() as i32).rev() {
+        let row = grid.row(y);
+
+        let mut next = Bitset256::new();
+        for x in 0..grid.width() as i64 {
+            if beams.contains(x) {
+                if row[x as usize] == b'^' {
+                    splits += 1;
+                    next.insert(x - 1);
+                    next.insert(x + 1);
+                } else {
+                    next.insert(x);
+                }
+            }
+        }
+        beams = next;
+    }
+
+    splits
+}
+
 // Part2 ========================================================================
 #[derive(Debug)]
 struct SplitterRow {
@@ -198,80 +227,37 @@ mod test {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
-    const EXAMPLE_INPUT: &str = r"
-.......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-";
-
-    const SMOL_INPUT: &str = r"
-..S..
-..^..
-.^.^.
-";
-
-    const SMOL_INPUT_2: &str = r"
-...S...
-...^...
-..^.^..
-.^.^.^.
-";
-
-    const SMOL_BUT_MISSES_INPUT: &str = r"
-...S..
-...^..
-.^....
-....^.
-";
+    use std::path::PathBuf;
 
     #[rstest]
-    #[case::given(21, EXAMPLE_INPUT)]
-    #[case::smol(3, SMOL_INPUT)]
     #[timeout(Duration::from_millis(1))]
     #[trace]
-    fn check_ex_part_1(
+    fn check_examples_part_1(
+        #[files("input/2025/examples/day7-*.txt")]
+        #[exclude("day7-smol2|day7-smol_but_misses")]
+        path: PathBuf,
         #[notrace]
-        #[values(part1)]
+        #[values(part1, part1_grid_view)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
         init_logging();
 
-        let input = input.trim();
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 1, p);
     }
 
     #[rstest]
-    #[case::given(40, EXAMPLE_INPUT)]
-    #[case::smol1(4, SMOL_INPUT)]
-    #[case::smol2(1+3+3+1, SMOL_INPUT_2)]
-    // #[case::smol_but_misses(2, SMOL_BUT_MISSES_INPUT)] // seems sus
     #[timeout(Duration::from_millis(100))]
     #[trace]
-    fn check_ex_part_2(
+    fn check_examples_part_2(
+        #[files("input/2025/examples/day7-*.txt")]
+        #[exclude("day7-smol_but_misses")]
+        path: PathBuf,
         #[notrace]
         #[values(part2)]
         p: impl FnOnce(&str) -> i64,
-        #[case] expected: i64,
-        #[case] input: &str,
     ) {
         init_logging();
 
-        let input = input.trim();
-        assert_eq!(p(input), expected);
+        aoc_harness::examples::check(&path, 2, p);
     }
 }
@@ -145,6 +145,44 @@ pub fn part1_more_vec(input: &str) -> i64 {
     ans as i64
 }
 
+/// Asks a k-d tree for the closest pairs one at a time, instead of working out all of them up front
+#[aoc(day8, part1, kd_tree)]
+pub fn part1_kd_tree(input: &str) -> i64 {
+    use crate::util::spatial::{KdTree, Metric};
+
+    let pts: Vec<[i64; 3]> = input
+        .i64s()
+        .tuples()
+        .map(|(x, y, z)| [x, y, z])
+        .collect_vec();
+    let tree = KdTree::new(pts.iter().copied());
+
+    let mut id_to_cid: Vec<usize> = (0..pts.len()).collect_vec();
+    for (_dist, a, b) in tree.pairs_by_distance(Metric::Euclidean).take(ITER_COUNT) {
+        let cid_a = id_to_cid[a];
+        let cid_b = id_to_cid[b];
+        if cid_a != cid_b {
+            let new = usize::min(cid_a, cid_b);
+            let old = usize::max(cid_a, cid_b);
+
+            for cid in &mut id_to_cid {
+                if *cid == old {
+                    *cid = new;
+                }
+            }
+        }
+    }
+
+    let counts = id_to_cid.into_iter().tally();
+    let mut counts: BinaryHeap<_> = counts.values().copied().collect();
+    let mut ans = 1;
+    ans *= counts.pop().unwrap_or(0);
+    ans *= counts.pop().unwrap_or(0);
+    ans *= counts.pop().unwrap_or(0);
+
+    ans as i64
+}
+
 // Part2 ========================================================================
 #[aoc(day8, part2)]
 pub fn part2(input: &str) -> i64 {
@@ -253,6 +291,45 @@ pub fn part2_more_vec(input: &str) -> i64 {
     unreachable!()
 }
 
+/// Asks a k-d tree for the closest pairs one at a time, so it stops as soon as everything is connected
+#[aoc(day8, part2, kd_tree)]
+pub fn part2_kd_tree(input: &str) -> i64 {
+    use crate::util::spatial::{KdTree, Metric};
+
+    let pts: Vec<[i64; 3]> = input
+        .i64s()
+        .tuples()
+        .map(|(x, y, z)| [x, y, z])
+        .collect_vec();
+    let tree = KdTree::new(pts.iter().copied());
+
+    let mut id_to_cid: Vec<usize> = (0..pts.len()).collect_vec();
+    let mut cid_0_count = 1;
+    for (_dist, a, b) in tree.pairs_by_distance(Metric::Euclidean) {
+        let cid_a = id_to_cid[a];
+        let cid_b = id_to_cid[b];
+        if cid_a != cid_b {
+            let new = usize::min(cid_a, cid_b);
+            let old = usize::max(cid_a, cid_b);
+
+            for cid in &mut id_to_cid {
+                if *cid == old {
+                    *cid = new;
+                    if new == 0 {
+                        cid_0_count += 1;
+                    }
+                }
+            }
+
+            if cid_0_count == id_to_cid.len() {
+                return pts[a][0] * pts[b][0];
+            }
+        }
+    }
+
+    unreachable!()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -289,7 +366,7 @@ mod test {
     #[timeout(Duration::from_millis(2))]
     fn check_ex_part_1(
         #[notrace]
-        #[values(part1, part1_more_vec)]
+        #[values(part1, part1_more_vec, part1_kd_tree)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -306,7 +383,7 @@ mod test {
     #[timeout(Duration::from_millis(2))]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2, part2_more_vec)]
+        #[values(part2, part2_more_vec, part2_kd_tree)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -94,6 +94,45 @@ pub fn part2(input: &str) -> i64 {
         .unwrap()
 }
 
+/// A rectangle is all red and green if the loop never cuts through its middle, and its middle is inside the loop
+#[aoc(day9, part2, geometry)]
+pub fn part2_geometry(input: &str) -> i64 {
+    use crate::util::geometry::{Rect, point_in_rectilinear};
+
+    let red_tiles: Vec<[i64; 2]> = input.i64s().tuples().map(|(x, y)| [x, y]).collect();
+    let edges = red_tiles
+        .iter()
+        .copied()
+        .circular_tuple_windows()
+        .collect_vec();
+
+    // Doubled, so that the middle of every rectangle is a lattice point
+    let doubled = red_tiles.iter().map(|p| p.map(|c| 2 * c)).collect_vec();
+
+    let mut rects = red_tiles
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| Rect::from_corners(a, b))
+        .collect_vec();
+    rects.sort_unstable_by_key(|rect| Reverse(rect.area()));
+
+    rects
+        .into_iter()
+        .find(|rect| {
+            // Tiles on the edge of the rectangle can be on the loop, but none inside of it can
+            if let Some(inner) = rect.shrink(1) {
+                if edges.iter().any(|&(a, b)| inner.intersects_segment(a, b)) {
+                    return false;
+                }
+            }
+
+            let middle = [rect.min[0] + rect.max[0], rect.min[1] + rect.max[1]];
+            point_in_rectilinear(&doubled, middle).is_covered()
+        })
+        .expect("No rectangle fits inside the loop")
+        .area()
+}
+
 fn print_verts(verts: &[IVec2], edges: &[(IVec2, IVec2)]) {
     if cfg!(test) {
         let nx = verts.iter().map(|v| v.x).max().unwrap() as u32;
@@ -218,7 +257,7 @@ mod test {
     #[timeout(Duration::from_millis(1000))]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2)]
+        #[values(part2, part2_geometry)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -94,6 +94,79 @@ pub fn part1(input: &str) -> i64 {
     total_presses
 }
 
+/// Pressing a button twice undoes it, so the fewest presses is the fewest buttons that XOR to the goal
+#[aoc(day10, part1, combinations)]
+pub fn part1_combinations(input: &str) -> i64 {
+    use crate::util::bitmask::combinations;
+
+    let mut total_presses = 0;
+    for line in input.lines() {
+        // "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+        let mut words = line.split(' ');
+
+        let lights = words.next().unwrap().trim_matches(['[', ']']);
+        let goal: Bitset64 = lights.bytes().positions(|b| b == b'#').collect();
+
+        let buttons: Vec<Bitset64> = words
+            .filter(|w| w.starts_with('('))
+            .map(|w| {
+                w.trim_matches(['(', ')'])
+                    .split(',')
+                    .map(|n| n.parse::<u32>().unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let n = buttons.len() as u32;
+        let presses = (0..=n)
+            .find(|&k| {
+                combinations(n, k).any(|pressed| {
+                    let lit = Bitset64::from_words([pressed])
+                        .iter()
+                        .fold(Bitset64::new(), |lit, i| lit ^ buttons[i as usize]);
+                    lit == goal
+                })
+            })
+            .expect("No buttons light it up");
+
+        total_presses += presses as i64;
+    }
+
+    total_presses
+}
+
+/// Pressing a button twice undoes it, so this is a system of equations over GF(2): find its lightest solution
+#[aoc(day10, part1, gf2)]
+pub fn part1_gf2(input: &str) -> i64 {
+    use crate::util::linalg::solve_gf2;
+
+    let mut total_presses = 0;
+    for line in input.lines() {
+        // "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+        let mut words = line.split(' ');
+
+        let lights = words.next().unwrap().trim_matches(['[', ']']);
+        let goal = lights
+            .bytes()
+            .positions(|b| b == b'#')
+            .fold(0_u64, |goal, i| goal | (1 << i));
+
+        let buttons: Vec<u64> = words
+            .filter(|w| w.starts_with('('))
+            .map(|w| {
+                w.trim_matches(['(', ')'])
+                    .split(',')
+                    .fold(0, |btn, n| btn | (1 << n.parse::<u32>().unwrap()))
+            })
+            .collect();
+
+        let solution = solve_gf2(&buttons, goal).expect("No buttons light it up");
+        total_presses += solution.min_weight().count_ones() as i64;
+    }
+
+    total_presses
+}
+
 // Part2 ========================================================================
 
 // Dedicated function to remove it from the parsing nonsense
@@ -218,6 +291,34 @@ pub fn part2(input: &str) -> i64 {
     total_presses
 }
 
+/// Each counter is an equation and each button a variable, so solve it exactly instead of with floats
+#[aoc(day10, part2, exact)]
+pub fn part2_exact(input: &str) -> i64 {
+    use crate::util::linalg::min_nonneg_integer_solution;
+
+    let mut total_presses = 0;
+    for line in input.lines() {
+        // "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+        let (buttons, goal) = line.split_once(" {").unwrap();
+        let goal = goal.i64s().collect_vec();
+
+        let buttons = buttons.split(' ').skip(1).collect_vec();
+        let mut a = vec![vec![0; buttons.len()]; goal.len()];
+        for (j, button) in buttons.iter().enumerate() {
+            for i in button.i64s() {
+                a[i as usize][j] = 1;
+            }
+        }
+
+        let cost = vec![1; buttons.len()];
+        let (presses, _counts) =
+            min_nonneg_integer_solution(&a, &goal, &cost).expect("No way to reach the goal");
+        total_presses += presses;
+    }
+
+    total_presses
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -240,7 +341,7 @@ mod test {
     #[timeout(Duration::from_millis(100))]
     fn check_ex_part_1(
         #[notrace]
-        #[values(part1)]
+        #[values(part1, part1_combinations, part1_gf2)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -272,7 +373,7 @@ mod test {
     #[timeout(Duration::from_millis(100))]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2)]
+        #[values(part2, part2_exact)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
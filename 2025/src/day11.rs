@@ -140,6 +140,39 @@ pub fn part1(input: &str) -> i64 {
     all_paths.len() as i64
 }
 
+/// How many paths go from `from` to `out`, and through every device in `through` on the way
+///
+/// Instead of finding every path, this remembers how many there are from each device, along with which of `through`
+/// we've been to so far.
+fn count_paths<'a>(map: &HashMap<&'a str, Path<'a>>, from: &'a str, through: &[&str]) -> i64 {
+    let all = (1_u32 << through.len()) - 1;
+
+    let count = |memo: &mut Memo<(&'a str, u32), i64>, (here, mut seen): (&'a str, u32)| -> i64 {
+        if let Some(i) = through.iter().position(|&t| t == here) {
+            seen |= 1 << i;
+        }
+        if here == "out" {
+            return (seen == all) as i64;
+        }
+        map.get(here)
+            .into_iter()
+            .flatten()
+            .map(|&next| memo.call((next, seen)))
+            .sum()
+    };
+    let mut memo = Memo::new(&count);
+    let paths = memo.call((from, 0));
+
+    debug!("count_paths({from:?}, {through:?}): {}", memo.stats());
+    paths
+}
+
+#[aoc(day11, part1, memo)]
+pub fn part1_memo(input: &str) -> i64 {
+    let map = parse(input);
+    count_paths(&map, "you", &[])
+}
+
 fn write_dot(filename: &str, map: &HashMap<&'_ str, Path<'_>>) {
     let mut lines: Vec<String> = vec![
         "digraph world {".into(),                               // .
@@ -209,6 +242,12 @@ pub fn part2(input: &str) -> i64 {
     (svr_to_fft.len() as i64) * (fft_to_dac.len() as i64) * (dac_to_out.len() as i64)
 }
 
+#[aoc(day11, part2, memo)]
+pub fn part2_memo(input: &str) -> i64 {
+    let map = parse(input);
+    count_paths(&map, "svr", &["dac", "fft"])
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -251,7 +290,7 @@ hhh: out
     #[timeout(Duration::from_millis(100))]
     fn check_ex_part_1(
         #[notrace]
-        #[values(part1)]
+        #[values(part1, part1_memo)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
@@ -268,7 +307,7 @@ hhh: out
     #[timeout(Duration::from_millis(100))]
     fn check_ex_part_2(
         #[notrace]
-        #[values(part2)]
+        #[values(part2, part2_memo)]
         p: impl FnOnce(&str) -> i64,
         #[case] expected: i64,
         #[case] input: &str,
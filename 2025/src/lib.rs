@@ -39,7 +39,6 @@ pub fn init_logging() {
     static LOGGING: std::sync::Once = std::sync::Once::new();
 
     LOGGING.call_once(|| {
-        println!("logging init'd");
         use env_logger::{Builder, Env};
 
         let mut env = Env::default();
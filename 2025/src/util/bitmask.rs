@@ -0,0 +1,199 @@
+//! Walking through subsets of a `u64` bitmask, for searching over which things get picked
+//!
+//! ```
+//! use aoc25::util::bitmask::{combinations, submasks};
+//!
+//! // Every way to pick some of the buttons in `mask`, from all of them down to none
+//! assert_eq!(submasks(0b101).collect::<Vec<_>>(), [0b101, 0b100, 0b001, 0]);
+//!
+//! // Every way to pick exactly 2 of 3 buttons, smallest mask first
+//! assert_eq!(combinations(3, 2).collect::<Vec<_>>(), [0b011, 0b101, 0b110]);
+//! ```
+
+/// The next bigger mask with as many bits set as `x`, by Gosper's hack
+///
+/// `x` can't be zero, and this wraps around if there isn't a bigger one in 64 bits.
+#[track_caller]
+pub const fn next_combination(x: u64) -> u64 {
+    assert!(x != 0, "0 has no next combination");
+
+    // Lowest set bit, and then carry it into the lowest run of 1s
+    let lowest = x & x.wrapping_neg();
+    let carried = x.wrapping_add(lowest);
+
+    // Whatever the carry cleared goes back in at the bottom, minus the bit that moved up
+    let moved = ((x ^ carried) >> 2) / lowest;
+    carried | moved
+}
+
+/// Every subset of `mask`, from `mask` itself down to `0`. There are `2^mask.count_ones()` of them.
+pub const fn submasks(mask: u64) -> Submasks {
+    Submasks {
+        mask,
+        next: Some(mask),
+    }
+}
+
+/// See [`submasks()`]
+#[derive(Copy, Clone, Debug)]
+pub struct Submasks {
+    mask: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for Submasks {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let curr = self.next?;
+
+        // Subtracting 1 clears the lowest bit and sets all of the ones below it, then the mask keeps just ours
+        self.next = match curr {
+            0 => None,
+            _ => Some((curr - 1) & self.mask),
+        };
+
+        Some(curr)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each submask is a count down in binary, with just the mask's bits as digits
+        let len = match self.next {
+            Some(next) => 1 + pext(next, self.mask) as usize,
+            None => 0,
+        };
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Submasks {}
+
+/// The bits of `x` picked out by `mask`, packed down together
+const fn pext(x: u64, mut mask: u64) -> u64 {
+    let mut out = 0;
+    let mut i = 0;
+    while mask != 0 {
+        let bit = mask & mask.wrapping_neg();
+        if x & bit != 0 {
+            out |= 1 << i;
+        }
+        mask &= mask - 1;
+        i += 1;
+    }
+    out
+}
+
+/// Every `n` bit mask with `k` bits set, from smallest to biggest. There are `n choose k` of them.
+#[track_caller]
+pub const fn combinations(n: u32, k: u32) -> Combinations {
+    assert!(n <= 64, "combinations() only goes up to 64 bits");
+
+    if k > n {
+        return Combinations {
+            next: 0,
+            last: 0,
+            done: true,
+        };
+    }
+
+    // The smallest has all of them at the bottom, and the biggest at the top
+    let ones = if k == 64 { u64::MAX } else { (1 << k) - 1 };
+    let last = if k == 0 { 0 } else { ones << (n - k) };
+    Combinations {
+        next: ones,
+        last,
+        done: false,
+    }
+}
+
+/// See [`combinations()`]
+#[derive(Copy, Clone, Debug)]
+pub struct Combinations {
+    next: u64,
+    last: u64,
+    done: bool,
+}
+
+impl Iterator for Combinations {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+
+        let curr = self.next;
+        if curr == self.last {
+            self.done = true;
+        } else {
+            self.next = next_combination(curr);
+        }
+
+        Some(curr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn check_next_combination() {
+        assert_eq!(next_combination(0b0011), 0b0101);
+        assert_eq!(next_combination(0b0101), 0b0110);
+        assert_eq!(next_combination(0b0110), 0b1001);
+        assert_eq!(next_combination(0b1), 0b10);
+        assert_eq!(next_combination(1 << 62), 1 << 63);
+    }
+
+    #[test]
+    fn check_submasks() {
+        let mask = 0b1011_0100;
+        let subs = submasks(mask).collect_vec();
+
+        assert_eq!(subs.len(), 16);
+        assert_eq!(submasks(mask).len(), 16);
+        assert_eq!(subs.first(), Some(&mask));
+        assert_eq!(subs.last(), Some(&0));
+        assert!(subs.iter().all(|&s| s & !mask == 0));
+        assert!(subs.iter().tuple_windows().all(|(a, b)| a > b));
+
+        assert_eq!(submasks(0).collect_vec(), [0]);
+        assert_eq!(
+            submasks(u64::MAX).take(2).collect_vec(),
+            [u64::MAX, u64::MAX - 1]
+        );
+    }
+
+    #[test]
+    fn check_combinations() {
+        // n choose k, and all of them different and the right size
+        for n in 0..=10 {
+            for k in 0..=n {
+                let combos = combinations(n, k).collect_vec();
+                let expected = (0..n).combinations(k as usize).count();
+                assert_eq!(combos.len(), expected, "{n} choose {k}");
+                assert!(combos.iter().all(|c| c.count_ones() == k && *c >> n == 0));
+                assert!(combos.iter().tuple_windows().all(|(a, b)| a < b));
+            }
+        }
+
+        assert_eq!(
+            combinations(4, 2).collect_vec(),
+            [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+        );
+        assert_eq!(combinations(3, 4).count(), 0);
+        assert_eq!(combinations(64, 64).collect_vec(), [u64::MAX]);
+        assert_eq!(combinations(64, 1).count(), 64);
+        assert_eq!(combinations(64, 63).count(), 64);
+        assert_eq!(combinations(64, 0).collect_vec(), [0]);
+    }
+
+    #[test]
+    fn check_const() {
+        const FIRST: u64 = next_combination(0b111);
+        assert_eq!(FIRST, 0b1011);
+    }
+}
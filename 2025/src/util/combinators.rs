@@ -0,0 +1,526 @@
+//! Small parser combinators for puzzle inputs
+//!
+//! Parsers return a [`ParseError`] that knows where things went wrong, instead of panicking:
+//! ```
+//! use aoc25::util::combinators::*;
+//!
+//! let calories = sections(lines(uint::<u32>()));
+//! assert_eq!(calories.parse("1\n2\n\n3"), Ok(vec![vec![1, 2], vec![3]]));
+//!
+//! let err = calories.parse("1\n2\n\nthree").unwrap_err();
+//! assert_eq!(err.to_string(), r#"line 4 col 1: expected u32, found "three""#);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/// The input still to be parsed, and enough context to say where it is
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Input<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    /// Everything that hasn't been parsed yet
+    pub fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.src.len()
+    }
+
+    pub fn advance(self, n: usize) -> Self {
+        debug_assert!(self.pos + n <= self.src.len());
+        Self {
+            src: self.src,
+            pos: self.pos + n,
+        }
+    }
+
+    /// 1-based line and column of the next character
+    pub fn line_col(&self) -> (usize, usize) {
+        let before = &self.src[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let col = before[line_start..].chars().count() + 1;
+
+        (line, col)
+    }
+
+    /// Build an error pointing at the next token
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let (line, col) = self.line_col();
+        let rest = self.rest();
+        let found = match rest.chars().next() {
+            None => "end of input".to_string(),
+            Some('\n') => "end of line".to_string(),
+            Some(_) => {
+                let token = rest
+                    .split(char::is_whitespace)
+                    .next()
+                    .unwrap_or_default()
+                    .chars()
+                    .take(16)
+                    .collect::<String>();
+                format!("{token:?}")
+            }
+        };
+
+        ParseError {
+            line,
+            col,
+            expected: expected.into(),
+            found,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            line,
+            col,
+            expected,
+            found,
+        } = self;
+        write!(
+            f,
+            "line {line} col {col}: expected {expected}, found {found}"
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type PResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn parse_from(&self, input: Input<'a>) -> PResult<'a, T>;
+
+    /// Parse all of `s`. Only trailing whitespace may be left over.
+    fn parse(&self, s: &'a str) -> Result<T, ParseError> {
+        let (t, rest) = self.parse_from(Input::new(s))?;
+        if rest.rest().trim().is_empty() {
+            Ok(t)
+        } else {
+            Err(rest.error("end of input"))
+        }
+    }
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| -> PResult<'a, U> {
+            let (t, input) = self.parse_from(input)?;
+            Ok((f(t), input))
+        }
+    }
+
+    /// Like [`Parser::map()`], but `f` can reject values. Rejections are reported as `expected`.
+    fn try_map<U>(self, expected: &'static str, f: impl Fn(T) -> Option<U>) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| -> PResult<'a, U> {
+            let (t, rest) = self.parse_from(input)?;
+            match f(t) {
+                Some(u) => Ok((u, rest)),
+                None => Err(input.error(expected)),
+            }
+        }
+    }
+
+    /// Zero or one of this
+    fn opt(self) -> impl Parser<'a, Option<T>>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| -> PResult<'a, Option<T>> {
+            match self.parse_from(input) {
+                Ok((t, input)) => Ok((Some(t), input)),
+                Err(_) => Ok((None, input)),
+            }
+        }
+    }
+
+    /// One or more of this, separated by `sep`
+    fn sep_by<S>(self, sep: impl Parser<'a, S>) -> impl Parser<'a, Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| -> PResult<'a, Vec<T>> {
+            let (first, mut input) = self.parse_from(input)?;
+            let mut ts = vec![first];
+
+            // Once we see a separator, we're committed to another element
+            while let Ok((_, next)) = sep.parse_from(input) {
+                let (t, next) = self.parse_from(next)?;
+                ts.push(t);
+                input = next;
+            }
+
+            Ok((ts, input))
+        }
+    }
+
+    /// Exactly `N` of this, separated by `sep`
+    fn array<const N: usize, S>(self, sep: impl Parser<'a, S>) -> impl Parser<'a, [T; N]>
+    where
+        Self: Sized,
+    {
+        move |input: Input<'a>| -> PResult<'a, [T; N]> {
+            let start = input;
+            let mut ts = Vec::with_capacity(N);
+            let mut input = input;
+
+            for i in 0..N {
+                if i > 0 {
+                    (_, input) = sep.parse_from(input)?;
+                }
+                let t;
+                (t, input) = self.parse_from(input)?;
+                ts.push(t);
+            }
+
+            match ts.try_into() {
+                Ok(ts) => Ok((ts, input)),
+                Err(_) => Err(start.error(format!("{N} values"))),
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    fn parse_from(&self, input: Input<'a>) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+pub(crate) fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn from_str_at<'a, T: FromStr>(input: Input<'a>, len: usize) -> PResult<'a, T> {
+    match input.rest()[..len].parse() {
+        Ok(t) if len > 0 => Ok((t, input.advance(len))),
+        _ => Err(input.error(short_type_name::<T>())),
+    }
+}
+
+/// An unsigned number, like `u32` or `usize`
+pub fn uint<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| -> PResult<'a, T> {
+        let len = input
+            .rest()
+            .bytes()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        from_str_at(input, len)
+    }
+}
+
+/// A number with an optional leading `-` or `+`, like `i64`
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| -> PResult<'a, T> {
+        let rest = input.rest().as_bytes();
+        let sign = matches!(rest.first(), Some(b'-' | b'+')) as usize;
+        let digits = rest[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            return Err(input.error(short_type_name::<T>()));
+        }
+        from_str_at(input, sign + digits)
+    }
+}
+
+/// Exactly `lit`
+pub fn tag<'a>(lit: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| -> PResult<'a, &'a str> {
+        if input.rest().starts_with(lit) {
+            Ok((&input.rest()[..lit.len()], input.advance(lit.len())))
+        } else {
+            Err(input.error(format!("{lit:?}")))
+        }
+    }
+}
+
+/// One or more ASCII letters, digits, or `_`
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| -> PResult<'a, &'a str> {
+        let len = input
+            .rest()
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+        if len == 0 {
+            return Err(input.error("a word"));
+        }
+        Ok((&input.rest()[..len], input.advance(len)))
+    }
+}
+
+/// Zero or more spaces or tabs. Never fails, and never crosses a line.
+pub fn ws<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| -> PResult<'a, ()> {
+        let len = input
+            .rest()
+            .bytes()
+            .take_while(|b| *b == b' ' || *b == b'\t')
+            .count();
+        Ok(((), input.advance(len)))
+    }
+}
+
+/// A single line break that's followed by more of the same section
+///
+/// Blank lines and the end of input don't count, so [`lines()`] stops at them.
+pub fn newline<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| -> PResult<'a, ()> {
+        let rest = input.rest();
+        let len = if rest.starts_with("\r\n") {
+            2
+        } else if rest.starts_with('\n') {
+            1
+        } else {
+            return Err(input.error("end of line"));
+        };
+
+        let next = &rest[len..];
+        if next.is_empty() || next.starts_with('\n') || next.starts_with("\r\n") {
+            return Err(input.error("another line"));
+        }
+
+        Ok(((), input.advance(len)))
+    }
+}
+
+/// A blank line separating two sections
+///
+/// Blank lines at the end of input don't count, so [`sections()`] stops at them.
+pub fn blank_line<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| -> PResult<'a, ()> {
+        for sep in ["\n\n", "\r\n\r\n"] {
+            if input.rest().starts_with(sep) {
+                if input.rest()[sep.len()..].trim().is_empty() {
+                    return Err(input.error("another section"));
+                }
+                return Ok(((), input.advance(sep.len())));
+            }
+        }
+        Err(input.error("a blank line"))
+    }
+}
+
+/// One `p` per line, until a blank line or the end of input
+pub fn lines<'a, T>(p: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    p.sep_by(newline())
+}
+
+/// Sections of `p`, separated by blank lines
+pub fn sections<'a, T>(p: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    p.sep_by(blank_line())
+}
+
+/// A single `key<sep>value` pair
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    sep: &'static str,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    tuple((key, tag(sep), value)).map(|(k, _, v)| (k, v))
+}
+
+/// Whitespace separated `key<sep>value` pairs that may span several lines, like `ecl:gry pid:860033327`
+///
+/// Values are everything up to the next whitespace.
+pub fn record<'a>(sep: &'static str) -> impl Parser<'a, Vec<(&'a str, &'a str)>> {
+    let value = move |input: Input<'a>| -> PResult<'a, &'a str> {
+        let len = input
+            .rest()
+            .find(char::is_whitespace)
+            .unwrap_or(input.rest().len());
+        if len == 0 {
+            return Err(input.error("a value"));
+        }
+        Ok((&input.rest()[..len], input.advance(len)))
+    };
+    let field_sep = move |input: Input<'a>| -> PResult<'a, ()> {
+        match tag(" ").parse_from(input) {
+            Ok((_, input)) => Ok(((), input)),
+            Err(_) => newline().parse_from(input),
+        }
+    };
+
+    key_value(word(), sep, value).sep_by(field_sep)
+}
+
+/// Run several parsers in a row. See [`tuple()`].
+pub trait Sequence<'a, T> {
+    fn parse_seq(&self, input: Input<'a>) -> PResult<'a, T>;
+}
+
+macro_rules! impl_sequence {
+    ($($p:ident $t:ident),+) => {
+        impl<'a, $($t,)+ $($p,)+> Sequence<'a, ($($t,)+)> for ($($p,)+)
+        where
+            $($p: Parser<'a, $t>,)+
+        {
+            #[allow(non_snake_case)]
+            fn parse_seq(&self, input: Input<'a>) -> PResult<'a, ($($t,)+)> {
+                let ($($p,)+) = self;
+                $(
+                    let ($t, input) = $p.parse_from(input)?;
+                )+
+                Ok((($($t,)+), input))
+            }
+        }
+    };
+}
+
+impl_sequence!(PA A, PB B);
+impl_sequence!(PA A, PB B, PC C);
+impl_sequence!(PA A, PB B, PC C, PD D);
+impl_sequence!(PA A, PB B, PC C, PD D, PE E);
+impl_sequence!(PA A, PB B, PC C, PD D, PE E, PF F);
+impl_sequence!(PA A, PB B, PC C, PD D, PE E, PF F, PG G);
+impl_sequence!(PA A, PB B, PC C, PD D, PE E, PF F, PG G, PH H);
+
+/// A fixed-arity tuple of parsers, run one after another
+///
+/// ```
+/// use aoc25::util::combinators::*;
+///
+/// let p = tuple((int::<i64>(), tag(","), int::<i64>())).map(|(x, _, y)| (x, y));
+/// assert_eq!(p.parse("-3,4"), Ok((-3, 4)));
+/// ```
+pub fn tuple<'a, T>(seq: impl Sequence<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| -> PResult<'a, T> { seq.parse_seq(input) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    #[rstest]
+    #[case::zero("0", 0)]
+    #[case::big("1234", 1234)]
+    #[case::trailing_newline("1234\n", 1234)]
+    fn check_uint(#[case] s: &str, #[case] expected: u32) {
+        assert_eq!(uint::<u32>().parse(s), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::positive("12", 12)]
+    #[case::negative("-12", -12)]
+    #[case::plus("+12", 12)]
+    fn check_int(#[case] s: &str, #[case] expected: i64) {
+        assert_eq!(int::<i64>().parse(s), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::not_a_number("abc", "line 1 col 1: expected i64, found \"abc\"")]
+    #[case::just_a_sign("- 1", "line 1 col 1: expected i64, found \"-\"")]
+    #[case::empty("", "line 1 col 1: expected i64, found end of input")]
+    #[case::leftovers("12abc", "line 1 col 3: expected end of input, found \"abc\"")]
+    fn check_int_errors(#[case] s: &str, #[case] expected: &str) {
+        let err = int::<i64>().parse(s).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn check_overflow_points_at_the_number() {
+        let p = lines(tuple((word(), tag(" "), uint::<u8>())));
+        let err = p.parse("a 1\nb 2\nc 300").unwrap_err();
+        assert_eq!(err.to_string(), "line 3 col 3: expected u8, found \"300\"");
+    }
+
+    #[test]
+    fn check_tuples_and_lists() {
+        let p = lines(
+            tuple((uint::<u64>(), tag(": "), uint::<u64>().sep_by(tag(" "))))
+                .map(|(total, _, args)| (total, args)),
+        );
+        assert_eq!(
+            p.parse("190: 10 19\n3267: 81 40 27\n"),
+            Ok(vec![(190, vec![10, 19]), (3267, vec![81, 40, 27])])
+        );
+    }
+
+    #[test]
+    fn check_array() {
+        let p = lines(int::<i32>().array::<3, _>(tag(",")));
+        assert_eq!(p.parse("1,-2,3\n4,5,6"), Ok(vec![[1, -2, 3], [4, 5, 6]]));
+
+        let err = p.parse("1,-2,3\n4,5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2 col 4: expected \",\", found end of input"
+        );
+    }
+
+    #[rstest]
+    #[case::trailing_newline("1000\n2000\n\n4000\n\n5000\n6000\n")]
+    #[case::trailing_blank_line("1000\n2000\n\n4000\n\n5000\n6000\n\n")]
+    #[case::crlf("1000\r\n2000\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n\r\n")]
+    fn check_sections(#[case] s: &str) {
+        let p = sections(lines(uint::<u32>()));
+        assert_eq!(
+            p.parse(s),
+            Ok(vec![vec![1000, 2000], vec![4000], vec![5000, 6000]])
+        );
+    }
+
+    #[test]
+    fn check_records() {
+        let p = sections(record(":"));
+        let parsed = p
+            .parse("ecl:gry pid:860033327\nhcl:#fffffd\n\niyr:2013 ecl:amb")
+            .unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                vec![("ecl", "gry"), ("pid", "860033327"), ("hcl", "#fffffd")],
+                vec![("iyr", "2013"), ("ecl", "amb")],
+            ]
+        );
+    }
+
+    #[test]
+    fn check_try_map() {
+        let p = word().try_map("a direction", |w| match w {
+            "up" => Some(-1),
+            "down" => Some(1),
+            _ => None,
+        });
+        assert_eq!(p.parse("down"), Ok(1));
+        assert_eq!(
+            p.parse("left").unwrap_err().to_string(),
+            "line 1 col 1: expected a direction, found \"left\""
+        );
+    }
+}
@@ -1,65 +1,73 @@
-use core::cmp;
 use core::fmt;
 use core::ops;
 
-use bytemuck;
+use crate::util::U256;
 
-#[path = "./u256.rs"]
-mod u256;
-pub use u256::U256;
+use itertools::Itertools;
 
-pub type Bitset128 = FixedBitset<u128>;
-pub type Bitset64 = FixedBitset<u64>;
-pub type Bitset32 = FixedBitset<u32>;
-pub type Bitset16 = FixedBitset<u16>;
-pub type Bitset8 = FixedBitset<u8>;
+pub type Bitset64 = FixedBitset<1>;
+pub type Bitset128 = FixedBitset<2>;
+pub type Bitset256 = FixedBitset<4>;
 
-// No longer built-in types
-pub type Bitset256 = FixedBitset<U256>;
+/// `const fn`s can't call closures, so this spells out the loop over two sets' words
+macro_rules! zip_words {
+    ($this:expr, $other:expr, |$a:ident, $b:ident| $f:expr) => {{
+        let mut words = [0; WORDS];
+        let mut i = 0;
+        while i < WORDS {
+            let ($a, $b) = ($this.words[i], $other.words[i]);
+            words[i] = $f;
+            i += 1;
+        }
+        Self { words }
+    }};
+}
 
-#[derive(Copy, Clone, Default)]
-pub struct FixedBitset<N> {
-    bits: N,
+/// A set of small numbers, as `64 * WORDS` bits
+///
+/// Word 0 holds 0 through 63, word 1 holds 64 through 127, and so on.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FixedBitset<const WORDS: usize> {
+    words: [u64; WORDS],
 }
 
-impl<N: Backing> fmt::Debug for FixedBitset<N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // let mut binary = "0b".to_string();
-        // for (i, byte) in self.bits.as_be_bytes().iter().enumerate() {
-        //     if i > 0 {
-        //         if i > 0 && i.is_multiple_of(8) {
-        //             binary.push_str("__");
-        //         } else if i.is_multiple_of(4) {
-        //             binary.push('_');
-        //         }
-        //     }
-
-        //     binary.push_str(&format!("{byte:08}"));
-        // }
+impl<const WORDS: usize> Default for FixedBitset<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
+impl<const WORDS: usize> fmt::Debug for FixedBitset<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("BitSet")
-            .field("bit_width", &N::bit_width())
-            // .field("bits", &binary)
+            .field("bit_width", &Self::bit_width())
             .field(
                 "bits",
                 &self
-                    .bits
-                    .as_be_bytes()
+                    .words
                     .iter()
-                    .map(|b| format!("0b_{b:08b}"))
+                    .map(|w| format!("0b_{w:064b}"))
                     .collect::<Vec<_>>(),
             )
             .finish()
     }
 }
 
-impl<N: Backing> FixedBitset<N> {
-    pub fn new() -> Self {
-        Self::default()
+impl<const WORDS: usize> FixedBitset<WORDS> {
+    pub const fn new() -> Self {
+        Self { words: [0; WORDS] }
+    }
+
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        Self { words }
+    }
+
+    pub const fn words(&self) -> &[u64; WORDS] {
+        &self.words
     }
 
-    pub fn inner(&self) -> N {
-        self.bits
+    pub const fn bit_width() -> u32 {
+        64 * WORDS as u32
     }
 
     #[track_caller]
@@ -67,9 +75,9 @@ impl<N: Backing> FixedBitset<N> {
     where
         Elem: num::PrimInt + core::fmt::Display,
     {
-        let idx: usize = Self::idx_of(item);
+        let (word, bit) = Self::word_of(item);
 
-        (self.bits & (N::ONE << idx)) != N::ZERO
+        (self.words[word] & bit) != 0
     }
 
     #[track_caller]
@@ -77,10 +85,10 @@ impl<N: Backing> FixedBitset<N> {
     where
         Elem: num::PrimInt + core::fmt::Display,
     {
-        let idx: usize = Self::idx_of(item);
+        let (word, bit) = Self::word_of(item);
 
-        let old = (self.bits & (N::ONE << idx)) != N::ZERO;
-        self.bits = self.bits | (N::ONE << idx);
+        let old = (self.words[word] & bit) != 0;
+        self.words[word] |= bit;
         old
     }
 
@@ -89,159 +97,370 @@ impl<N: Backing> FixedBitset<N> {
     where
         Elem: num::PrimInt + core::fmt::Display,
     {
-        let idx: usize = Self::idx_of(item);
+        let (word, bit) = Self::word_of(item);
 
-        let old = (self.bits & (N::ONE << idx)) != N::ZERO;
-        self.bits = self.bits & !(N::ONE << idx);
+        let old = (self.words[word] & bit) != 0;
+        self.words[word] &= !bit;
         old
     }
 
-    pub fn len(&self) -> usize {
-        self.bits.count_ones() as usize
+    pub fn clear(&mut self) {
+        self.words = [0; WORDS];
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    /// How many are in the set, by popcount
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < WORDS {
+            len += self.words[i].count_ones() as usize;
+            i += 1;
+        }
+        len
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
-        (0..Self::bit_width()).filter(|&i| self.contains(i))
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
     }
 
-    fn bit_width() -> u32 {
-        N::ZERO.count_zeros()
+    /// From smallest to biggest, or the other way with `.rev()`
+    pub fn iter(&self) -> FixedBitsetIter<WORDS> {
+        FixedBitsetIter {
+            words: self.words,
+            front: 0,
+            back: WORDS,
+        }
+    }
+
+    /// Everything in either set
+    pub const fn union(&self, other: &Self) -> Self {
+        zip_words!(self, other, |a, b| a | b)
+    }
+
+    /// Everything in both sets
+    pub const fn intersection(&self, other: &Self) -> Self {
+        zip_words!(self, other, |a, b| a & b)
+    }
+
+    /// Everything in this set that isn't in `other`
+    pub const fn difference(&self, other: &Self) -> Self {
+        zip_words!(self, other, |a, b| a & !b)
+    }
+
+    /// Everything in exactly one of the sets
+    pub const fn symmetric_difference(&self, other: &Self) -> Self {
+        zip_words!(self, other, |a, b| a ^ b)
+    }
+
+    /// Whether everything in this set is also in `other`
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Whether nothing is in both sets
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Every subset of this set, from all of it down to none of it. There are `2^self.len()` of them.
+    pub const fn subsets(&self) -> FixedBitsetSubsets<WORDS> {
+        FixedBitsetSubsets {
+            set: self.words,
+            next: Some(self.words),
+        }
+    }
+
+    /// Every subset of this set with `k` things in it, in lexicographic order of what's picked
+    pub fn combinations(&self, k: usize) -> FixedBitsetCombinations<WORDS> {
+        let elems = self.iter().collect_vec();
+        FixedBitsetCombinations {
+            done: k > elems.len(),
+            picked: (0..k).collect(),
+            elems,
+        }
     }
 
     #[track_caller]
-    fn idx_of<T: num::PrimInt + core::fmt::Display>(t: T) -> usize {
+    fn word_of<T: num::PrimInt + core::fmt::Display>(t: T) -> (usize, u64) {
         if let Some(i) = t.to_u32() {
             if i < Self::bit_width() {
-                i as usize
+                (i as usize / 64, 1 << (i % 64))
             } else {
                 panic!(
-                    "Failed to convert \"{t}\" to an 8-bit int because it's out of bounds (valid is 0..<{bits}).",
+                    "Failed to convert \"{t}\" to an index because it's out of bounds (valid is 0..<{bits}).",
                     bits = Self::bit_width()
                 );
             }
         } else {
-            panic!("Failed to convert \"{t}\" to an 8-bit int");
+            panic!("Failed to convert \"{t}\" to an index");
+        }
+    }
+}
+
+impl From<U256> for Bitset256 {
+    fn from(n: U256) -> Self {
+        let U256([hi, lo]) = n;
+        Self::from_words([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
+
+impl From<Bitset256> for U256 {
+    fn from(set: Bitset256) -> Self {
+        let [w0, w1, w2, w3] = set.words.map(|w| w as u128);
+        U256([w2 | (w3 << 64), w0 | (w1 << 64)])
+    }
+}
+
+impl<const WORDS: usize> ops::BitOr for FixedBitset<WORDS> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(&rhs)
+    }
+}
+
+impl<const WORDS: usize> ops::BitAnd for FixedBitset<WORDS> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(&rhs)
+    }
+}
+
+impl<const WORDS: usize> ops::Sub for FixedBitset<WORDS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(&rhs)
+    }
+}
+
+impl<const WORDS: usize> ops::BitXor for FixedBitset<WORDS> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(&rhs)
+    }
+}
+
+impl<const WORDS: usize> ops::BitOrAssign for FixedBitset<WORDS> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(&rhs);
+    }
+}
+
+impl<const WORDS: usize> ops::BitAndAssign for FixedBitset<WORDS> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(&rhs);
+    }
+}
+
+impl<const WORDS: usize> ops::SubAssign for FixedBitset<WORDS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(&rhs);
+    }
+}
+
+impl<const WORDS: usize> ops::BitXorAssign for FixedBitset<WORDS> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(&rhs);
+    }
+}
+
+impl<const WORDS: usize, Elem> FromIterator<Elem> for FixedBitset<WORDS>
+where
+    Elem: num::PrimInt + core::fmt::Display,
+{
+    fn from_iter<I: IntoIterator<Item = Elem>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for item in iter {
+            set.insert(item);
         }
+        set
     }
 }
 
+/// Everything in a [`FixedBitset`], a word at a time
 #[derive(Copy, Clone, Debug)]
-pub struct FixedBitsetIter {
-    // TODO: Need to route to U256 instead
-    bits: u128,
-    i: u32,
+pub struct FixedBitsetIter<const WORDS: usize> {
+    /// What's left to visit. Visited bits are cleared.
+    words: [u64; WORDS],
+    /// Words before `front` and from `back` on are all cleared
+    front: usize,
+    back: usize,
 }
 
-impl Iterator for FixedBitsetIter {
+impl<const WORDS: usize> Iterator for FixedBitsetIter<WORDS> {
     type Item = u32;
+
     fn next(&mut self) -> Option<u32> {
-        let shifted = self.bits >> self.i;
-        if shifted.count_ones() == 0 {
-            return None;
+        while self.front < self.back {
+            let word = &mut self.words[self.front];
+            if *word != 0 {
+                let bit = word.trailing_zeros();
+                *word &= *word - 1;
+                return Some(64 * self.front as u32 + bit);
+            }
+            self.front += 1;
         }
 
-        self.i += shifted.trailing_zeros();
-        let res = self.i;
-        assert_eq!((self.bits >> self.i) & 1, 1);
-        self.i += 1;
+        None
+    }
 
-        Some(res)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.words[self.front..self.back]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum();
+        (len, Some(len))
     }
 }
 
-impl<N: Backing + num::PrimInt> IntoIterator for FixedBitset<N> {
-    type Item = u32;
-    type IntoIter = FixedBitsetIter;
+impl<const WORDS: usize> DoubleEndedIterator for FixedBitsetIter<WORDS> {
+    fn next_back(&mut self) -> Option<u32> {
+        while self.front < self.back {
+            let word = &mut self.words[self.back - 1];
+            if *word != 0 {
+                let bit = 63 - word.leading_zeros();
+                *word &= !(1 << bit);
+                return Some(64 * (self.back as u32 - 1) + bit);
+            }
+            self.back -= 1;
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        // (╯°□°)╯︵ ┻━┻
-        let bits = N::from(self.bits).unwrap().to_u128().unwrap();
-        FixedBitsetIter { bits, i: 0 }
+        None
     }
 }
 
-/// (╯°□°)╯︵ ┻━┻
-pub trait Backing:
-    Default                          //
-    + Copy                           //
-    + fmt::Display                   //
-    + ops::Shl<usize, Output = Self> //
-    + ops::BitOr<Output=Self>        //
-    + ops::BitAnd<Output=Self>       //
-    + ops::Not<Output=Self>          //
-    + cmp::Eq                        //
-{
-    const ONE: Self;
-    const ZERO: Self;
+impl<const WORDS: usize> ExactSizeIterator for FixedBitsetIter<WORDS> {}
 
-    fn bit_width() -> u32;
+/// See [`FixedBitset::subsets()`]
+#[derive(Copy, Clone, Debug)]
+pub struct FixedBitsetSubsets<const WORDS: usize> {
+    set: [u64; WORDS],
+    next: Option<[u64; WORDS]>,
+}
 
-    fn count_ones(&self) -> u32;
-    fn count_zeros(&self) -> u32;
+impl<const WORDS: usize> Iterator for FixedBitsetSubsets<WORDS> {
+    type Item = FixedBitset<WORDS>;
 
-    fn as_be_bytes(&self) -> &[u8];
-}
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = self.next?;
 
-macro_rules! impl_backing_for_primint {
-    ($($prim:ty),+) => {
-        $(
-            impl Backing for $prim {
-                const ONE: Self = 1;
-                const ZERO: Self = 0;
+        // The submask trick: subtract 1, borrowing across words, and keep just the set's bits
+        self.next = curr.iter().position(|&w| w != 0).map(|i| {
+            let mut next = curr;
+            next[i] -= 1;
+            next[..i].fill(u64::MAX);
+            for (w, s) in next.iter_mut().zip(self.set) {
+                *w &= s;
+            }
+            next
+        });
 
-                fn bit_width() -> u32 {
-                    8 * core::mem::size_of::<$prim>() as u32
-                }
+        Some(FixedBitset::from_words(curr))
+    }
+}
 
-                fn count_ones(&self) -> u32 {
-                    Self::count_ones(*self)
-                }
+/// See [`FixedBitset::combinations()`]
+#[derive(Clone, Debug)]
+pub struct FixedBitsetCombinations<const WORDS: usize> {
+    elems: Vec<u32>,
+    /// Indexes into `elems`, always increasing
+    picked: Vec<usize>,
+    done: bool,
+}
 
-                fn count_zeros(&self) -> u32 {
-                    Self::count_zeros(*self)
-                }
+impl<const WORDS: usize> Iterator for FixedBitsetCombinations<WORDS> {
+    type Item = FixedBitset<WORDS>;
 
-                fn as_be_bytes(&self) -> &[u8] {
-                    bytemuck::bytes_of(self)
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let curr = self.picked.iter().map(|&i| self.elems[i]).collect();
+
+        // Bump the last pick that still has room, and restart everything after it right behind it
+        let (n, k) = (self.elems.len(), self.picked.len());
+        match (0..k).rev().find(|&i| self.picked[i] < n - k + i) {
+            Some(i) => {
+                self.picked[i] += 1;
+                for j in i + 1..k {
+                    self.picked[j] = self.picked[j - 1] + 1;
                 }
             }
-        )+
-    };
+            None => self.done = true,
+        }
+
+        Some(curr)
+    }
+}
+
+impl<const WORDS: usize> IntoIterator for FixedBitset<WORDS> {
+    type Item = u32;
+    type IntoIter = FixedBitsetIter<WORDS>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-impl_backing_for_primint![u8, u16, u32, u64, u128];
+impl<const WORDS: usize> IntoIterator for &FixedBitset<WORDS> {
+    type Item = u32;
+    type IntoIter = FixedBitsetIter<WORDS>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
     #[allow(unused)]
     use itertools::Itertools;
+    use pretty_assertions::assert_eq;
+
+    // Every check runs at each of these widths, including one that isn't a power of two
+    macro_rules! for_each_width {
+        ($check:ident) => {
+            $check::<1>();
+            $check::<2>();
+            $check::<3>();
+            $check::<4>();
+        };
+    }
 
-    #[allow(clippy::bool_assert_comparison)]
-    #[generic_tests::define]
-    mod bitset_generic_tests {
-
-        use super::*;
-        use pretty_assertions::assert_eq;
-
-        #[test]
-        fn check_empty<N: Backing>() {
-            let bitset = FixedBitset::<N>::new();
+    #[test]
+    fn check_empty() {
+        fn check<const W: usize>() {
+            let bitset = FixedBitset::<W>::new();
 
             assert_eq!(bitset.len(), 0);
             assert!(bitset.is_empty());
-            for i in 0..FixedBitset::<N>::bit_width() {
+            for i in 0..FixedBitset::<W>::bit_width() {
                 assert!(!bitset.contains(i));
             }
         }
+        for_each_width!(check);
+    }
 
-        #[test]
-        fn check_basic_usage<N: Backing>() {
-            let mut bitset = FixedBitset::<N>::new();
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn check_basic_usage() {
+        fn check<const W: usize>() {
+            let mut bitset = FixedBitset::<W>::new();
             assert!(bitset.is_empty());
 
             // Insert first 1
@@ -255,119 +474,218 @@ mod test {
             assert!(bitset.contains(1));
 
             // Insert a different number
-            assert_eq!(bitset.insert(FixedBitset::<N>::bit_width() - 1), false);
+            assert_eq!(bitset.insert(FixedBitset::<W>::bit_width() - 1), false);
             assert_eq!(bitset.len(), 2);
-            assert!(bitset.contains(FixedBitset::<N>::bit_width() - 1));
+            assert!(bitset.contains(FixedBitset::<W>::bit_width() - 1));
+
+            // And take them back out
+            assert_eq!(bitset.remove(1), true);
+            assert_eq!(bitset.remove(1), false);
+            assert_eq!(bitset.len(), 1);
         }
+        for_each_width!(check);
+    }
 
-        #[test]
-        fn check_all_inserts<N: Backing>() {
-            let mut bitset = FixedBitset::<N>::new();
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn check_all_inserts() {
+        fn check<const W: usize>() {
+            let mut bitset = FixedBitset::<W>::new();
             assert!(bitset.is_empty());
 
-            for i in 0..FixedBitset::<N>::bit_width() {
+            for i in 0..FixedBitset::<W>::bit_width() {
                 let i = i as usize;
-                // println!("BEFORE bits: {bitset:#?}");
                 assert!(!bitset.contains(i), "bitset already contains {i} somehow");
-
-                // println!("Inserting i={i}");
                 assert_eq!(
                     bitset.insert(i),
                     false,
                     "inserting {i} and it already contains it somehow"
                 );
-
-                // println!("AFTER  bits: {bitset:#?}");
                 assert_eq!(bitset.len(), i + 1, "bitset has the wrong length");
             }
         }
+        for_each_width!(check);
+    }
 
-        #[test]
-        #[should_panic(expected = "out of bounds")]
-        fn check_bad_small_index<N: Backing>() {
-            let mut bitset = FixedBitset::<N>::new();
-            bitset.insert(FixedBitset::<N>::bit_width());
-        }
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn check_bad_small_index() {
+        let mut bitset = Bitset64::new();
+        bitset.insert(Bitset64::bit_width());
+    }
 
-        #[test]
-        #[should_panic(expected = "to convert")]
-        fn check_bad_big_index<N: Backing>() {
-            let mut bitset = FixedBitset::<N>::new();
-            bitset.insert(10_000_u16);
-        }
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn check_bad_small_index_wide() {
+        let mut bitset = FixedBitset::<3>::new();
+        bitset.insert(FixedBitset::<3>::bit_width());
+    }
 
-        #[instantiate_tests(<u8>)]
-        mod bitset8 {}
+    #[test]
+    #[should_panic(expected = "to convert")]
+    fn check_bad_big_index() {
+        let mut bitset = Bitset256::new();
+        bitset.insert(10_000_u16);
+    }
 
-        #[instantiate_tests(<u16>)]
-        mod bitset16 {}
+    #[test]
+    #[should_panic(expected = "to convert")]
+    fn check_bad_negative_index() {
+        let mut bitset = Bitset256::new();
+        bitset.insert(-1_i32);
+    }
 
-        #[instantiate_tests(<u32>)]
-        mod bitset32 {}
+    #[test]
+    fn check_forward_iter() {
+        fn check<const W: usize>() {
+            let mut bitset = FixedBitset::<W>::new();
 
-        #[instantiate_tests(<u64>)]
-        mod bitset64 {}
+            let expected = (0..FixedBitset::<W>::bit_width()).step_by(2).collect_vec();
 
-        #[instantiate_tests(<u128>)]
-        mod bitset128 {}
+            for &i in &expected {
+                bitset.insert(i);
+            }
+            let actual = bitset.into_iter().take(1_000).collect_vec();
 
-        #[instantiate_tests(<U256>)]
-        mod bitset256 {}
+            assert_eq!(expected, actual);
+            assert_eq!(bitset.iter().len(), expected.len());
+        }
+        for_each_width!(check);
     }
 
-    // TODO: Need this working for U256, so for now test everything else
-    #[allow(clippy::bool_assert_comparison)]
-    #[generic_tests::define]
-    mod bitset_generic_tests_just_iter {
+    #[test]
+    fn check_backward_iter() {
+        fn check<const W: usize>() {
+            let mut bitset = FixedBitset::<W>::new();
 
-        use super::*;
-        use pretty_assertions::assert_eq;
-
-        #[test]
-        fn check_forward_iter<N: Backing + num::PrimInt>() {
-            let mut bitset = FixedBitset::<N>::new();
-
-            let expected = (0..FixedBitset::<N>::bit_width()).step_by(2).collect_vec();
+            let expected = (0..FixedBitset::<W>::bit_width())
+                .step_by(3)
+                .rev()
+                .collect_vec();
 
             for &i in &expected {
                 bitset.insert(i);
             }
-            let actual = bitset.into_iter().take(1_000).collect_vec();
+            let actual = bitset.into_iter().rev().take(1_000).collect_vec();
 
             assert_eq!(expected, actual);
         }
+        for_each_width!(check);
+    }
 
-        // TODO: Need to impl backward iter
-        // #[test]
-        // fn check_backard_iter<N: Backing + num::PrimInt>() {
-        //     let mut bitset = FixedBitset::<N>::new();
+    #[test]
+    fn check_iter_from_both_ends() {
+        let bitset: Bitset256 = [0, 5, 64, 130, 255].into_iter().collect();
+        let mut iter = bitset.iter();
 
-        //     let expected = (0..FixedBitset::<N>::bit_width())
-        //         .step_by(2)
-        //         .rev()
-        //         .collect_vec();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(255));
+        assert_eq!(iter.next_back(), Some(130));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect_vec(), [5, 64]);
+    }
 
-        //     for &i in &expected {
-        //         bitset.insert(i);
-        //     }
-        //     let actual = bitset.into_iter().rev().take(1_000).collect_vec();
+    #[test]
+    fn check_set_algebra() {
+        let evens: Bitset256 = (0..256).step_by(2).collect();
+        let threes: Bitset256 = (0..256).step_by(3).collect();
+
+        let sixes = (0..256).step_by(6).collect_vec();
+        assert_eq!(evens.intersection(&threes).iter().collect_vec(), sixes);
+        assert_eq!((evens & threes).iter().collect_vec(), sixes);
+
+        let either = (0..256).filter(|i| i % 2 == 0 || i % 3 == 0).collect_vec();
+        assert_eq!(evens.union(&threes).iter().collect_vec(), either);
+        assert_eq!((evens | threes).iter().collect_vec(), either);
+
+        let just_evens = (0..256).filter(|i| i % 2 == 0 && i % 3 != 0).collect_vec();
+        assert_eq!(evens.difference(&threes).iter().collect_vec(), just_evens);
+        assert_eq!((evens - threes).iter().collect_vec(), just_evens);
+
+        let mut set = evens;
+        set -= evens;
+        assert!(set.is_empty());
+        set |= threes;
+        set &= evens;
+        assert_eq!(set.len(), sixes.len());
+    }
 
-        //     assert_eq!(expected, actual);
-        // }
+    #[test]
+    fn check_subset_relations() {
+        let evens: Bitset256 = (0..256).step_by(2).collect();
+        let fours: Bitset256 = (0..256).step_by(4).collect();
+        let odds: Bitset256 = (1..256).step_by(2).collect();
+
+        assert!(fours.is_subset(&evens));
+        assert!(evens.is_superset(&fours));
+        assert!(!evens.is_subset(&fours));
+        assert!(evens.is_disjoint(&odds));
+        assert!(!evens.is_disjoint(&fours));
+
+        assert_eq!(evens ^ odds, (0..256).collect());
+        assert_eq!(
+            (evens ^ fours).iter().collect_vec(),
+            (2..256).step_by(4).collect_vec()
+        );
+
+        let mut set = evens;
+        set ^= evens;
+        assert!(set.is_empty());
+    }
 
-        #[instantiate_tests(<u8>)]
-        mod bitset8 {}
+    #[test]
+    fn check_subsets() {
+        // Spread out over 3 words, so the submask trick has to borrow across them
+        let set: FixedBitset<3> = [1, 63, 64, 130].into_iter().collect();
+        let subsets = set.subsets().collect_vec();
 
-        #[instantiate_tests(<u16>)]
-        mod bitset16 {}
+        assert_eq!(subsets.len(), 16);
+        assert_eq!(subsets.first(), Some(&set));
+        assert_eq!(subsets.last(), Some(&FixedBitset::new()));
+        assert!(subsets.iter().all(|sub| sub.is_subset(&set)));
+        assert_eq!(subsets.iter().unique().count(), 16);
 
-        #[instantiate_tests(<u32>)]
-        mod bitset32 {}
+        assert_eq!(Bitset64::new().subsets().collect_vec(), [Bitset64::new()]);
+    }
 
-        #[instantiate_tests(<u64>)]
-        mod bitset64 {}
+    #[test]
+    fn check_combinations() {
+        let set: Bitset256 = [3, 70, 100, 200, 255].into_iter().collect();
+
+        let pairs = set.combinations(2).collect_vec();
+        assert_eq!(pairs.len(), 10);
+        assert!(
+            pairs
+                .iter()
+                .all(|pair| pair.len() == 2 && pair.is_subset(&set))
+        );
+        assert_eq!(pairs.iter().unique().count(), 10);
+        assert_eq!(pairs[0].iter().collect_vec(), [3, 70]);
+        assert_eq!(pairs[9].iter().collect_vec(), [200, 255]);
+
+        assert_eq!(set.combinations(0).collect_vec(), [Bitset256::new()]);
+        assert_eq!(set.combinations(5).collect_vec(), [set]);
+        assert_eq!(set.combinations(6).count(), 0);
+    }
 
-        #[instantiate_tests(<u128>)]
-        mod bitset128 {}
+    #[test]
+    fn check_const() {
+        const A: Bitset128 = Bitset128::from_words([0b0110, 1]);
+        const B: Bitset128 = Bitset128::from_words([0b0011, 0]);
+        const BOTH: Bitset128 = A.intersection(&B);
+        const LEN: usize = A.union(&B).len();
+
+        assert_eq!(BOTH.iter().collect_vec(), [1]);
+        assert_eq!(LEN, 4);
+    }
+
+    #[test]
+    fn check_u256_round_trip() {
+        for i in 0..256 {
+            let n = U256::ONE << i;
+            let set = Bitset256::from(n);
+            assert_eq!(set.iter().collect_vec(), [i as u32]);
+            assert_eq!(U256::from(set), n);
+        }
     }
 }
@@ -8,6 +8,8 @@ use std::hash::Hash;
 use std::ops::Range;
 use std::ops::{Index, IndexMut};
 
+use super::GridView;
+
 /*
  --> src/framebuffer.rs
   |
@@ -167,6 +169,24 @@ impl Framebuffer<u8> {
     }
 }
 
+/// Copies a line at a time instead of a `char` at a time
+impl From<GridView<'_>> for Framebuffer<u8> {
+    fn from(view: GridView<'_>) -> Self {
+        let mut buf = SmallVec::with_capacity(view.width() * view.height());
+        for y in 0..view.height() as i32 {
+            buf.extend_from_slice(view.row(y));
+        }
+
+        Framebuffer {
+            buf,
+            width: view.width() as isize,
+            height: view.height() as isize,
+            offsets: IVec2::zero(),
+            border_color: None,
+        }
+    }
+}
+
 /// Construction Methods
 impl<T> Framebuffer<T> {
     pub fn new_with_ranges_and(
@@ -0,0 +1,469 @@
+//! Polygons on the integer lattice: their areas, what's inside them, and what crosses them
+//!
+//! Every polygon is a list of its corners in order, going either way around, and closes back up to the first one.
+//! Everything is done in `i64`s, so nothing rounds.
+//!
+//! ```
+//! use aoc25::util::geometry::{boundary_points, interior_points};
+//!
+//! // A lagoon dug from a dig plan: the trench plus everything it surrounds
+//! let corners = [[0, 0], [6, 0], [6, 5], [0, 5]];
+//! assert_eq!(boundary_points(&corners) + interior_points(&corners), 7 * 6);
+//! ```
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+
+use ultraviolet::IVec2;
+
+/// Anything with integer `x` and `y` coordinates
+pub trait LatticePoint: Copy {
+    fn xy(self) -> [i64; 2];
+}
+
+impl LatticePoint for IVec2 {
+    fn xy(self) -> [i64; 2] {
+        [self.x as i64, self.y as i64]
+    }
+}
+
+impl LatticePoint for [i64; 2] {
+    fn xy(self) -> [i64; 2] {
+        self
+    }
+}
+
+impl LatticePoint for [i32; 2] {
+    fn xy(self) -> [i64; 2] {
+        self.map(|c| c as i64)
+    }
+}
+
+impl LatticePoint for (i64, i64) {
+    fn xy(self) -> [i64; 2] {
+        [self.0, self.1]
+    }
+}
+
+/// Every edge of `poly`, including the one from the last corner back to the first
+fn edges<P: LatticePoint>(poly: &[P]) -> impl Iterator<Item = ([i64; 2], [i64; 2])> + '_ {
+    let next = poly.iter().skip(1).chain(poly.first());
+    poly.iter().zip(next).map(|(&a, &b)| (a.xy(), b.xy()))
+}
+
+/// Twice the area of `poly`, which is positive if it goes counter-clockwise (with +y going up)
+///
+/// This is the shoelace formula, and it's doubled so that it's always a whole number.
+pub fn twice_signed_area<P: LatticePoint>(poly: &[P]) -> i64 {
+    edges(poly)
+        .map(|([ax, ay], [bx, by])| ax * by - bx * ay)
+        .sum()
+}
+
+/// Twice the area of `poly`, whichever way it goes around
+pub fn twice_area<P: LatticePoint>(poly: &[P]) -> i64 {
+    twice_signed_area(poly).abs()
+}
+
+/// How many lattice points are on the edges of `poly`
+pub fn boundary_points<P: LatticePoint>(poly: &[P]) -> i64 {
+    edges(poly)
+        .map(|([ax, ay], [bx, by])| num::integer::gcd(bx - ax, by - ay))
+        .sum()
+}
+
+/// How many lattice points are strictly inside `poly`, by Pick's theorem
+///
+/// Pick's says `A = I + B/2 - 1`, so `I = A - B/2 + 1`. That's only true if `poly` doesn't cross itself.
+pub fn interior_points<P: LatticePoint>(poly: &[P]) -> i64 {
+    (twice_area(poly) - boundary_points(poly)) / 2 + 1
+}
+
+/// Which side of the line through `a` and `b` that `p` is on: `Greater` is to the left, and `Equal` is on it
+pub fn orientation(a: impl LatticePoint, b: impl LatticePoint, p: impl LatticePoint) -> Ordering {
+    let ([ax, ay], [bx, by], [px, py]) = (a.xy(), b.xy(), p.xy());
+    ((bx - ax) * (py - ay) - (by - ay) * (px - ax)).cmp(&0)
+}
+
+/// Whether `p` is on the segment from `a` to `b`, including its ends
+pub fn on_segment(a: impl LatticePoint, b: impl LatticePoint, p: impl LatticePoint) -> bool {
+    let ([ax, ay], [bx, by], [px, py]) = (a.xy(), b.xy(), p.xy());
+    orientation(a, b, p) == Ordering::Equal
+        && ax.min(bx) <= px
+        && px <= ax.max(bx)
+        && ay.min(by) <= py
+        && py <= ay.max(by)
+}
+
+/// Whether the segments `a0 a1` and `b0 b1` share any point, including just touching
+pub fn segments_intersect(
+    a0: impl LatticePoint,
+    a1: impl LatticePoint,
+    b0: impl LatticePoint,
+    b1: impl LatticePoint,
+) -> bool {
+    let d0 = orientation(a0, a1, b0);
+    let d1 = orientation(a0, a1, b1);
+    let d2 = orientation(b0, b1, a0);
+    let d3 = orientation(b0, b1, a1);
+
+    // Each one's ends are on different sides of the other
+    if d0 != d1
+        && d0 != Ordering::Equal
+        && d1 != Ordering::Equal
+        && d2 != d3
+        && d2 != Ordering::Equal
+        && d3 != Ordering::Equal
+    {
+        return true;
+    }
+
+    on_segment(a0, a1, b0)
+        || on_segment(a0, a1, b1)
+        || on_segment(b0, b1, a0)
+        || on_segment(b0, b1, a1)
+}
+
+/// Where a point is compared to a polygon
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    Outside,
+    Boundary,
+    Inside,
+}
+
+impl Containment {
+    /// Inside or on the boundary
+    pub fn is_covered(self) -> bool {
+        self != Containment::Outside
+    }
+}
+
+/// Whether `p` is inside `poly`, which can be any shape that doesn't cross itself
+///
+/// This counts how many edges a ray going right from `p` crosses: it's inside if that's odd.
+pub fn point_in_polygon<P: LatticePoint>(poly: &[P], p: impl LatticePoint) -> Containment {
+    let [px, py] = p.xy();
+    let mut inside = false;
+
+    for (a, b) in edges(poly) {
+        if on_segment(a, b, p) {
+            return Containment::Boundary;
+        }
+
+        // Count edges that start on or below the ray and end above it (or the other way around), so a corner that's
+        // right on the ray only counts once
+        let ([ax, ay], [bx, by]) = (a, b);
+        if (ay > py) != (by > py) {
+            // Where the edge crosses y = py, compared to px, without dividing
+            let cross = (bx - ax) * (py - ay) - (px - ax) * (by - ay);
+            if (cross > 0) == (by > ay) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+/// Like [`point_in_polygon()`], but faster for polygons whose edges are all horizontal or vertical
+///
+/// Only the vertical edges can cross a ray going right, so those are the only ones it does math on.
+pub fn point_in_rectilinear<P: LatticePoint>(poly: &[P], p: impl LatticePoint) -> Containment {
+    let [px, py] = p.xy();
+    let mut inside = false;
+
+    for ([ax, ay], [bx, by]) in edges(poly) {
+        debug_assert!(
+            ax == bx || ay == by,
+            "({ax}, {ay}) -> ({bx}, {by}) isn't horizontal or vertical"
+        );
+
+        let (y0, y1) = (ay.min(by), ay.max(by));
+        if ay == by {
+            if py == ay && ax.min(bx) <= px && px <= ax.max(bx) {
+                return Containment::Boundary;
+            }
+        } else if px == ax && y0 <= py && py <= y1 {
+            return Containment::Boundary;
+        } else if ax > px && y0 <= py && py < y1 {
+            inside = !inside;
+        }
+    }
+
+    if inside {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+/// A rectangle of lattice points, including its edges
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: [i64; 2],
+    pub max: [i64; 2],
+}
+
+impl Rect {
+    /// The rectangle with opposite corners `a` and `b`, in any order
+    pub fn from_corners(a: impl LatticePoint, b: impl LatticePoint) -> Self {
+        let ([ax, ay], [bx, by]) = (a.xy(), b.xy());
+        Self {
+            min: [ax.min(bx), ay.min(by)],
+            max: [ax.max(bx), ay.max(by)],
+        }
+    }
+
+    pub fn xs(&self) -> RangeInclusive<i64> {
+        self.min[0]..=self.max[0]
+    }
+
+    pub fn ys(&self) -> RangeInclusive<i64> {
+        self.min[1]..=self.max[1]
+    }
+
+    /// How many lattice points it covers, which is one more than its size along each axis
+    pub fn area(&self) -> i64 {
+        (self.max[0] - self.min[0] + 1) * (self.max[1] - self.min[1] + 1)
+    }
+
+    pub fn contains(&self, p: impl LatticePoint) -> bool {
+        let [x, y] = p.xy();
+        self.xs().contains(&x) && self.ys().contains(&y)
+    }
+
+    /// What's left after taking `by` off of every side, if anything is
+    pub fn shrink(&self, by: i64) -> Option<Self> {
+        let rect = Self {
+            min: self.min.map(|c| c + by),
+            max: self.max.map(|c| c - by),
+        };
+        (rect.min[0] <= rect.max[0] && rect.min[1] <= rect.max[1]).then_some(rect)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let rect = Self {
+            min: [self.min[0].max(other.min[0]), self.min[1].max(other.min[1])],
+            max: [self.max[0].min(other.max[0]), self.max[1].min(other.max[1])],
+        };
+        (rect.min[0] <= rect.max[0] && rect.min[1] <= rect.max[1]).then_some(rect)
+    }
+
+    /// The four corners, going counter-clockwise from `min`
+    pub fn corners(&self) -> [[i64; 2]; 4] {
+        let ([x0, y0], [x1, y1]) = (self.min, self.max);
+        [[x0, y0], [x1, y0], [x1, y1], [x0, y1]]
+    }
+
+    /// Whether the segment from `a` to `b` touches any part of this, including the edges
+    pub fn intersects_segment(&self, a: impl LatticePoint, b: impl LatticePoint) -> bool {
+        if self.contains(a) || self.contains(b) {
+            return true;
+        }
+
+        let corners = self.corners();
+        (0..4).any(|i| segments_intersect(a, b, corners[i], corners[(i + 1) % 4]))
+    }
+}
+
+/// Squishes a sparse set of coordinates down to `0..len()`, keeping their order
+///
+/// Puzzles with huge coordinates often only care about the handful where something changes, so working with their
+/// indices instead keeps grids small.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoordCompression {
+    /// Every coordinate, sorted and without repeats
+    values: Vec<i64>,
+}
+
+impl CoordCompression {
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Which index `value` was squished to, if it was one of the coordinates
+    pub fn get(&self, value: i64) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    /// Which index `value` was squished to, which must be one of the coordinates
+    #[track_caller]
+    pub fn index(&self, value: i64) -> usize {
+        self.get(value)
+            .unwrap_or_else(|| panic!("{value} isn't one of the compressed coordinates"))
+    }
+
+    /// The coordinate at index `i`
+    pub fn value(&self, i: usize) -> i64 {
+        self.values[i]
+    }
+
+    /// Every coordinate, in order
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    /// The example from 2025 day 9, which is a rectilinear polygon
+    const RED_TILES: [[i64; 2]; 8] = [
+        [7, 1],
+        [11, 1],
+        [11, 7],
+        [9, 7],
+        [9, 5],
+        [2, 5],
+        [2, 3],
+        [7, 3],
+    ];
+
+    #[rstest]
+    #[case::unit_square(2, 4, 0, &[[0, 0], [1, 0], [1, 1], [0, 1]])]
+    #[case::clockwise_square(-2, 4, 0, &[[0, 0], [0, 1], [1, 1], [1, 0]])]
+    #[case::triangle(12, 8, 3, &[[0, 0], [4, 0], [0, 3]])]
+    #[case::red_tiles(2 * 30, 30, 16, &RED_TILES)]
+    #[trace]
+    fn check_area_and_picks(
+        #[case] expected_twice_signed: i64,
+        #[case] expected_boundary: i64,
+        #[case] expected_interior: i64,
+        #[case] poly: &[[i64; 2]],
+    ) {
+        assert_eq!(twice_signed_area(poly), expected_twice_signed);
+        assert_eq!(twice_area(poly), expected_twice_signed.abs());
+        assert_eq!(boundary_points(poly), expected_boundary);
+        assert_eq!(interior_points(poly), expected_interior);
+    }
+
+    #[test]
+    fn check_picks_matches_counting() {
+        // Every lattice point in the bounding box is either inside, on, or outside it
+        let bounds = Rect::from_corners([2, 1], [11, 7]);
+        let mut counts = [0; 3];
+        for x in bounds.xs() {
+            for y in bounds.ys() {
+                let c = point_in_polygon(&RED_TILES, [x, y]);
+                assert_eq!(c, point_in_rectilinear(&RED_TILES, [x, y]), "({x}, {y})");
+                counts[c as usize] += 1;
+            }
+        }
+        assert_eq!(
+            counts[Containment::Boundary as usize],
+            boundary_points(&RED_TILES)
+        );
+        assert_eq!(
+            counts[Containment::Inside as usize],
+            interior_points(&RED_TILES)
+        );
+    }
+
+    #[rstest]
+    #[case::inside(Containment::Inside, [2, 1])]
+    #[case::vertex(Containment::Boundary, [4, 0])]
+    #[case::edge(Containment::Boundary, [0, 1])]
+    #[case::slanted_edge(Containment::Boundary, [3, 3])]
+    #[case::outside(Containment::Outside, [4, 3])]
+    #[case::level_with_vertices(Containment::Inside, [1, 2])]
+    #[case::left_of_vertices(Containment::Outside, [-1, 2])]
+    #[case::left_of_top(Containment::Outside, [-1, 4])]
+    #[case::left_of_bottom(Containment::Outside, [-1, 0])]
+    #[trace]
+    fn check_point_in_polygon(#[case] expected: Containment, #[case] p: [i64; 2]) {
+        // A diamond with a flat bottom, so rays can go right through vertices
+        let poly = [[0, 0], [4, 0], [4, 2], [2, 4], [0, 2]];
+        assert_eq!(point_in_polygon(&poly, p), expected);
+    }
+
+    #[rstest]
+    #[case::crossing(true, [0, 0], [2, 2], [0, 2], [2, 0])]
+    #[case::touching_end(true, [0, 0], [2, 2], [2, 2], [3, 0])]
+    #[case::t_junction(true, [0, 0], [4, 0], [2, 0], [2, 5])]
+    #[case::collinear_overlap(true, [0, 0], [4, 0], [3, 0], [6, 0])]
+    #[case::collinear_apart(false, [0, 0], [2, 0], [3, 0], [6, 0])]
+    #[case::parallel(false, [0, 0], [4, 0], [0, 1], [4, 1])]
+    #[case::near_miss(false, [0, 0], [2, 2], [3, 0], [2, 1])]
+    #[trace]
+    fn check_segments_intersect(
+        #[case] expected: bool,
+        #[case] a0: [i64; 2],
+        #[case] a1: [i64; 2],
+        #[case] b0: [i64; 2],
+        #[case] b1: [i64; 2],
+    ) {
+        assert_eq!(segments_intersect(a0, a1, b0, b1), expected);
+        assert_eq!(segments_intersect(b1, b0, a1, a0), expected);
+    }
+
+    #[test]
+    fn check_rect() {
+        let rect = Rect::from_corners(IVec2::new(9, 5), IVec2::new(2, 3));
+        assert_eq!(
+            rect,
+            Rect {
+                min: [2, 3],
+                max: [9, 5]
+            }
+        );
+        assert_eq!(rect.area(), 24);
+        assert!(rect.contains([2, 5]));
+        assert!(!rect.contains([1, 5]));
+
+        assert_eq!(
+            rect.shrink(1),
+            Some(Rect {
+                min: [3, 4],
+                max: [8, 4]
+            })
+        );
+        assert_eq!(rect.shrink(2), None);
+
+        let other = Rect::from_corners([7, 1], [11, 7]);
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect {
+                min: [7, 3],
+                max: [9, 5]
+            })
+        );
+        assert_eq!(
+            rect.intersection(&Rect::from_corners([10, 0], [12, 2])),
+            None
+        );
+
+        assert!(rect.intersects_segment([0, 0], [20, 10]));
+        assert!(rect.intersects_segment([5, 0], [5, 3]));
+        assert!(!rect.intersects_segment([5, 0], [5, 2]));
+        assert!(!rect.intersects_segment([0, 0], [10, 1]));
+    }
+
+    #[test]
+    fn check_coord_compression() {
+        let xs = CoordCompression::new([1_000_000, -5, 42, 42, 7]);
+        assert_eq!(xs.len(), 4);
+        assert_eq!(xs.values(), [-5, 7, 42, 1_000_000]);
+        assert_eq!(xs.index(42), 2);
+        assert_eq!(xs.value(3), 1_000_000);
+        assert_eq!(xs.get(8), None);
+    }
+}
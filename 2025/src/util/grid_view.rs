@@ -0,0 +1,259 @@
+use crate::prelude::*;
+
+use std::ops::Index;
+
+use super::Framebuffer;
+
+/// A read-only grid that borrows the puzzle input instead of copying it
+///
+/// Coordinates match [`Framebuffer::parse_grid()`]: `x` goes right and `y` goes *up*, so the first line of text is
+/// `y = height - 1`. That way a `GridView` and the `Framebuffer` made from it agree on every `IVec2`.
+///
+/// Lines are found with a fixed stride, so every line must be the same length. `"\r\n"` and a missing trailing
+/// newline are both fine.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GridView<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    /// Bytes from the start of one line to the next, including the line ending
+    stride: usize,
+}
+
+impl<'a> GridView<'a> {
+    #[track_caller]
+    pub fn new(input: &'a str) -> Self {
+        Self::from_bytes(input.as_bytes())
+    }
+
+    #[track_caller]
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        debug_assert!(
+            !bytes.is_empty(),
+            "Parsing a grid from an empty string is probably not on purpose."
+        );
+
+        let (width, stride) = match memchr(b'\n', bytes) {
+            Some(nl) if nl > 0 && bytes[nl - 1] == b'\r' => (nl - 1, nl + 1),
+            Some(nl) => (nl, nl + 1),
+            None => (bytes.len(), bytes.len() + 1),
+        };
+        // The last line might not have its line ending
+        let height = (bytes.len() + stride - width) / stride;
+        assert!(
+            bytes.len() == height * stride || bytes.len() + stride - width == height * stride,
+            "Is the input string actually a grid? {} bytes doesn't fit {width}x{height} with a stride of {stride}",
+            bytes.len()
+        );
+        if cfg!(debug_assertions) {
+            for y in 0..height - 1 {
+                assert_eq!(
+                    bytes[y * stride + stride - 1],
+                    b'\n',
+                    "Is the input string actually a grid? Line {y} isn't {width} long"
+                );
+            }
+        }
+
+        Self {
+            bytes,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// The whole input, line endings and all. Use with [`GridView::xy_of()`] to search it quickly.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn in_bounds(&self, xy: IVec2) -> bool {
+        (0..self.width as i32).contains(&xy.x) && (0..self.height as i32).contains(&xy.y)
+    }
+
+    /// Byte offset of `xy` in [`GridView::as_bytes()`]
+    pub fn idx_of(&self, xy: IVec2) -> Option<usize> {
+        if !self.in_bounds(xy) {
+            return None;
+        }
+        let line = self.height - 1 - xy.y as usize;
+
+        Some(line * self.stride + xy.x as usize)
+    }
+
+    /// Coordinates of a byte offset in [`GridView::as_bytes()`]. Line endings aren't in the grid, so they're `None`.
+    pub fn xy_of(&self, idx: usize) -> Option<IVec2> {
+        let (line, x) = (idx / self.stride, idx % self.stride);
+        if x >= self.width || line >= self.height {
+            return None;
+        }
+
+        Some(IVec2::new(x as i32, (self.height - 1 - line) as i32))
+    }
+
+    pub fn get(&self, xy: IVec2) -> Option<u8> {
+        self.idx_of(xy).map(|idx| self.bytes[idx])
+    }
+
+    /// Where `b` first shows up, reading the text top to bottom
+    pub fn find(&self, b: u8) -> Option<IVec2> {
+        memchr(b, self.bytes).and_then(|idx| self.xy_of(idx))
+    }
+
+    /// The line of text at `y`, without its line ending
+    pub fn row(&self, y: i32) -> &'a [u8] {
+        assert!(
+            (0..self.height as i32).contains(&y),
+            "oob row {y} with height={}",
+            self.height
+        );
+        let start = (self.height - 1 - y as usize) * self.stride;
+
+        &self.bytes[start..start + self.width]
+    }
+
+    /// Every cell, in the same order as the text
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, u8)> + 'a {
+        let view = *self;
+        (0..self.height as i32).rev().flat_map(move |y| {
+            view.row(y)
+                .iter()
+                .enumerate()
+                .map(move |(x, &b)| (IVec2::new(x as i32, y), b))
+        })
+    }
+
+    /// The in-bounds cells north, south, east, and west of `xy`
+    pub fn neighbors(&self, xy: IVec2) -> impl Iterator<Item = (IVec2, u8)> + 'a {
+        let view = *self;
+        Cardinal::ALL_NO_DIAG.into_iter().filter_map(move |dir| {
+            let next = xy + IVec2::from(dir);
+            view.get(next).map(|b| (next, b))
+        })
+    }
+
+    /// The in-bounds cells around `xy`, including diagonals
+    pub fn neighbors8(&self, xy: IVec2) -> impl Iterator<Item = (IVec2, u8)> + 'a {
+        let view = *self;
+        (-1..=1)
+            .cartesian_product(-1..=1)
+            .filter(|&d| d != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let next = xy + IVec2::new(dx, dy);
+                view.get(next).map(|b| (next, b))
+            })
+    }
+
+    /// Copy into a [`Framebuffer`], a line at a time
+    pub fn to_framebuffer(&self) -> Framebuffer<u8> {
+        Framebuffer::from(*self)
+    }
+
+    /// Build a [`Framebuffer`] of anything, like [`Framebuffer::parse_grid()`] does
+    pub fn map<T: Default + Clone>(&self, mut f: impl FnMut(u8) -> T) -> Framebuffer<T> {
+        let mut grid = Framebuffer::new(self.width as u32, self.height as u32);
+        for (xy, b) in self.iter() {
+            grid[xy] = f(b);
+        }
+
+        grid
+    }
+}
+
+impl Index<IVec2> for GridView<'_> {
+    type Output = u8;
+
+    #[track_caller]
+    fn index(&self, xy: IVec2) -> &Self::Output {
+        let Some(idx) = self.idx_of(xy) else {
+            panic!(
+                "oob index ({}, {}) dims=({}, {})",
+                xy.x, xy.y, self.width, self.height
+            );
+        };
+
+        &self.bytes[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    const EXAMPLE: &str = "\
+S.#
+.#.
+..E";
+
+    #[rstest]
+    #[case::lf(EXAMPLE.to_string())]
+    #[case::trailing_lf(format!("{EXAMPLE}\n"))]
+    #[case::crlf(EXAMPLE.replace('\n', "\r\n"))]
+    #[case::trailing_crlf(format!("{EXAMPLE}\n").replace('\n', "\r\n"))]
+    #[trace]
+    fn check_matches_framebuffer(#[case] input: String) {
+        let view = GridView::new(&input);
+        let fb = Framebuffer::parse_grid_u8(EXAMPLE);
+
+        assert_eq!((view.width(), view.height()), (fb.width(), fb.height()));
+        for (x, y) in fb.iter_coords() {
+            let xy = IVec2::new(x, y);
+            assert_eq!(view[xy], fb[xy], "at {xy:?}");
+        }
+        assert!(view.to_framebuffer() == fb);
+    }
+
+    #[test]
+    fn check_find_and_offsets() {
+        let view = GridView::new(EXAMPLE);
+
+        assert_eq!(view.find(b'S'), Some(IVec2::new(0, 2)));
+        assert_eq!(view.find(b'E'), Some(IVec2::new(2, 0)));
+        assert_eq!(view.find(b'?'), None);
+
+        // The '\n' at offset 3 isn't a cell
+        assert_eq!(view.xy_of(3), None);
+        for (xy, b) in view.iter() {
+            let idx = view.idx_of(xy).unwrap();
+            assert_eq!(view.as_bytes()[idx], b);
+            assert_eq!(view.xy_of(idx), Some(xy));
+        }
+    }
+
+    #[test]
+    fn check_neighbors() {
+        let view = GridView::new(EXAMPLE);
+        let corner = view
+            .neighbors(IVec2::new(0, 0))
+            .map(|(_, b)| b)
+            .sorted()
+            .collect_vec();
+        assert_eq!(corner, [b'.', b'.']);
+
+        assert_eq!(view.neighbors(IVec2::new(1, 1)).count(), 4);
+        assert_eq!(view.neighbors8(IVec2::new(1, 1)).count(), 8);
+        assert_eq!(view.neighbors8(IVec2::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_ragged_grid() {
+        GridView::new("...\n..\n...");
+    }
+}
@@ -0,0 +1,382 @@
+//! Sets of numbers stored as the ranges they cover, for when there are far too many numbers to list
+//!
+//! ```
+//! use aoc25::util::IntervalSet;
+//!
+//! let fresh: IntervalSet = [3..=5, 10..=14, 12..=18].into_iter().collect();
+//! assert!(fresh.contains(17));
+//! assert_eq!(fresh.total_len(), 3 + 9);
+//! ```
+use core::fmt;
+use core::ops::{Bound, Range, RangeBounds, RangeInclusive};
+
+use num::PrimInt;
+
+/// A set of numbers, kept as sorted ranges that don't overlap or touch
+///
+/// Anything that's a [`RangeBounds`] can go in, so `a..b`, `a..=b`, and `a..` all work. Inside, every range is
+/// half-open, which means `T::max_value()` itself can never be in the set.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T = i64> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt + fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+/// Turns any kind of range into the half-open one covering the same numbers
+fn half_open<T: PrimInt>(range: impl RangeBounds<T>) -> Range<T> {
+    let start = match range.start_bound() {
+        Bound::Included(&a) => a,
+        Bound::Excluded(&a) => a.saturating_add(T::one()),
+        Bound::Unbounded => T::min_value(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&b) => b.saturating_add(T::one()),
+        Bound::Excluded(&b) => b,
+        Bound::Unbounded => T::max_value(),
+    };
+    start..end
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub const fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Builds a set out of ranges that are already sorted by where they start, without searching for where each goes
+    fn from_sorted(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut merged: Vec<Range<T>> = vec![];
+        for r in ranges {
+            if r.is_empty() {
+                continue;
+            }
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many numbers are in the set, across all of its ranges
+    pub fn total_len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |sum, r| sum + (r.end - r.start))
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= x);
+        self.ranges.get(i).is_some_and(|r| r.start <= x)
+    }
+
+    /// The set's ranges in order, half-open
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + ExactSizeIterator + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// The set's ranges in order, with both ends in them
+    pub fn iter_inclusive(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + ExactSizeIterator + '_ {
+        self.ranges.iter().map(|r| r.start..=r.end - T::one())
+    }
+
+    /// Adds every number in `range`, merging it with any ranges it overlaps or touches
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        let range = half_open(range);
+        if range.is_empty() {
+            return;
+        }
+
+        // Everything from `lo` up to `hi` overlaps or touches the new range, so they all become one
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let mut merged = range;
+        if lo < hi {
+            merged.start = merged.start.min(self.ranges[lo].start);
+            merged.end = merged.end.max(self.ranges[hi - 1].end);
+        }
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Takes out every number in `range`, which might split a range in two
+    pub fn remove(&mut self, range: impl RangeBounds<T>) {
+        let range = half_open(range);
+        if range.is_empty() {
+            return;
+        }
+
+        // Everything from `lo` up to `hi` overlaps it, and only the first and last can stick out past it
+        let lo = self.ranges.partition_point(|r| r.end <= range.start);
+        let hi = self.ranges.partition_point(|r| r.start < range.end);
+        if lo >= hi {
+            return;
+        }
+
+        let left = self.ranges[lo].start..range.start;
+        let right = range.end..self.ranges[hi - 1].end;
+        let kept = [left, right].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(lo..hi, kept);
+    }
+
+    /// Everything in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if x.start <= y.start => a.next(),
+                (Some(_), Some(_)) => b.next(),
+                (Some(_), None) => a.next(),
+                (None, _) => b.next(),
+            };
+            match next {
+                Some(r) => ranges.push(r.clone()),
+                None => break,
+            }
+        }
+        Self::from_sorted(ranges)
+    }
+
+    /// Everything in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            // Whichever ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Everything in this set that isn't in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let Some(bounds) = self.bounds() else {
+            return Self::new();
+        };
+        self.intersection(&other.complement(bounds))
+    }
+
+    /// Everything in `within` that isn't in this set
+    pub fn complement(&self, within: impl RangeBounds<T>) -> Self {
+        let within = half_open(within);
+
+        let mut gaps = vec![];
+        let mut start = within.start;
+        for r in &self.ranges {
+            gaps.push(start..r.start.min(within.end));
+            start = start.max(r.end);
+        }
+        gaps.push(start..within.end);
+
+        Self::from_sorted(gaps)
+    }
+
+    /// Splits the set into everything below `at`, and everything at or above it
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let i = self.ranges.partition_point(|r| r.end <= at);
+        let mut below = self.ranges[..i].to_vec();
+        let mut above = self.ranges[i..].to_vec();
+
+        if let Some(r) = above.first_mut() {
+            if r.start < at {
+                below.push(r.start..at);
+                r.start = at;
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Sends every number through a piecewise mapping, a range at a time
+    ///
+    /// Each piece is a source range and where its start ends up, so `(98..100, 50)` sends 98 to 50 and 99 to 51.
+    /// Pieces shouldn't overlap each other, and anything that isn't in any piece stays where it is.
+    pub fn map_pieces<R: RangeBounds<T>>(&self, pieces: impl IntoIterator<Item = (R, T)>) -> Self {
+        let mut rest = self.clone();
+        let mut mapped = vec![];
+        for (src, dst) in pieces {
+            let src = half_open(src);
+            let hit = rest.intersection(&Self {
+                ranges: vec![src.clone()],
+            });
+            for r in hit.ranges {
+                mapped.push(dst + (r.start - src.start)..dst + (r.end - src.start));
+            }
+            rest.remove(src);
+        }
+
+        mapped.sort_by_key(|r| r.start);
+        rest.union(&Self::from_sorted(mapped))
+    }
+
+    /// The smallest range that holds everything in the set
+    fn bounds(&self) -> Option<Range<T>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(first.start..last.end)
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> Extend<R> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: PrimInt, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut ranges = iter.into_iter().map(half_open).collect::<Vec<_>>();
+        ranges.sort_by_key(|r| r.start);
+        Self::from_sorted(ranges)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod test {
+    use super::*;
+    use itertools::Itertools;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    fn inclusive(set: &IntervalSet) -> Vec<(i64, i64)> {
+        set.iter_inclusive()
+            .map(|r| (*r.start(), *r.end()))
+            .collect_vec()
+    }
+
+    // The first three are from the `check_merge_ranges` that this replaced
+    #[rstest]
+    #[case::simple([(3, 5), (10, 20)], [(3, 5), (10, 14), (16, 20), (12, 18) ])]
+    #[case::adjacent([(10, 30)], [(10, 20), (20, 30)])]
+    #[case::superset([(1, 100)], [(1, 100), (10, 20), (30, 40)])]
+    #[case::touching([(1, 9)], [(1, 4), (5, 9)])]
+    #[case::unsorted([(1, 2), (5, 9)], [(7, 9), (1, 2), (5, 6)])]
+    fn check_insert(
+        #[case] expected: impl IntoIterator<Item = (i64, i64)>,
+        #[case] ranges: impl IntoIterator<Item = (i64, i64)>,
+    ) {
+        let expected = expected.into_iter().collect_vec();
+        let ranges = ranges.into_iter().map(|(a, b)| a..=b).collect_vec();
+
+        let collected: IntervalSet = ranges.iter().cloned().collect();
+        assert_eq!(inclusive(&collected), expected);
+
+        let mut inserted = IntervalSet::new();
+        for r in ranges {
+            inserted.insert(r);
+        }
+        assert_eq!(inserted, collected);
+    }
+
+    #[test]
+    fn check_remove() {
+        let mut set: IntervalSet = [0..10, 20..30].into_iter().collect();
+
+        set.remove(3..5);
+        assert_eq!(set.iter().collect_vec(), [0..3, 5..10, 20..30]);
+
+        set.remove(8..=22);
+        assert_eq!(set.iter().collect_vec(), [0..3, 5..8, 23..30]);
+
+        set.remove(..6);
+        assert_eq!(set.iter().collect_vec(), [6..8, 23..30]);
+
+        set.remove(100..200);
+        set.remove(10..10);
+        assert_eq!(set.iter().collect_vec(), [6..8, 23..30]);
+
+        set.remove(..);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn check_contains() {
+        let set: IntervalSet = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+        let ids = [1, 5, 8, 11, 17, 32];
+        let fresh = ids.into_iter().filter(|&id| set.contains(id)).collect_vec();
+
+        assert_eq!(fresh, [5, 11, 17]);
+        assert_eq!(set.total_len(), 14);
+    }
+
+    #[test]
+    fn check_set_ops() {
+        let a: IntervalSet = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet = [5..25, 40..50].into_iter().collect();
+
+        assert_eq!(a.union(&b).iter().collect_vec(), [0..30, 40..50]);
+        assert_eq!(a.intersection(&b).iter().collect_vec(), [5..10, 20..25]);
+        assert_eq!(a.difference(&b).iter().collect_vec(), [0..5, 25..30]);
+        assert_eq!(b.difference(&a).iter().collect_vec(), [10..20, 40..50]);
+        assert_eq!(
+            a.complement(-5..35).iter().collect_vec(),
+            [-5..0, 10..20, 30..35]
+        );
+        assert_eq!(a.complement(12..18).iter().collect_vec(), [12..18]);
+        assert_eq!(a.complement(0..30).complement(0..30), a);
+
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+        assert_eq!(a.union(&IntervalSet::new()), a);
+    }
+
+    #[test]
+    fn check_split_at() {
+        let set: IntervalSet<u32> = [0..10, 20..30].into_iter().collect();
+
+        let (below, above) = set.split_at(25);
+        assert_eq!(below.iter().collect_vec(), [0..10, 20..25]);
+        assert_eq!(above.iter().collect_vec(), [25..30]);
+
+        let (below, above) = set.split_at(15);
+        assert_eq!(below.iter().collect_vec(), [0..10]);
+        assert_eq!(above.iter().collect_vec(), [20..30]);
+    }
+
+    #[test]
+    fn check_map_pieces() {
+        // 2023 day 5's seed-to-soil map, which is "destination source length"
+        let seed_to_soil = [(98..100, 50), (50..98, 52)];
+
+        let seeds: IntervalSet = [79..93, 55..68].into_iter().collect();
+        let soil = seeds.map_pieces(seed_to_soil.clone());
+        assert_eq!(soil.iter().collect_vec(), [57..70, 81..95]);
+
+        // Straddling the pieces splits it up, and what's outside of them stays put
+        let seeds: IntervalSet = [40..60, 97..110].into_iter().collect();
+        let soil = seeds.map_pieces(seed_to_soil);
+        assert_eq!(soil.iter().collect_vec(), [40..62, 99..110]);
+    }
+}
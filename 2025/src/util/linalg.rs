@@ -0,0 +1,559 @@
+//! Exact math for puzzles that are really systems of equations
+//!
+//! - [`solve_rational()`]: every solution to `A x = b`, as fractions so nothing rounds wrong
+//! - [`solve_gf2()`]: the same, but where adding is XOR, like toggling lights
+//! - [`min_nonneg_integer_solution()`]: the cheapest `x` made of whole numbers that are at least 0
+//! - [`mod_inv()`], [`crt()`], [`lcm_all()`]: for when things line up every so many steps
+//!
+//! ```
+//! use aoc25::util::linalg::min_nonneg_integer_solution;
+//!
+//! // Button `j` adds 1 to every counter `i` with `a[i][j] == 1`: how few presses get to `goal`?
+//! let a = [vec![1, 1, 0], vec![0, 1, 1]];
+//! let goal = [3, 5];
+//! let (presses, counts) = min_nonneg_integer_solution(&a, &goal, &[1, 1, 1]).unwrap();
+//! assert_eq!(presses, 5);
+//! assert_eq!(counts, [0, 3, 2]);
+//! ```
+use num::rational::Ratio;
+use num::{Integer, One, Signed, Zero};
+
+/// Exact fractions, big enough to not overflow on most puzzle inputs
+pub type Rational = Ratio<i128>;
+
+/// Every solution to `A x = b`, which is `particular` plus any mix of `null_space`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinearSolution {
+    /// One solution, with every free variable set to 0
+    pub particular: Vec<Rational>,
+    /// Every `v` where `A v = 0`, one per free variable
+    pub null_space: Vec<Vec<Rational>>,
+}
+
+/// `A x = b` after Gauss-Jordan elimination, with a 1 in each pivot and 0s above and below it
+struct Rref {
+    /// `rows[r]` is `A` then `b`
+    rows: Vec<Vec<Rational>>,
+    /// Which variable each row solves for
+    pivots: Vec<usize>,
+    /// Every variable that isn't a pivot, and can be anything
+    free: Vec<usize>,
+}
+
+/// Reduces `A x = b` to row echelon form, or returns `None` if there's no solution
+fn rref(a: &[Vec<i64>], b: &[i64]) -> Option<Rref> {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "A has {} rows but b has {}",
+        a.len(),
+        b.len()
+    );
+    let n = a.first().map_or(0, Vec::len);
+
+    let mut rows: Vec<Vec<Rational>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &b)| {
+            assert_eq!(row.len(), n, "Every row of A should be {n} long");
+            row.iter()
+                .chain([&b])
+                .map(|&x| Rational::from(x as i128))
+                .collect()
+        })
+        .collect();
+
+    let mut pivots = vec![];
+    let mut free = vec![];
+    for col in 0..n {
+        let r = pivots.len();
+        let Some(p) = (r..rows.len()).find(|&i| !rows[i][col].is_zero()) else {
+            free.push(col);
+            continue;
+        };
+        rows.swap(r, p);
+
+        let scale = rows[r][col];
+        for x in &mut rows[r] {
+            *x /= scale;
+        }
+
+        for i in 0..rows.len() {
+            let factor = rows[i][col];
+            if i == r || factor.is_zero() {
+                continue;
+            }
+            for j in col..=n {
+                let sub = factor * rows[r][j];
+                rows[i][j] -= sub;
+            }
+        }
+        pivots.push(col);
+    }
+
+    // Whatever's left over says `0 = b`, so `b` had better be 0
+    if rows[pivots.len()..].iter().any(|row| !row[n].is_zero()) {
+        return None;
+    }
+    rows.truncate(pivots.len());
+
+    Some(Rref { rows, pivots, free })
+}
+
+/// Every rational solution to `A x = b`, or `None` if there aren't any
+pub fn solve_rational(a: &[Vec<i64>], b: &[i64]) -> Option<LinearSolution> {
+    let n = a.first().map_or(0, Vec::len);
+    let Rref { rows, pivots, free } = rref(a, b)?;
+
+    let mut particular = vec![Rational::zero(); n];
+    for (row, &p) in rows.iter().zip(&pivots) {
+        particular[p] = row[n];
+    }
+
+    let null_space = free
+        .iter()
+        .map(|&f| {
+            let mut v = vec![Rational::zero(); n];
+            v[f] = Rational::one();
+            for (row, &p) in rows.iter().zip(&pivots) {
+                v[p] = -row[f];
+            }
+            v
+        })
+        .collect();
+
+    Some(LinearSolution {
+        particular,
+        null_space,
+    })
+}
+
+/// A basis for every `v` where `A v = 0`, scaled to the smallest whole numbers
+pub fn integer_null_space(a: &[Vec<i64>]) -> Vec<Vec<i64>> {
+    let b = vec![0; a.len()];
+    let solution = solve_rational(a, &b).expect("A v = 0 always has v = 0");
+
+    solution
+        .null_space
+        .into_iter()
+        .map(|v| {
+            let denom = v.iter().fold(1, |l, x| l.lcm(x.denom()));
+            let nums = v
+                .iter()
+                .map(|x| x.numer() * (denom / x.denom()))
+                .collect::<Vec<_>>();
+            let gcd = nums.iter().fold(0, |g, x| g.gcd(x)).max(1);
+            nums.into_iter().map(|x| (x / gcd) as i64).collect()
+        })
+        .collect()
+}
+
+/// Every solution to `A x = b` over GF(2), where adding is XOR
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2Solution {
+    /// One solution, with every free variable set to 0. Bit `j` is `x[j]`.
+    pub particular: u64,
+    /// Every `v` where `A v = 0`, one per free variable
+    pub null_space: Vec<u64>,
+}
+
+impl Gf2Solution {
+    /// The solution with the fewest 1s, by trying every mix of the null space
+    pub fn min_weight(&self) -> u64 {
+        let all = (1_u64 << self.null_space.len()) - 1;
+        crate::util::bitmask::submasks(all)
+            .map(|mix| {
+                (0..self.null_space.len())
+                    .filter(|&i| mix & (1 << i) != 0)
+                    .fold(self.particular, |x, i| x ^ self.null_space[i])
+            })
+            .min_by_key(|x| x.count_ones())
+            .unwrap()
+    }
+}
+
+/// Every solution to `A x = b` over GF(2), or `None` if there aren't any
+///
+/// `A` is given by its columns: bit `i` of `columns[j]` is `A[i][j]`, so each variable is the rows it flips. That's
+/// up to 64 variables and 64 equations.
+pub fn solve_gf2(columns: &[u64], b: u64) -> Option<Gf2Solution> {
+    let n = columns.len();
+    assert!(n <= 64, "solve_gf2() only has room for 64 variables");
+
+    // Transpose into rows, with `b` as bit 64
+    let mut rows: Vec<u128> = (0..64)
+        .map(|i| {
+            let vars = (0..n)
+                .filter(|&j| columns[j] & (1 << i) != 0)
+                .fold(0_u128, |row, j| row | (1 << j));
+            vars | (((b >> i) & 1) as u128) << 64
+        })
+        .filter(|&row| row != 0)
+        .collect();
+
+    let mut pivots = vec![];
+    let mut free = vec![];
+    for col in 0..n {
+        let r = pivots.len();
+        let Some(p) = (r..rows.len()).find(|&i| rows[i] & (1 << col) != 0) else {
+            free.push(col);
+            continue;
+        };
+        rows.swap(r, p);
+
+        for i in 0..rows.len() {
+            if i != r && rows[i] & (1 << col) != 0 {
+                rows[i] ^= rows[r];
+            }
+        }
+        pivots.push(col);
+    }
+
+    if rows[pivots.len()..].iter().any(|&row| row != 0) {
+        return None;
+    }
+
+    let particular = rows
+        .iter()
+        .zip(&pivots)
+        .filter(|&(&row, _)| row >> 64 != 0)
+        .fold(0, |x, (_, &p)| x | (1 << p));
+
+    let null_space = free
+        .iter()
+        .map(|&f| {
+            rows.iter()
+                .zip(&pivots)
+                .filter(|&(&row, _)| row & (1 << f) != 0)
+                .fold(1 << f, |v, (_, &p)| v | (1 << p))
+        })
+        .collect();
+
+    Some(Gf2Solution {
+        particular,
+        null_space,
+    })
+}
+
+/// The cheapest solution to `A x = b` where every `x[j]` is a whole number that's at least 0, as its cost and `x`
+///
+/// Every variable needs an upper bound, which comes from rows with no negative numbers in them: if `A[i][j] > 0`,
+/// then `x[j]` can't be more than `b[i] / A[i][j]`. This tries everything for the free variables, and skips
+/// whatever can't beat the best so far or would make a pivot variable negative.
+pub fn min_nonneg_integer_solution(
+    a: &[Vec<i64>],
+    b: &[i64],
+    cost: &[i64],
+) -> Option<(i64, Vec<i64>)> {
+    let n = a.first().map_or(0, Vec::len);
+    assert_eq!(cost.len(), n, "Every variable needs a cost");
+
+    let upper: Vec<i64> = (0..n)
+        .map(|j| {
+            (0..a.len())
+                .filter(|&i| a[i][j] > 0 && a[i].iter().all(|&x| x >= 0))
+                .map(|i| b[i] / a[i][j])
+                .min()
+                .unwrap_or_else(|| panic!("x[{j}] doesn't have an upper bound"))
+        })
+        .collect();
+
+    let rref = rref(a, b)?;
+
+    // Each pivot is `rhs - sum(coef * free)`, so the whole cost is a constant plus a "reduced" cost per free variable
+    let cost_of = |j: usize| Rational::from(cost[j] as i128);
+    let base_cost: Rational = rref
+        .rows
+        .iter()
+        .zip(&rref.pivots)
+        .map(|(row, &p)| cost_of(p) * row[n])
+        .sum();
+    let reduced: Vec<Rational> = rref
+        .free
+        .iter()
+        .map(|&f| {
+            let via_pivots: Rational = rref
+                .rows
+                .iter()
+                .zip(&rref.pivots)
+                .map(|(row, &p)| cost_of(p) * row[f])
+                .sum();
+            cost_of(f) - via_pivots
+        })
+        .collect();
+
+    let mut search = Search {
+        rref: &rref,
+        n,
+        upper: &upper,
+        reduced: &reduced,
+        free_values: vec![0; rref.free.len()],
+        best: None,
+    };
+    search.branch(0, base_cost);
+
+    search.best.map(|(cost, x)| (cost.to_integer() as i64, x))
+}
+
+/// Branch-and-bound over the free variables, for [`min_nonneg_integer_solution()`]
+struct Search<'a> {
+    rref: &'a Rref,
+    n: usize,
+    upper: &'a [i64],
+    reduced: &'a [Rational],
+    free_values: Vec<i64>,
+    best: Option<(Rational, Vec<i64>)>,
+}
+
+impl Search<'_> {
+    /// Value of a pivot row, counting only the free variables before `k`
+    fn pivot_value(&self, row: &[Rational], k: usize) -> Rational {
+        let mut value = row[self.n];
+        for (i, &f) in self.rref.free[..k].iter().enumerate() {
+            value -= row[f] * Rational::from(self.free_values[i] as i128);
+        }
+        value
+    }
+
+    /// Which values of the `k`th free variable could still keep every pivot in bounds, if any
+    fn free_range(&self, k: usize) -> Option<(i64, i64)> {
+        let f = self.rref.free[k];
+        let (mut lo, mut hi) = (0, self.upper[f]);
+
+        for (row, &p) in self.rref.rows.iter().zip(&self.rref.pivots) {
+            // The pivot is `value - coef * x[f]`, give or take whatever the free variables after this one do
+            let value = self.pivot_value(row, k);
+            let (mut least, mut most) = (Rational::zero(), Rational::zero());
+            for &g in &self.rref.free[k + 1..] {
+                let swing = -row[g] * Rational::from(self.upper[g] as i128);
+                if swing.is_negative() {
+                    least += swing;
+                } else {
+                    most += swing;
+                }
+            }
+
+            // It needs to end up somewhere in `0..=upper`, so `coef * x[f]` needs to be in `min..=max`
+            let min = value + least - Rational::from(self.upper[p] as i128);
+            let max = value + most;
+            let coef = row[f];
+            let (from, to) = if coef.is_zero() {
+                if min.is_positive() || max.is_negative() {
+                    return None;
+                }
+                continue;
+            } else if coef.is_positive() {
+                (min / coef, max / coef)
+            } else {
+                (max / coef, min / coef)
+            };
+            lo = lo.max(from.ceil().to_integer() as i64);
+            hi = hi.min(to.floor().to_integer() as i64);
+        }
+
+        (lo <= hi).then_some((lo, hi))
+    }
+
+    fn branch(&mut self, k: usize, cost: Rational) {
+        // Even the cheapest choices for everything left over can't beat the best so far
+        let lowest: Rational = self.reduced[k..]
+            .iter()
+            .zip(&self.rref.free[k..])
+            .map(|(&rc, &f)| rc.min(Rational::zero()) * Rational::from(self.upper[f] as i128))
+            .sum();
+        if let Some((best, _)) = &self.best {
+            if cost + lowest >= *best {
+                return;
+            }
+        }
+
+        if k < self.rref.free.len() {
+            let Some((lo, hi)) = self.free_range(k) else {
+                return;
+            };
+            for value in lo..=hi {
+                self.free_values[k] = value;
+                let cost = cost + self.reduced[k] * Rational::from(value as i128);
+                self.branch(k + 1, cost);
+            }
+            return;
+        }
+
+        // Everything's picked, so the pivots are too, and they need to be whole numbers in bounds
+        let mut x = vec![0; self.n];
+        for (i, &f) in self.rref.free.iter().enumerate() {
+            x[f] = self.free_values[i];
+        }
+        for (row, &p) in self.rref.rows.iter().zip(&self.rref.pivots) {
+            let value = self.pivot_value(row, k);
+            if !value.is_integer()
+                || value.is_negative()
+                || value.to_integer() > self.upper[p] as i128
+            {
+                return;
+            }
+            x[p] = value.to_integer() as i64;
+        }
+        self.best = Some((cost, x));
+    }
+}
+
+/// `x` where `a * x = 1 (mod m)`, if there is one
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let egcd = a.rem_euclid(m).extended_gcd(&m);
+    (egcd.gcd == 1).then(|| egcd.x.rem_euclid(m))
+}
+
+/// The smallest `x >= 0` with `x = r (mod m)` for every `(r, m)`, along with the LCM of every `m`
+///
+/// The moduli don't need to be coprime, but if they disagree with each other there's no answer.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut lcm) = (0_i128, 1_i128);
+    for (r, m) in congruences {
+        let (r, m) = (r as i128, m as i128);
+
+        // Solve x + lcm * k = r (mod m) for k, which only works if they agree mod their GCD
+        let egcd = lcm.extended_gcd(&m);
+        let diff = r - x;
+        if diff % egcd.gcd != 0 {
+            return None;
+        }
+        let step = m / egcd.gcd;
+        let k = (diff / egcd.gcd * egcd.x).rem_euclid(step);
+
+        x += lcm * k;
+        lcm *= step;
+        x = x.rem_euclid(lcm);
+    }
+    Some((x as i64, lcm as i64))
+}
+
+/// The LCM of everything in `ns`, which is 1 if there's nothing
+pub fn lcm_all(ns: impl IntoIterator<Item = i64>) -> i64 {
+    ns.into_iter().fold(1, |l, n| l.lcm(&n))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    fn q(n: i128, d: i128) -> Rational {
+        Rational::new(n, d)
+    }
+
+    #[test]
+    fn check_solve_rational_unique() {
+        // 2024 day 13's first claw machine, which is a 2x2 that Cramer's rule would do
+        let a = vec![vec![94, 22], vec![34, 67]];
+        let solution = solve_rational(&a, &[8400, 5400]).unwrap();
+        assert_eq!(solution.particular, [q(80, 1), q(40, 1)]);
+        assert!(solution.null_space.is_empty());
+
+        // And the second, which only works with fractions of a press
+        let a = vec![vec![26, 67], vec![66, 21]];
+        let solution = solve_rational(&a, &[12748, 12176]).unwrap();
+        assert!(solution.particular.iter().any(|x| !x.is_integer()));
+    }
+
+    #[test]
+    fn check_solve_rational_underdetermined() {
+        let a = vec![vec![1, 1, 0], vec![0, 1, 1]];
+        let solution = solve_rational(&a, &[3, 5]).unwrap();
+        assert_eq!(solution.particular, [q(-2, 1), q(5, 1), q(0, 1)]);
+        assert_eq!(solution.null_space, [vec![q(1, 1), q(-1, 1), q(1, 1)]]);
+
+        assert_eq!(solve_rational(&[vec![1, 1], vec![2, 2]], &[1, 3]), None);
+    }
+
+    #[test]
+    fn check_integer_null_space() {
+        let a = vec![vec![2, 3, 0], vec![0, 1, 4]];
+        let null = integer_null_space(&a);
+        assert_eq!(null, [vec![6, -4, 1]]);
+
+        assert!(integer_null_space(&[vec![1, 0], vec![0, 1]]).is_empty());
+    }
+
+    #[test]
+    fn check_solve_gf2() {
+        // 2025 day 10's first machine: [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1)
+        let buttons = [0b1000, 0b1010, 0b0100, 0b1100, 0b0101, 0b0011];
+        let goal = 0b0110;
+
+        let solution = solve_gf2(&buttons, goal).unwrap();
+        assert_eq!(solution.null_space.len(), 6 - 4);
+
+        let lit = |x: u64| {
+            (0..6)
+                .filter(|&j| x & (1 << j) != 0)
+                .fold(0, |lit, j| lit ^ buttons[j])
+        };
+        assert_eq!(lit(solution.particular), goal);
+        for &v in &solution.null_space {
+            assert_eq!(lit(v), 0);
+        }
+        assert_eq!(solution.min_weight().count_ones(), 2);
+
+        assert_eq!(solve_gf2(&[0b01, 0b01], 0b10), None);
+    }
+
+    #[rstest]
+    #[case::given_1(10, "(3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}")]
+    #[case::given_2(12, "(0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}")]
+    #[case::given_3(11, "(0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}")]
+    #[case::broke(
+        74,
+        "(0,1,3) (3,4) (0,1,2,3) (0,1,2,4) (1) (1,2,4) (1,4) {29,62,39,28,57}"
+    )]
+    fn check_min_nonneg_integer_solution(#[case] expected: i64, #[case] machine: &str) {
+        let (buttons, goal) = machine.split_once(" {").unwrap();
+        let goal: Vec<i64> = goal
+            .trim_end_matches('}')
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        let mut a = vec![vec![0; buttons.split(' ').count()]; goal.len()];
+        for (j, button) in buttons.split(' ').enumerate() {
+            for i in button.trim_matches(['(', ')']).split(',') {
+                a[i.parse::<usize>().unwrap()][j] = 1;
+            }
+        }
+
+        let (presses, x) = min_nonneg_integer_solution(&a, &goal, &vec![1; a[0].len()]).unwrap();
+        assert_eq!(presses, expected);
+        assert_eq!(x.iter().sum::<i64>(), expected);
+        for (row, &b) in a.iter().zip(&goal) {
+            assert_eq!(row.iter().zip(&x).map(|(a, x)| a * x).sum::<i64>(), b);
+        }
+    }
+
+    #[test]
+    fn check_min_nonneg_integer_solution_none() {
+        // x + y = 1 and x + y = 2 can't both be true
+        assert_eq!(
+            min_nonneg_integer_solution(&[vec![1, 1], vec![1, 1]], &[1, 2], &[1, 1]),
+            None
+        );
+        // 2x = 3 only has a fraction
+        assert_eq!(min_nonneg_integer_solution(&[vec![2]], &[3], &[1]), None);
+    }
+
+    #[test]
+    fn check_number_theory() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+
+        assert_eq!(lcm_all([4, 6, 10]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+}
@@ -0,0 +1,165 @@
+//! A cache for recursive functions, so they don't have to thread a `HashMap` through by hand
+//!
+//! ```
+//! use aoc25::util::Memo;
+//!
+//! // How many ways there are to climb `n` stairs, one or two at a time
+//! let ways = |memo: &mut Memo<u64, u64>, n: u64| {
+//!     if n < 2 {
+//!         return 1;
+//!     }
+//!     memo.call(n - 1) + memo.call(n - 2)
+//! };
+//! let mut memo = Memo::new(&ways);
+//! assert_eq!(memo.call(80), 37_889_062_373_143_906);
+//! assert_eq!(memo.stats().misses, 81);
+//! ```
+use core::fmt;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A recursive function and everything it's worked out so far
+///
+/// The cache lives as long as the `Memo` does, so making one per top-level call keeps calls (and tests) from seeing
+/// each other's answers. Use [`Memo::clear()`] to reuse one.
+pub struct Memo<'f, K, V> {
+    f: &'f dyn Fn(&mut Memo<'f, K, V>, K) -> V,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+/// How well a [`Memo`] has been doing
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    /// How many answers are cached, which is the same as `misses` unless it's been cleared
+    pub len: usize,
+}
+
+impl MemoStats {
+    /// What fraction of calls were already cached, from 0 to 1
+    pub fn hit_rate(&self) -> f64 {
+        let calls = self.hits + self.misses;
+        if calls == 0 {
+            0.0
+        } else {
+            self.hits as f64 / calls as f64
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit), {} cached",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.len
+        )
+    }
+}
+
+impl<'f, K, V> Memo<'f, K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    /// `f` gets this `Memo` back, and should recurse with [`Memo::call()`] instead of calling itself
+    pub fn new(f: &'f dyn Fn(&mut Self, K) -> V) -> Self {
+        Self {
+            f,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Calls the function, or returns what it gave last time for this `key`
+    pub fn call(&mut self, key: K) -> V {
+        if let Some(v) = self.cache.get(&key) {
+            self.hits += 1;
+            return v.clone();
+        }
+        self.misses += 1;
+
+        // Copy the reference out, so that `f` can borrow all of `self`
+        let f = self.f;
+        let v = f(self, key.clone());
+        self.cache.insert(key, v.clone());
+        v
+    }
+
+    /// Forgets every cached answer and resets the stats
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            len: self.cache.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn check_fibonacci() {
+        let mut memo = Memo::new(&|memo, n: u64| -> u64 {
+            if n < 2 {
+                n
+            } else {
+                memo.call(n - 1) + memo.call(n - 2)
+            }
+        });
+
+        assert_eq!(memo.call(90), 2_880_067_194_370_816_120);
+
+        // Each of 0..=90 is worked out once, and everything from 3 up already knows its second call
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 91);
+        assert_eq!(stats.len, 91);
+        assert_eq!(stats.hits, 88);
+
+        memo.call(90);
+        assert_eq!(memo.stats().hits, 89);
+
+        memo.clear();
+        assert_eq!(memo.stats(), MemoStats::default());
+    }
+
+    #[test]
+    fn check_tuple_keys_and_captures() {
+        // Ways to climb `n` stairs taking any of `steps` at a time, with how many steps are left
+        let steps = [1, 2, 3];
+        let f = |memo: &mut Memo<(u32, u32), u64>, (n, left): (u32, u32)| -> u64 {
+            match (n, left) {
+                (0, _) => 1,
+                (_, 0) => 0,
+                _ => steps
+                    .iter()
+                    .filter(|&&s| s <= n)
+                    .map(|&s| memo.call((n - s, left - 1)))
+                    .sum(),
+            }
+        };
+        let mut memo = Memo::new(&f);
+
+        assert_eq!(memo.call((4, 4)), 7);
+        assert_eq!(memo.call((4, 2)), 3);
+        assert_eq!(memo.call((30, 30)), 53_798_080);
+        assert!(memo.stats().hit_rate() > 0.5, "{}", memo.stats());
+    }
+}
@@ -3,18 +3,40 @@ use crate::prelude::*;
 use std::cmp::{Ord, Reverse};
 use std::str::FromStr;
 
+pub mod bitmask;
+
 pub mod cardinal;
 pub use cardinal::*;
 
+pub mod combinators;
+
 pub mod fixed_bitset;
 pub use fixed_bitset::*;
 
 pub mod framebuffer;
 pub use framebuffer::*;
 
+pub mod geometry;
+
+pub mod grid_view;
+pub use grid_view::*;
+
+pub mod interval_set;
+pub use interval_set::*;
+
+pub mod linalg;
+
+pub mod memo;
+pub use memo::*;
+
 pub mod parse;
 pub use parse::*;
 
+pub mod spatial;
+
+pub mod u256;
+pub use u256::*;
+
 pub mod vec_n_ext;
 pub use vec_n_ext::*;
 
@@ -76,7 +98,7 @@ where
 #[track_caller]
 pub fn parse_or_fail<T: FromStr>(s: impl AsRef<str>) -> T {
     let s: &str = s.as_ref();
-    match s.parse() {
+    match try_parse(s) {
         Ok(t) => t,
         Err(_err) => panic!(
             "Failed to parse \"{s}\" as a {}",
@@ -85,46 +107,32 @@ pub fn parse_or_fail<T: FromStr>(s: impl AsRef<str>) -> T {
     }
 }
 
-#[track_caller]
-pub fn merge_ranges(mut rs: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
-    debug_assert!(rs.is_sorted(), "Ranges must be sorted to be merged");
-
-    let mut i = 0;
-    while i + 1 < rs.len() {
-        if rs[i].1 < rs[i + 1].0 {
-            // no overlap, cannot merge, continue
-            i += 1;
-        } else {
-            // we have some overlap, let's try and merge
-            rs[i] = (rs[i].0, i64::max(rs[i].1, rs[i + 1].1));
-            rs.remove(i + 1);
+/// Like [`parse_or_fail()`], but for input that might not parse
+pub fn try_parse<T: FromStr>(s: impl AsRef<str>) -> Result<T, combinators::ParseError> {
+    let s: &str = s.as_ref();
+    s.parse().map_err(|_| {
+        let (line, col) = combinators::Input::new(s).line_col();
+        combinators::ParseError {
+            line,
+            col,
+            expected: combinators::short_type_name::<T>().to_string(),
+            found: format!("{s:?}"),
         }
-    }
-
-    rs
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use itertools::Itertools;
     #[allow(unused_imports)]
     use pretty_assertions::{assert_eq, assert_ne};
-    use rstest::*;
-
-    #[rstest]
-    #[case::simple([(3, 5), (10, 20)], [(3, 5), (10, 14), (16, 20), (12, 18) ])]
-    #[case::adjacent([(10, 30)], [(10, 20), (20, 30)])]
-    #[case::superset([(1, 100)], [(1, 100), (10, 20), (30, 40)])]
-    #[timeout(Duration::from_millis(1))]
-    fn check_merge_ranges(
-        #[case] expected: impl IntoIterator<Item = (i64, i64)>,
-        #[case] ranges: impl IntoIterator<Item = (i64, i64)>,
-    ) {
-        let expected = expected.into_iter().collect_vec();
-        let mut ranges = ranges.into_iter().collect_vec();
-        ranges.sort();
-
-        assert_eq!(expected, merge_ranges(ranges));
+
+    #[test]
+    fn check_try_parse() {
+        assert_eq!(try_parse::<u32>("1234"), Ok(1234));
+        assert_eq!(
+            try_parse::<u32>("-1").unwrap_err().to_string(),
+            "line 1 col 1: expected u32, found \"-1\""
+        );
     }
 }
@@ -1,168 +1,395 @@
 use crate::prelude::*;
 
-use std::mem::MaybeUninit;
+use std::marker::PhantomData;
+use std::ops::Range;
 use std::str::FromStr;
 
-pub struct Parsedi64s<'a> {
+use super::combinators::{Input, ParseError, short_type_name};
+
+/// What a `'-'` right before some digits means
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Dash {
+    /// Always a sign, so `"3-7"` is `3, -7`. This is what [`IntParsable::i64s()`] does.
+    #[default]
+    Sign,
+    /// A sign unless it's glued to something alphanumeric, so `"3-7"` is `3, 7` but `"x=-7"` is `-7`.
+    Auto,
+    /// Never a sign, so `"-7"` is `7`
+    Separator,
+}
+
+/// Numbers that [`NumScanner`] knows how to find
+pub trait Scannable: FromStr {
+    /// Whether a leading `'-'` can be part of the number
+    const SIGNED: bool;
+    /// Whether `"1.5"` is one number instead of two
+    const FLOAT: bool = false;
+}
+
+macro_rules! impl_scannable {
+    ($signed:literal, $float:literal: $($t:ty),*) => {
+        $(
+            impl Scannable for $t {
+                const SIGNED: bool = $signed;
+                const FLOAT: bool = $float;
+            }
+        )*
+    };
+}
+
+impl_scannable!(false, false: u8, u16, u32, u64, u128, usize);
+impl_scannable!(true, false: i8, i16, i32, i64, i128, isize);
+impl_scannable!(true, true: f32, f64);
+
+/// A number and the bytes it came from, including any sign
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token<T> {
+    pub value: T,
+    pub span: Range<usize>,
+}
+
+/// Finds every number in some text, ignoring whatever is between them
+///
+/// Build one with [`IntParsable::nums()`] (or [`IntParsable::i64s()`]), and iterate from either end.
+/// Use [`NumScanner::spanned()`] if you need to know where each number was.
+#[derive(Clone, Debug)]
+pub struct NumScanner<'a, T> {
     bytes: &'a [u8],
+    // Everything we haven't yielded yet is in bytes[front..back]
+    front: usize,
+    back: usize,
+    dash: Dash,
+    _t: PhantomData<T>,
 }
 
-// TODO: Reverse would be nice
-impl Iterator for Parsedi64s<'_> {
-    type Item = i64;
+pub type Parsedi64s<'a> = NumScanner<'a, i64>;
+
+impl<'a, T: Scannable> NumScanner<'a, T> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            front: 0,
+            back: bytes.len(),
+            dash: Dash::default(),
+            _t: PhantomData,
+        }
+    }
+
+    pub fn dash(self, dash: Dash) -> Self {
+        Self { dash, ..self }
+    }
+
+    /// Yield [`Token`]s instead of bare numbers
+    pub fn spanned(self) -> SpannedNums<'a, T> {
+        SpannedNums(self)
+    }
+
+    /// Where the number that ends with the digit run `[start, end)` begins, including its sign
+    fn token_start(&self, start: usize) -> usize {
+        if !T::SIGNED || start == 0 || self.bytes[start - 1] != b'-' {
+            return start;
+        }
+
+        let is_sign = match self.dash {
+            Dash::Sign => true,
+            Dash::Auto => start < 2 || !self.bytes[start - 2].is_ascii_alphanumeric(),
+            Dash::Separator => false,
+        };
+        if is_sign { start - 1 } else { start }
+    }
+
+    fn token(&self, span: Range<usize>) -> Token<T> {
+        // Spans only ever cover ASCII, so this can't split a char
+        let text = std::str::from_utf8(&self.bytes[span.clone()]).unwrap();
+        let value = text.parse().unwrap_or_else(|_| {
+            panic!(
+                "Parsing {text:?} at {span:?} to {} failed",
+                short_type_name::<T>()
+            )
+        });
+
+        Token { value, span }
+    }
+
+    fn next_token(&mut self) -> Option<Token<T>> {
+        let start = next_digit(self.bytes, self.front, self.back)?;
+        let mut end = digit_run_end(self.bytes, start);
+
+        // We're always at the start of a number going forward, so a fraction belongs to us
+        if T::FLOAT && self.bytes.get(end) == Some(&b'.') && is_digit_at(self.bytes, end + 1) {
+            end = digit_run_end(self.bytes, end + 1);
+        }
+        if end > self.back {
+            // This was already yielded from the back
+            self.front = self.back;
+            return None;
+        }
+
+        let span = self.token_start(start)..end;
+        self.front = end;
+        Some(self.token(span))
+    }
+
+    fn next_back_token(&mut self) -> Option<Token<T>> {
+        let end = prev_digit(self.bytes, self.front, self.back)? + 1;
+        let mut start = digit_run_start(self.bytes, end);
+
+        if T::FLOAT {
+            // "1.2.3.4" is "1.2" and "3.4" going forward, so count how many runs are chained
+            // together with '.' to know if we're a fraction.
+            let mut runs = 1;
+            let mut int_start = start;
+            let mut cur = start;
+            while cur >= 2 && self.bytes[cur - 1] == b'.' && self.bytes[cur - 2].is_ascii_digit() {
+                cur = digit_run_start(self.bytes, cur - 1);
+                if runs == 1 {
+                    int_start = cur;
+                }
+                runs += 1;
+            }
+            if runs % 2 == 0 {
+                start = int_start;
+            }
+        }
+        if start < self.front {
+            // This was already yielded from the front
+            self.back = self.front;
+            return None;
+        }
+
+        let span = self.token_start(start)..end;
+        self.back = span.start;
+        Some(self.token(span))
+    }
+}
+
+impl<T: Scannable> Iterator for NumScanner<'_, T> {
+    type Item = T;
+
     fn next(&mut self) -> Option<Self::Item> {
-        // Skip non-digits
-        let mut i = 0;
-        while i < self.bytes.len() && !(self.bytes[i].is_ascii_digit() || self.bytes[i] == b'-') {
-            i += 1;
-        }
-        self.bytes = &self.bytes[i..];
-
-        // Check for a leading '-' because we handle negatives
-        let mut negative = false;
-        let mut i = 0;
-        if i < self.bytes.len() && self.bytes[i] == b'-' {
-            negative = true;
-            i += 1;
-        }
-
-        // Grab digits
-        let mut n: i64 = 0;
-        while i < self.bytes.len() && self.bytes[i].is_ascii_digit() {
-            n *= 10;
-            n += (self.bytes[i] - b'0') as i64;
-
-            i += 1;
-        }
-        if negative {
-            n = -n;
-        }
-
-        // Sanity check on debug builds
-        if cfg!(debug_assertions) && i > 0 {
-            let digits_text: &str =
-                std::str::from_utf8(&self.bytes[..i]).expect("Failed to parse bytes as UTF8");
-
-            let parsed_n: i64 = digits_text
-                    .parse::<i64>()
-                    .unwrap_or_else(|e| {
-                panic!("Parsing {digits_text:?} to an i64 failed: {e:?}. Release builds will parse as n={n}")
-            });
-            debug_assert_eq!(
-                n, parsed_n,
-                "We parsed {digits_text:?} as {n}, but \"should\" have parsed {parsed_n}"
-            );
+        self.next_token().map(|t| t.value)
+    }
+}
+
+impl<T: Scannable> DoubleEndedIterator for NumScanner<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_token().map(|t| t.value)
+    }
+}
+
+/// See [`NumScanner::spanned()`]
+#[derive(Clone, Debug)]
+pub struct SpannedNums<'a, T>(NumScanner<'a, T>);
+
+impl<T: Scannable> Iterator for SpannedNums<'_, T> {
+    type Item = Token<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next_token()
+    }
+}
+
+impl<T: Scannable> DoubleEndedIterator for SpannedNums<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back_token()
+    }
+}
+
+const ONES: u64 = u64::from_ne_bytes([0x01; 8]);
+const HIGHS: u64 = ONES * 0x80;
+
+/// Sets the high bit of every byte in `chunk` that's an ASCII digit, and clears everything else
+///
+/// Bytes are kept to 7 bits before adding so nothing carries into its neighbor.
+#[inline]
+fn digit_mask(chunk: u64) -> u64 {
+    let low7 = chunk & !HIGHS;
+    let at_least_0 = low7 + ONES * (0x80 - b'0' as u64);
+    let past_9 = low7 + ONES * (0x80 - b'9' as u64 - 1);
+
+    at_least_0 & !past_9 & !chunk & HIGHS
+}
+
+#[inline]
+fn load(bytes: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap())
+}
+
+fn is_digit_at(bytes: &[u8], i: usize) -> bool {
+    bytes.get(i).is_some_and(u8::is_ascii_digit)
+}
+
+/// First digit in `bytes[from..to]`
+fn next_digit(bytes: &[u8], mut from: usize, to: usize) -> Option<usize> {
+    while from + 8 <= to {
+        let mask = digit_mask(load(bytes, from));
+        if mask != 0 {
+            return Some(from + mask.trailing_zeros() as usize / 8);
         }
-        self.bytes = &self.bytes[i..];
+        from += 8;
+    }
+    (from..to).find(|&i| bytes[i].is_ascii_digit())
+}
 
-        // If we didn't grab any digits, we're done
-        if i > 0 { Some(n) } else { None }
+/// Last digit in `bytes[from..to]`
+fn prev_digit(bytes: &[u8], from: usize, mut to: usize) -> Option<usize> {
+    while to >= from + 8 {
+        let mask = digit_mask(load(bytes, to - 8));
+        if mask != 0 {
+            return Some(to - 1 - mask.leading_zeros() as usize / 8);
+        }
+        to -= 8;
     }
+    (from..to).rev().find(|&i| bytes[i].is_ascii_digit())
+}
+
+/// One past the end of the digits starting at `from`
+fn digit_run_end(bytes: &[u8], mut from: usize) -> usize {
+    while from + 8 <= bytes.len() {
+        let non_digits = !digit_mask(load(bytes, from)) & HIGHS;
+        if non_digits != 0 {
+            return from + non_digits.trailing_zeros() as usize / 8;
+        }
+        from += 8;
+    }
+    from + bytes[from..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count()
+}
+
+/// Start of the digits that end right before `to`
+fn digit_run_start(bytes: &[u8], mut to: usize) -> usize {
+    while to >= 8 {
+        let non_digits = !digit_mask(load(bytes, to - 8)) & HIGHS;
+        if non_digits != 0 {
+            return to - non_digits.leading_zeros() as usize / 8;
+        }
+        to -= 8;
+    }
+    to - bytes[..to]
+        .iter()
+        .rev()
+        .take_while(|b| b.is_ascii_digit())
+        .count()
 }
 
 pub trait IntParsable {
-    fn i64s(&self) -> Parsedi64s<'_>;
+    fn as_scannable_bytes(&self) -> &[u8];
+
+    /// Every integer, treating every `'-'` before one as a sign
+    fn i64s(&self) -> Parsedi64s<'_> {
+        NumScanner::new(self.as_scannable_bytes())
+    }
+
+    fn nums<T: Scannable>(&self) -> NumScanner<'_, T> {
+        NumScanner::new(self.as_scannable_bytes())
+    }
 }
 
 impl IntParsable for &'_ str {
-    fn i64s(&self) -> Parsedi64s<'_> {
-        Parsedi64s {
-            bytes: self.as_bytes(),
-        }
+    fn as_scannable_bytes(&self) -> &[u8] {
+        self.as_bytes()
     }
 }
 
 impl IntParsable for &'_ [u8] {
-    fn i64s(&self) -> Parsedi64s<'_> {
-        Parsedi64s { bytes: self }
+    fn as_scannable_bytes(&self) -> &[u8] {
+        self
     }
 }
 
 impl IntParsable for str {
-    fn i64s(&self) -> Parsedi64s<'_> {
-        Parsedi64s {
-            bytes: self.as_bytes(),
-        }
+    fn as_scannable_bytes(&self) -> &[u8] {
+        self.as_bytes()
     }
 }
 
 impl IntParsable for [u8] {
-    fn i64s(&self) -> Parsedi64s<'_> {
-        Parsedi64s { bytes: self }
+    fn as_scannable_bytes(&self) -> &[u8] {
+        self
     }
 }
 
 // TODO: Use Pattern when it's stable, https://doc.rust-lang.org/std/str/pattern/index.html?
+#[track_caller]
 pub fn parse_list<const N: usize, T>(s: &str, pattern: &str) -> [T; N]
 where
-    T: FromStr + Copy + Sized,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    let ty_name = std::any::type_name::<T>();
-    let mut list: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
-    let mut iter = s.split(pattern).enumerate();
-    for (i, t_s) in (&mut iter).take(N) {
-        list[i] = match t_s.parse() {
-            Ok(t) => MaybeUninit::new(t),
-            Err(e) => {
-                error!(
-                    "While splitting \"{s}\" by \"{pattern}\", failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}"
-                );
-                unreachable!(
-                    "While splitting \"{s}\" by \"{pattern}\", failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}"
-                );
-            }
-        };
-    }
+    try_parse_list(s, pattern).unwrap_or_else(|e| {
+        error!("While splitting {s:?} by {pattern:?}: {e}");
+        panic!("While splitting {s:?} by {pattern:?}: {e}");
+    })
+}
 
-    let rem = iter.count();
-    if rem != 0 {
-        error!(
-            str=s,
-            pattern=pattern;
-                "Trying to parse exactly {N} values of {ty_name}, but found {rem} more!",
-        );
-        unreachable!("Trying to parse exactly {N} values of {ty_name}, but found {rem} more!");
-    }
+#[track_caller]
+pub fn parse_list_whitespace<const N: usize, T>(s: &str) -> [T; N]
+where
+    T: FromStr,
+{
+    try_parse_list_whitespace(s).unwrap_or_else(|e| {
+        error!("While splitting {s:?} by whitespace: {e}");
+        panic!("While splitting {s:?} by whitespace: {e}");
+    })
+}
 
-    unsafe { std::mem::transmute_copy::<_, [T; N]>(&list) }
+pub fn try_parse_list<const N: usize, T>(s: &str, pattern: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+{
+    parse_pieces(s, s.split(pattern))
 }
 
-pub fn parse_list_whitespace<const N: usize, T>(s: &str) -> [T; N]
+pub fn try_parse_list_whitespace<const N: usize, T>(s: &str) -> Result<[T; N], ParseError>
 where
-    T: FromStr + Copy + Sized,
-    <T as std::str::FromStr>::Err: std::fmt::Debug,
+    T: FromStr,
 {
-    let ty_name = std::any::type_name::<T>();
-    let mut list: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
-    let mut iter = s.split_whitespace().enumerate();
-    for (i, t_s) in (&mut iter).take(N) {
-        list[i] = match t_s.parse() {
-            Ok(t) => MaybeUninit::new(t),
-            Err(e) => {
-                error!(
-                    "While splitting \"{s}\" by whitespace, failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}"
-                );
-                unreachable!(
-                    "While splitting \"{s}\" by whitespace, failed to parse {i}th elem \"{t_s}\" as {ty_name}: {e:?}"
-                );
-            }
-        };
-    }
+    parse_pieces(s, s.split_whitespace())
+}
 
-    let rem = iter.count();
-    if rem != 0 {
-        error!(
-            str=s,
-            pattern="whitespace";
-                "Trying to parse exactly {N} values of {ty_name}, but found {rem} more!",
-        );
-        unreachable!("Trying to parse exactly {N} values of {ty_name}, but found {rem} more!");
+/// Parse exactly `N` pieces of `s`, reporting errors at the offending piece
+fn parse_pieces<'a, const N: usize, T>(
+    s: &'a str,
+    pieces: impl Iterator<Item = &'a str>,
+) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+{
+    let ty_name = short_type_name::<T>();
+    let error_at = |piece: &str, expected: String| {
+        let (line, col) = Input::new(s)
+            .advance(piece.as_ptr() as usize - s.as_ptr() as usize)
+            .line_col();
+        ParseError {
+            line,
+            col,
+            expected,
+            found: format!("{piece:?}"),
+        }
+    };
+
+    let mut list = Vec::with_capacity(N);
+    for (i, piece) in pieces.enumerate() {
+        if i == N {
+            return Err(error_at(piece, format!("exactly {N} values of {ty_name}")));
+        }
+        match piece.parse() {
+            Ok(t) => list.push(t),
+            Err(_) => return Err(error_at(piece, ty_name.to_string())),
+        }
     }
 
-    unsafe { std::mem::transmute_copy::<_, [T; N]>(&list) }
+    let found = list.len();
+    list.try_into().map_err(|_| {
+        let (line, col) = Input::new(s).advance(s.len()).line_col();
+        ParseError {
+            line,
+            col,
+            expected: format!("exactly {N} values of {ty_name}"),
+            found: format!("{found}"),
+        }
+    })
 }
 
 #[cfg(test)]
@@ -172,6 +399,18 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
     use rstest::*;
 
+    #[rstest]
+    #[case::bad_elem("10,x,1", "line 1 col 4: expected i32, found \"x\"")]
+    #[case::too_many(
+        "10,2,1,4",
+        "line 1 col 8: expected exactly 3 values of i32, found \"4\""
+    )]
+    #[case::too_few("10,2", "line 1 col 5: expected exactly 3 values of i32, found 2")]
+    fn check_try_parse_list_errors(#[case] s: &str, #[case] expected: &str) {
+        let err = try_parse_list::<3, i32>(s, ",").unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
     #[test]
     fn check_parse_list() {
         {
@@ -203,5 +442,115 @@ mod tests {
         println!("expected: {expected:?}");
 
         assert_eq!(parsed, expected);
+
+        let mut reversed: Vec<i64> = blah.i64s().rev().collect_vec();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[rstest]
+    #[case::sign("3-7 x=-2", Dash::Sign, [3, -7, -2])]
+    #[case::auto("3-7 x=-2", Dash::Auto, [3, 7, -2])]
+    #[case::auto_date("2024-01-05", Dash::Auto, [2024, 1, 5])]
+    #[case::auto_start("-4,-5", Dash::Auto, [-4, -5])]
+    #[case::separator("3-7 x=-2", Dash::Separator, [3, 7, 2])]
+    #[case::lone_dash("a - b 1", Dash::Sign, [1])]
+    #[trace]
+    fn check_dash(
+        #[case] s: &str,
+        #[case] dash: Dash,
+        #[case] expected: impl IntoIterator<Item = i64>,
+    ) {
+        let expected = expected.into_iter().collect_vec();
+        assert_eq!(s.nums::<i64>().dash(dash).collect_vec(), expected);
+
+        let mut reversed = s.nums::<i64>().dash(dash).rev().collect_vec();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn check_nums_types() {
+        assert_eq!("a-3 b4".nums::<u64>().collect_vec(), [3, 4]);
+        assert_eq!(
+            "170141183460469231731687303715884105727 -1"
+                .nums::<i128>()
+                .collect_vec(),
+            [i128::MAX, -1]
+        );
+        assert_eq!(
+            "x=1.5 y=-0.25 z=3".nums::<f64>().collect_vec(),
+            [1.5, -0.25, 3.0]
+        );
+    }
+
+    #[rstest]
+    #[case::pairs("1.2.3.4", [1.2, 3.4])]
+    #[case::odd("1.2.3", [1.2, 3.0])]
+    #[case::leading_dot(".5 6.", [5.0, 6.0])]
+    #[trace]
+    fn check_floats_both_ways(#[case] s: &str, #[case] expected: impl IntoIterator<Item = f64>) {
+        let expected = expected.into_iter().collect_vec();
+        assert_eq!(s.nums::<f64>().collect_vec(), expected);
+
+        let mut reversed = s.nums::<f64>().rev().collect_vec();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[test]
+    fn check_spans() {
+        let s = "move -12 from 345678901234 to 6";
+        let tokens = s.nums::<i64>().spanned().collect_vec();
+        let texts = tokens.iter().map(|t| &s[t.span.clone()]).collect_vec();
+        assert_eq!(texts, ["-12", "345678901234", "6"]);
+        assert_eq!(tokens[1].value, 345678901234);
+    }
+
+    #[test]
+    fn check_meet_in_the_middle() {
+        let s = "1, 22, 333, 4444, 55555, 666666, 7777777, 88888888, 999999999";
+        let all = s.nums::<u64>().collect_vec();
+        for n in 0..=all.len() {
+            let mut it = s.nums::<u64>();
+            let mut both = it.by_ref().take(n).collect_vec();
+            both.extend(it.rev().collect_vec().into_iter().rev());
+            assert_eq!(both, all, "n={n}");
+        }
+    }
+
+    #[test]
+    fn check_swar_matches_scalar() {
+        let bytes: Vec<u8> = (0..=255)
+            .chain(b"0123456789x0123456789012345678".iter().copied())
+            .collect();
+        for from in 0..bytes.len() {
+            let to = bytes.len();
+            assert_eq!(
+                next_digit(&bytes, from, to),
+                (from..to).find(|&i| bytes[i].is_ascii_digit()),
+                "from={from}"
+            );
+            assert_eq!(
+                prev_digit(&bytes, 0, from),
+                (0..from).rev().find(|&i| bytes[i].is_ascii_digit()),
+                "to={from}"
+            );
+            if bytes[from].is_ascii_digit() {
+                let end = from
+                    + bytes[from..]
+                        .iter()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                assert_eq!(digit_run_end(&bytes, from), end, "from={from}");
+                let start = end
+                    - bytes[..end]
+                        .iter()
+                        .rev()
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                assert_eq!(digit_run_start(&bytes, end), start, "end={end}");
+            }
+        }
     }
 }
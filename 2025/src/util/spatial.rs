@@ -0,0 +1,625 @@
+//! Finding points near other points without checking every pair
+//!
+//! - [`KdTree`]: splits space in half over and over, which works for any spread of points
+//! - [`BucketGrid`]: drops points into evenly sized cells, which is simpler when they're spread out evenly
+//!
+//! Both hand back [`Neighbor`]s, which are indices into the points they were made with. Euclidean distances are all
+//! squared, so they stay whole numbers.
+//!
+//! ```
+//! use aoc25::util::spatial::{KdTree, Metric};
+//!
+//! let boxes = [[0, 0, 0], [10, 0, 0], [1, 1, 0], [10, 2, 0]];
+//! let tree = KdTree::new(boxes);
+//! let closest: Vec<_> = tree.pairs_by_distance(Metric::Euclidean).take(2).collect();
+//! assert_eq!(closest, [(2, 0, 2), (4, 1, 3)]);
+//! ```
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+use ultraviolet::{IVec2, IVec3};
+
+/// Anything with `N` integer coordinates
+pub trait Coords<const N: usize>: Copy {
+    fn coords(self) -> [i64; N];
+}
+
+impl Coords<2> for IVec2 {
+    fn coords(self) -> [i64; 2] {
+        [self.x as i64, self.y as i64]
+    }
+}
+
+impl Coords<3> for IVec3 {
+    fn coords(self) -> [i64; 3] {
+        [self.x as i64, self.y as i64, self.z as i64]
+    }
+}
+
+impl<const N: usize> Coords<N> for [i32; N] {
+    fn coords(self) -> [i64; N] {
+        self.map(|c| c as i64)
+    }
+}
+
+impl<const N: usize> Coords<N> for [i64; N] {
+    fn coords(self) -> [i64; N] {
+        self
+    }
+}
+
+/// How to measure the distance between two points
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// As the crow flies, but squared
+    Euclidean,
+    /// Along the grid, which is the sum of the distances along each axis
+    Manhattan,
+}
+
+impl Metric {
+    pub fn dist<const N: usize>(self, a: [i64; N], b: [i64; N]) -> i64 {
+        let gaps = (0..N).map(|axis| a[axis] - b[axis]);
+        match self {
+            Metric::Euclidean => gaps.map(|d| d * d).sum(),
+            Metric::Manhattan => gaps.map(i64::abs).sum(),
+        }
+    }
+
+    /// The smallest [`Metric::dist()`] could be, for points that are `gap` apart along just one axis
+    fn axis_bound(self, gap: i64) -> i64 {
+        match self {
+            Metric::Euclidean => gap * gap,
+            Metric::Manhattan => gap.abs(),
+        }
+    }
+
+    /// The furthest apart two points could be along one axis, and still be `max_dist` apart
+    fn axis_reach(self, max_dist: i64) -> i64 {
+        match self {
+            Metric::Euclidean => max_dist.isqrt(),
+            Metric::Manhattan => max_dist,
+        }
+    }
+}
+
+/// A point that was found near another one, which sorts closest first (and then by index, so ties always come out
+/// the same way)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Neighbor {
+    pub dist: i64,
+    /// Where it was in the points this was made with
+    pub index: usize,
+}
+
+/// Keeps the `k` closest neighbors in `best`, which is a max heap so the worst one is easy to kick out
+fn offer(best: &mut BinaryHeap<Neighbor>, k: usize, n: Neighbor) {
+    if best.len() < k {
+        best.push(n);
+    } else if best.peek().is_some_and(|worst| n < *worst) {
+        best.pop();
+        best.push(n);
+    }
+}
+
+/// Whether something `bound` away could still make it into `best`
+fn could_improve(best: &BinaryHeap<Neighbor>, k: usize, bound: i64) -> bool {
+    best.len() < k || best.peek().is_some_and(|worst| bound <= worst.dist)
+}
+
+/// A k-d tree over points with `N` coordinates, for nearest neighbor and radius queries in about `O(log n)`
+///
+/// There aren't any nodes: the tree is just an order of the points, where the middle of every range splits the rest of
+/// it in half along axis `depth % N`.
+#[derive(Clone, Debug)]
+pub struct KdTree<const N: usize> {
+    points: Vec<[i64; N]>,
+    tree: Vec<u32>,
+}
+
+impl<const N: usize> KdTree<N> {
+    pub fn new<P: Coords<N>>(points: impl IntoIterator<Item = P>) -> Self {
+        let points: Vec<[i64; N]> = points.into_iter().map(Coords::coords).collect();
+        let mut tree: Vec<u32> = (0..points.len() as u32).collect();
+        Self::build(&points, &mut tree, 0);
+
+        Self { points, tree }
+    }
+
+    fn build(points: &[[i64; N]], tree: &mut [u32], depth: usize) {
+        if tree.len() <= 1 {
+            return;
+        }
+
+        let mid = tree.len() / 2;
+        tree.select_nth_unstable_by_key(mid, |&i| points[i as usize][depth % N]);
+
+        let (left, right) = tree.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The point at `index`, in the order they were given in
+    pub fn point(&self, index: usize) -> [i64; N] {
+        self.points[index]
+    }
+
+    /// The `k` closest points to `p`, closest first
+    pub fn nearest(&self, p: impl Coords<N>, k: usize, metric: Metric) -> Vec<Neighbor> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.nearest_in(&self.tree, 0, p.coords(), k, metric, &mut best);
+        }
+        best.into_sorted_vec()
+    }
+
+    fn nearest_in(
+        &self,
+        tree: &[u32],
+        depth: usize,
+        p: [i64; N],
+        k: usize,
+        metric: Metric,
+        best: &mut BinaryHeap<Neighbor>,
+    ) {
+        let Some((near, far, gap)) = self.visit(tree, depth, p, |(index, q)| {
+            let dist = metric.dist(p, q);
+            offer(best, k, Neighbor { dist, index });
+        }) else {
+            return;
+        };
+
+        self.nearest_in(near, depth + 1, p, k, metric, best);
+        if could_improve(best, k, metric.axis_bound(gap)) {
+            self.nearest_in(far, depth + 1, p, k, metric, best);
+        }
+    }
+
+    /// Every point that's at most `max_dist` from `p`, closest first
+    pub fn within(&self, p: impl Coords<N>, max_dist: i64, metric: Metric) -> Vec<Neighbor> {
+        let mut found = vec![];
+        self.within_in(&self.tree, 0, p.coords(), max_dist, metric, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    fn within_in(
+        &self,
+        tree: &[u32],
+        depth: usize,
+        p: [i64; N],
+        max_dist: i64,
+        metric: Metric,
+        found: &mut Vec<Neighbor>,
+    ) {
+        let Some((near, far, gap)) = self.visit(tree, depth, p, |(index, q)| {
+            let dist = metric.dist(p, q);
+            if dist <= max_dist {
+                found.push(Neighbor { dist, index });
+            }
+        }) else {
+            return;
+        };
+
+        self.within_in(near, depth + 1, p, max_dist, metric, found);
+        if metric.axis_bound(gap) <= max_dist {
+            self.within_in(far, depth + 1, p, max_dist, metric, found);
+        }
+    }
+
+    /// Calls `f` with the point that splits `tree`, and returns the half that's on `p`'s side, the other half, and how
+    /// far `p` is from the split
+    fn visit<'t>(
+        &self,
+        tree: &'t [u32],
+        depth: usize,
+        p: [i64; N],
+        f: impl FnOnce((usize, [i64; N])),
+    ) -> Option<(&'t [u32], &'t [u32], i64)> {
+        if tree.is_empty() {
+            return None;
+        }
+
+        let mid = tree.len() / 2;
+        let index = tree[mid] as usize;
+        let q = self.points[index];
+        f((index, q));
+
+        // Everything on the left is at most `q`, and everything on the right is at least `q`
+        let (left, right) = (&tree[..mid], &tree[mid + 1..]);
+        let gap = p[depth % N] - q[depth % N];
+        if gap < 0 {
+            Some((left, right, gap))
+        } else {
+            Some((right, left, gap))
+        }
+    }
+
+    /// Every pair of points as `(dist, i, j)` with `i < j`, closest first, worked out as they're needed
+    ///
+    /// Each point asks the tree for its nearest neighbors a few at a time, so taking the first `m` pairs only looks at
+    /// about `m` of them instead of all `n^2`.
+    pub fn pairs_by_distance(&self, metric: Metric) -> PairsByDistance<'_, N> {
+        let mut pairs = PairsByDistance {
+            tree: self,
+            metric,
+            streams: vec![NeighborStream::default(); self.len()],
+            heads: BinaryHeap::with_capacity(self.len()),
+        };
+        for i in 0..self.len() {
+            pairs.advance(i);
+        }
+        pairs
+    }
+}
+
+/// See [`KdTree::pairs_by_distance()`]
+#[derive(Clone, Debug)]
+pub struct PairsByDistance<'t, const N: usize> {
+    tree: &'t KdTree<N>,
+    metric: Metric,
+    streams: Vec<NeighborStream>,
+    /// The next pair from every point that has any left
+    heads: BinaryHeap<Reverse<(i64, usize, usize)>>,
+}
+
+/// One point's neighbors, closest first, from asking the tree for more and more of them
+#[derive(Clone, Debug, Default)]
+struct NeighborStream {
+    nearest: Vec<Neighbor>,
+    next: usize,
+}
+
+impl<const N: usize> PairsByDistance<'_, N> {
+    /// Queues up the next pair `(i, j)` with `j > i`, if there is one. Pairs with `j < i` come out of `j`'s stream.
+    fn advance(&mut self, i: usize) {
+        let stream = &mut self.streams[i];
+        loop {
+            if let Some(&n) = stream.nearest.get(stream.next) {
+                stream.next += 1;
+                if n.index > i {
+                    self.heads.push(Reverse((n.dist, i, n.index)));
+                    return;
+                }
+            } else if stream.nearest.len() < self.tree.len() {
+                // Ask for twice as many: the ones we've already seen come back first, in the same order
+                let k = (2 * stream.nearest.len()).max(8).min(self.tree.len());
+                stream.nearest = self.tree.nearest(self.tree.point(i), k, self.metric);
+            } else {
+                return;
+            }
+        }
+    }
+}
+
+impl<const N: usize> Iterator for PairsByDistance<'_, N> {
+    type Item = (i64, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((dist, i, j)) = self.heads.pop()?;
+        self.advance(i);
+        Some((dist, i, j))
+    }
+}
+
+/// Points dropped into cubes that are `cell` wide, so a query only has to look in the cubes around it
+///
+/// This is simpler than a [`KdTree`], and about as fast when the points are spread evenly and `cell` is about as big
+/// as the queries are.
+#[derive(Clone, Debug)]
+pub struct BucketGrid<const N: usize> {
+    points: Vec<[i64; N]>,
+    cell: i64,
+    buckets: HashMap<[i64; N], Vec<u32>>,
+    /// The smallest and largest cell that has anything in it, along each axis
+    bounds: [(i64, i64); N],
+}
+
+impl<const N: usize> BucketGrid<N> {
+    pub fn new<P: Coords<N>>(points: impl IntoIterator<Item = P>, cell: i64) -> Self {
+        assert!(cell > 0, "Cells need to be at least 1 wide, not {cell}");
+
+        let points: Vec<[i64; N]> = points.into_iter().map(Coords::coords).collect();
+        let mut buckets: HashMap<[i64; N], Vec<u32>> = HashMap::new();
+        let mut bounds = [(i64::MAX, i64::MIN); N];
+        for (i, &p) in points.iter().enumerate() {
+            let c = p.map(|x| x.div_euclid(cell));
+            for axis in 0..N {
+                bounds[axis].0 = bounds[axis].0.min(c[axis]);
+                bounds[axis].1 = bounds[axis].1.max(c[axis]);
+            }
+            buckets.entry(c).or_default().push(i as u32);
+        }
+
+        Self {
+            points,
+            cell,
+            buckets,
+            bounds,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The point at `index`, in the order they were given in
+    pub fn point(&self, index: usize) -> [i64; N] {
+        self.points[index]
+    }
+
+    fn cell_of(&self, p: [i64; N]) -> [i64; N] {
+        p.map(|x| x.div_euclid(self.cell))
+    }
+
+    /// Every cell from `lo` to `hi`, including both
+    fn cells_between(lo: [i64; N], hi: [i64; N]) -> impl Iterator<Item = [i64; N]> {
+        let len: i64 = (0..N).map(|axis| hi[axis] - lo[axis] + 1).product();
+        (0..len.max(0)).map(move |mut i| {
+            std::array::from_fn(|axis| {
+                let n = hi[axis] - lo[axis] + 1;
+                let c = lo[axis] + i % n;
+                i /= n;
+                c
+            })
+        })
+    }
+
+    /// Every point in the bucket for `cell`, as `(index, point)`
+    fn bucket(&self, cell: &[i64; N]) -> impl Iterator<Item = (usize, [i64; N])> + '_ {
+        let indices = self.buckets.get(cell).map_or(&[][..], Vec::as_slice);
+        indices
+            .iter()
+            .map(|&i| (i as usize, self.points[i as usize]))
+    }
+
+    /// Every point that's at most `max_dist` from `p`, closest first
+    pub fn within(&self, p: impl Coords<N>, max_dist: i64, metric: Metric) -> Vec<Neighbor> {
+        let p = p.coords();
+        let reach = metric.axis_reach(max_dist);
+        let lo = self.cell_of(p.map(|x| x - reach));
+        let hi = self.cell_of(p.map(|x| x + reach));
+
+        let mut found = vec![];
+        for cell in Self::cells_between(lo, hi) {
+            for (index, q) in self.bucket(&cell) {
+                let dist = metric.dist(p, q);
+                if dist <= max_dist {
+                    found.push(Neighbor { dist, index });
+                }
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// Every cell that's exactly `ring` cells from `center` along some axis, and inside `bounds`
+    ///
+    /// The ring is split into faces, one for each side of each axis. A cell goes on the face for the first axis it's
+    /// `ring` away along, so no cell is on two of them.
+    fn ring_cells(&self, center: [i64; N], ring: i64) -> impl Iterator<Item = [i64; N]> + '_ {
+        let sides: &[i64] = if ring == 0 { &[0] } else { &[-1, 1] };
+        let faces = (0..N).flat_map(move |axis| sides.iter().map(move |&side| (axis, side)));
+        faces
+            .filter_map(move |(axis, side)| {
+                let mut lo = [0; N];
+                let mut hi = [0; N];
+                for b in 0..N {
+                    let (min, max) = self.bounds[b];
+                    let (from, to) = match b.cmp(&axis) {
+                        Ordering::Less => (center[b] - ring + 1, center[b] + ring - 1),
+                        Ordering::Equal => (center[b] + side * ring, center[b] + side * ring),
+                        Ordering::Greater => (center[b] - ring, center[b] + ring),
+                    };
+                    (lo[b], hi[b]) = (from.max(min), to.min(max));
+                    if lo[b] > hi[b] {
+                        return None;
+                    }
+                }
+                Some((lo, hi))
+            })
+            .flat_map(|(lo, hi)| Self::cells_between(lo, hi))
+    }
+
+    /// The `k` closest points to `p`, closest first
+    ///
+    /// This looks at rings of cells around `p`, further and further out, until nothing past the last ring could be any
+    /// closer. Only the part of each ring that's inside `bounds` is looked at, and rings that don't reach them at all
+    /// are skipped, so a `p` that's far away from every point is as quick as one that's close.
+    pub fn nearest(&self, p: impl Coords<N>, k: usize, metric: Metric) -> Vec<Neighbor> {
+        let p = p.coords();
+        let center = self.cell_of(p);
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k == 0 || self.is_empty() {
+            return vec![];
+        }
+
+        let first_ring = (0..N)
+            .map(|axis| {
+                let (min, max) = self.bounds[axis];
+                (min - center[axis]).max(center[axis] - max).max(0)
+            })
+            .max()
+            .unwrap_or(0);
+        for ring in first_ring.. {
+            for cell in self.ring_cells(center, ring) {
+                for (index, q) in self.bucket(&cell) {
+                    let dist = metric.dist(p, q);
+                    offer(&mut best, k, Neighbor { dist, index });
+                }
+            }
+
+            // Anything in a further ring is more than `ring` cells away along some axis
+            let covered_everything = (0..N).all(|axis| {
+                let (min, max) = self.bounds[axis];
+                center[axis] - ring <= min && max <= center[axis] + ring
+            });
+            if covered_everything
+                || !could_improve(&best, k, metric.axis_bound(ring * self.cell + 1))
+            {
+                break;
+            }
+        }
+
+        best.into_sorted_vec()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    /// Points that are all over the place, with plenty of repeated coordinates and a few exact duplicates
+    fn scattered<const N: usize>(count: usize, spread: i64) -> Vec<[i64; N]> {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut points = (0..count)
+            .map(|_| {
+                std::array::from_fn(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    (seed % (2 * spread as u64)) as i64 - spread
+                })
+            })
+            .collect::<Vec<_>>();
+        points.extend_from_within(..3);
+        points
+    }
+
+    fn brute_force<const N: usize>(
+        points: &[[i64; N]],
+        p: [i64; N],
+        metric: Metric,
+    ) -> Vec<Neighbor> {
+        let mut all = points
+            .iter()
+            .enumerate()
+            .map(|(index, &q)| Neighbor {
+                dist: metric.dist(p, q),
+                index,
+            })
+            .collect::<Vec<_>>();
+        all.sort_unstable();
+        all
+    }
+
+    #[rstest]
+    fn check_nearest(
+        #[values(Metric::Euclidean, Metric::Manhattan)] metric: Metric,
+        #[values(1, 4, 20, 400)] k: usize,
+    ) {
+        let points = scattered::<3>(300, 50);
+        let tree = KdTree::new(points.iter().copied());
+        let grid = BucketGrid::new(points.iter().copied(), 16);
+
+        for p in scattered::<3>(30, 60)
+            .into_iter()
+            .chain(points.iter().copied().take(5))
+        {
+            let expected = &brute_force(&points, p, metric)[..k.min(points.len())];
+            assert_eq!(tree.nearest(p, k, metric), expected, "{p:?}");
+            assert_eq!(grid.nearest(p, k, metric), expected, "{p:?}");
+        }
+    }
+
+    #[rstest]
+    fn check_within(
+        #[values(Metric::Euclidean, Metric::Manhattan)] metric: Metric,
+        #[values(0, 10, 400)] max_dist: i64,
+    ) {
+        let points = scattered::<2>(300, 50);
+        let tree = KdTree::new(points.iter().copied());
+        let grid = BucketGrid::new(points.iter().copied(), 8);
+
+        for p in scattered::<2>(30, 60)
+            .into_iter()
+            .chain(points.iter().copied().take(5))
+        {
+            let expected = brute_force(&points, p, metric)
+                .into_iter()
+                .filter(|n| n.dist <= max_dist)
+                .collect::<Vec<_>>();
+            assert_eq!(tree.within(p, max_dist, metric), expected, "{p:?}");
+            assert_eq!(grid.within(p, max_dist, metric), expected, "{p:?}");
+        }
+    }
+
+    #[rstest]
+    fn check_pairs_by_distance(#[values(Metric::Euclidean, Metric::Manhattan)] metric: Metric) {
+        let points = scattered::<3>(100, 20);
+        let tree = KdTree::new(points.iter().copied());
+
+        let mut expected = vec![];
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                expected.push((metric.dist(points[i], points[j]), i, j));
+            }
+        }
+        expected.sort_unstable();
+
+        assert_eq!(
+            tree.pairs_by_distance(metric).take(500).collect::<Vec<_>>(),
+            expected[..500]
+        );
+        assert_eq!(tree.pairs_by_distance(metric).count(), expected.len());
+    }
+
+    #[test]
+    fn check_ivecs_and_edge_cases() {
+        let tree = KdTree::new([IVec2::new(0, 0), IVec2::new(3, 4), IVec2::new(-1, 0)]);
+        assert_eq!(
+            tree.nearest(IVec2::new(2, 2), 2, Metric::Euclidean),
+            [
+                Neighbor { dist: 5, index: 1 },
+                Neighbor { dist: 8, index: 0 }
+            ]
+        );
+        assert_eq!(tree.nearest([0, 0], 10, Metric::Manhattan).len(), 3);
+        assert_eq!(tree.nearest([0, 0], 0, Metric::Manhattan), []);
+
+        let empty = KdTree::<3>::new(Vec::<IVec3>::new());
+        assert_eq!(empty.nearest([0, 0, 0], 3, Metric::Euclidean), []);
+        assert_eq!(empty.pairs_by_distance(Metric::Euclidean).next(), None);
+
+        let grid = BucketGrid::new([IVec3::new(-5, -5, -5), IVec3::new(100, 0, 0)], 3);
+        assert_eq!(
+            grid.nearest([0, 0, 0], 1, Metric::Manhattan),
+            [Neighbor { dist: 15, index: 0 }]
+        );
+        assert_eq!(
+            grid.within([0, 0, 0], 75, Metric::Euclidean),
+            [Neighbor { dist: 75, index: 0 }]
+        );
+
+        // Way outside of every point, asking for more than there are
+        let far = 1_000_000_000_000;
+        assert_eq!(
+            grid.nearest([far, 0, 0], 5, Metric::Manhattan),
+            [
+                Neighbor {
+                    dist: far - 100,
+                    index: 1
+                },
+                Neighbor {
+                    dist: far + 15,
+                    index: 0
+                }
+            ]
+        );
+    }
+}
@@ -1,18 +1,246 @@
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops;
 
-use crate::util::fixed_bitset::Backing;
-
-#[derive(Copy, Clone, PartialEq, Eq, bytemuck::NoUninit)]
+/// A 256-bit unsigned integer, for when `u128` isn't quite wide enough
+///
+/// Operators act like the built-in integers do: overflowing panics in debug builds and wraps in release builds, and
+/// shifting by 256 or more always panics.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct U256(pub [u128; 2]);
 
+impl U256 {
+    pub const ZERO: Self = Self([0, 0]);
+    pub const ONE: Self = Self([0, 1]);
+    pub const MAX: Self = Self([u128::MAX, u128::MAX]);
+    pub const BITS: u32 = 256;
+
+    pub const fn from_u128(n: u128) -> Self {
+        Self([0, n])
+    }
+
+    pub const fn hi(self) -> u128 {
+        self.0[0]
+    }
+
+    pub const fn lo(self) -> u128 {
+        self.0[1]
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.hi() == 0 && self.lo() == 0
+    }
+
+    pub const fn count_ones(self) -> u32 {
+        self.hi().count_ones() + self.lo().count_ones()
+    }
+
+    pub const fn count_zeros(self) -> u32 {
+        self.hi().count_zeros() + self.lo().count_zeros()
+    }
+
+    pub const fn leading_zeros(self) -> u32 {
+        if self.hi() == 0 {
+            128 + self.lo().leading_zeros()
+        } else {
+            self.hi().leading_zeros()
+        }
+    }
+
+    pub const fn trailing_zeros(self) -> u32 {
+        if self.lo() == 0 {
+            128 + self.hi().trailing_zeros()
+        } else {
+            self.lo().trailing_zeros()
+        }
+    }
+
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (lo, carry) = self.lo().overflowing_add(rhs.lo());
+        let (hi, overflow1) = self.hi().overflowing_add(rhs.hi());
+        let (hi, overflow2) = hi.overflowing_add(carry as u128);
+
+        (Self([hi, lo]), overflow1 || overflow2)
+    }
+
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (lo, borrow) = self.lo().overflowing_sub(rhs.lo());
+        let (hi, overflow1) = self.hi().overflowing_sub(rhs.hi());
+        let (hi, overflow2) = hi.overflowing_sub(borrow as u128);
+
+        (Self([hi, lo]), overflow1 || overflow2)
+    }
+
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        // (a_hi * 2^128 + a_lo) * (b_hi * 2^128 + b_lo), where a_hi * b_hi is always past the top
+        let Self([lo_carry, lo]) = widening_mul(self.lo(), rhs.lo());
+        let (cross1, overflow1) = self.hi().overflowing_mul(rhs.lo());
+        let (cross2, overflow2) = self.lo().overflowing_mul(rhs.hi());
+        let (hi, overflow3) = lo_carry.overflowing_add(cross1);
+        let (hi, overflow4) = hi.overflowing_add(cross2);
+
+        let overflow =
+            (self.hi() != 0 && rhs.hi() != 0) || overflow1 || overflow2 || overflow3 || overflow4;
+        (Self([hi, lo]), overflow)
+    }
+
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (n, false) => Some(n),
+            (_, true) => None,
+        }
+    }
+
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (n, false) => Some(n),
+            (_, true) => None,
+        }
+    }
+
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (n, false) => Some(n),
+            (_, true) => None,
+        }
+    }
+
+    /// Divides by a small number, returning the quotient and remainder. Enough for printing in any base.
+    #[track_caller]
+    pub const fn div_rem_u64(self, rhs: u64) -> (Self, u64) {
+        assert!(rhs != 0, "attempt to divide by zero");
+
+        // Long division, 64 bits at a time so each step fits in a u128
+        let rhs = rhs as u128;
+        let mut rem = 0_u128;
+        let mut out = [0_u128; 2];
+        let mut i = 0;
+        while i < 4 {
+            let half = i / 2;
+            let shift = if i % 2 == 0 { 64 } else { 0 };
+            let digit = (self.0[half] >> shift) as u64 as u128;
+
+            let n = (rem << 64) | digit;
+            out[half] |= (n / rhs) << shift;
+            rem = n % rhs;
+            i += 1;
+        }
+
+        (Self(out), rem as u64)
+    }
+
+    /// Digits in `base`, most significant first
+    fn to_digits(self, base: u64, upper: bool) -> String {
+        if self.hi() == 0 {
+            return match (base, upper) {
+                (2, _) => format!("{:b}", self.lo()),
+                (10, _) => format!("{}", self.lo()),
+                (16, false) => format!("{:x}", self.lo()),
+                (16, true) => format!("{:X}", self.lo()),
+                _ => unreachable!("Unsupported base {base}"),
+            };
+        }
+
+        let digits = if upper {
+            b"0123456789ABCDEF"
+        } else {
+            b"0123456789abcdef"
+        };
+        let mut out = vec![];
+        let mut n = self;
+        while !n.is_zero() {
+            let (quot, rem) = n.div_rem_u64(base);
+            out.push(digits[rem as usize]);
+            n = quot;
+        }
+        out.reverse();
+
+        String::from_utf8(out).unwrap()
+    }
+}
+
+/// The full 256-bit product of two `u128`s
+const fn widening_mul(a: u128, b: u128) -> U256 {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    // Each of these fits, since they're 64 x 64 bits
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+
+    U256([hi, lo])
+}
+
 impl Default for U256 {
     fn default() -> Self {
         Self::ZERO
     }
 }
 
+impl From<u128> for U256 {
+    fn from(n: u128) -> Self {
+        Self::from_u128(n)
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(n: u64) -> Self {
+        Self::from_u128(n as u128)
+    }
+}
+
+impl From<u32> for U256 {
+    fn from(n: u32) -> Self {
+        Self::from_u128(n as u128)
+    }
+}
+
+impl TryFrom<U256> for u128 {
+    type Error = core::num::TryFromIntError;
+
+    fn try_from(n: U256) -> Result<Self, Self::Error> {
+        if n.hi() == 0 {
+            Ok(n.lo())
+        } else {
+            // There's no way to make one of these ourselves
+            Err(u8::try_from(u16::MAX).unwrap_err())
+        }
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Already most significant first
+        self.0.cmp(&other.0)
+    }
+}
+
 impl fmt::Debug for U256 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("U256")
@@ -24,17 +252,34 @@ impl fmt::Debug for U256 {
 
 impl fmt::Display for U256 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("U256")
-            .field("hi", &format!("0b_{:0b}", self.0[0]))
-            .field("lo", &format!("0b_{:0b}", self.0[1]))
-            .finish()
+        f.pad_integral(true, "", &self.to_digits(10, false))
+    }
+}
+
+impl fmt::LowerHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_digits(16, false))
+    }
+}
+
+impl fmt::UpperHex for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_digits(16, true))
+    }
+}
+
+impl fmt::Binary for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0b", &self.to_digits(2, false))
     }
 }
 
 impl ops::Shl<usize> for U256 {
     type Output = Self;
 
+    #[track_caller]
     fn shl(self, mut n: usize) -> Self::Output {
+        assert!(n < 256, "attempt to shift left with overflow");
         let Self([mut hi, mut lo]) = self;
 
         if n >= 128 {
@@ -54,6 +299,31 @@ impl ops::Shl<usize> for U256 {
     }
 }
 
+impl ops::Shr<usize> for U256 {
+    type Output = Self;
+
+    #[track_caller]
+    fn shr(self, mut n: usize) -> Self::Output {
+        assert!(n < 256, "attempt to shift right with overflow");
+        let Self([mut hi, mut lo]) = self;
+
+        if n >= 128 {
+            // shift by 128
+            lo = hi;
+            hi = 0;
+            n -= 128;
+        }
+
+        if n > 0 {
+            lo >>= n;
+            lo |= hi << (128 - n);
+            hi >>= n;
+        }
+
+        Self([hi, lo])
+    }
+}
+
 impl ops::BitAnd for U256 {
     type Output = Self;
 
@@ -72,6 +342,15 @@ impl ops::BitOr for U256 {
     }
 }
 
+impl ops::BitXor for U256 {
+    type Output = Self;
+
+    fn bitxor(self, U256(rhs): U256) -> Self::Output {
+        let U256(this) = self;
+        U256([this[0] ^ rhs[0], this[1] ^ rhs[1]])
+    }
+}
+
 impl ops::Not for U256 {
     type Output = Self;
 
@@ -81,30 +360,75 @@ impl ops::Not for U256 {
     }
 }
 
-impl Backing for U256 {
-    const ONE: Self = Self([0, 1]);
-    const ZERO: Self = Self([0, 0]);
+/// The built-in integers only check for overflow in debug builds, so we don't either
+macro_rules! impl_arith_op {
+    ($trait:ident, $method:ident, $overflowing:ident, $msg:literal) => {
+        impl ops::$trait for U256 {
+            type Output = Self;
 
-    fn count_ones(&self) -> u32 {
-        self.0[0].count_ones() + self.0[1].count_ones()
-    }
+            #[track_caller]
+            fn $method(self, rhs: Self) -> Self {
+                let (n, overflow) = self.$overflowing(rhs);
+                if cfg!(debug_assertions) && overflow {
+                    panic!($msg);
+                }
+                n
+            }
+        }
+    };
+}
 
-    fn count_zeros(&self) -> u32 {
-        self.0[0].count_zeros() + self.0[1].count_zeros()
-    }
+impl_arith_op!(Add, add, overflowing_add, "attempt to add with overflow");
+impl_arith_op!(
+    Sub,
+    sub,
+    overflowing_sub,
+    "attempt to subtract with overflow"
+);
+impl_arith_op!(
+    Mul,
+    mul,
+    overflowing_mul,
+    "attempt to multiply with overflow"
+);
 
-    fn bit_width() -> u32 {
-        256
-    }
+macro_rules! impl_assign_op {
+    ($($trait:ident, $method:ident, $op:tt, $rhs:ty);+ $(;)?) => {
+        $(
+            impl ops::$trait<$rhs> for U256 {
+                #[track_caller]
+                fn $method(&mut self, rhs: $rhs) {
+                    *self = *self $op rhs;
+                }
+            }
+        )+
+    };
+}
 
-    fn as_be_bytes(&self) -> &[u8] {
-        bytemuck::bytes_of(self)
-    }
+impl_assign_op! {
+    ShlAssign, shl_assign, <<, usize;
+    ShrAssign, shr_assign, >>, usize;
+    BitAndAssign, bitand_assign, &, Self;
+    BitOrAssign, bitor_assign, |, Self;
+    BitXorAssign, bitxor_assign, ^, Self;
+    AddAssign, add_assign, +, Self;
+    SubAssign, sub_assign, -, Self;
+    MulAssign, mul_assign, *, Self;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// 2^n - 1, a bunch of 1s in a row
+    fn ones(n: usize) -> U256 {
+        if n == 256 {
+            U256::MAX
+        } else {
+            (U256::ONE << n) - U256::ONE
+        }
+    }
 
     #[test]
     fn check_u256_shifts() {
@@ -131,4 +455,119 @@ mod tests {
             dbg!();
         }
     }
+
+    #[test]
+    fn check_u256_shr_undoes_shl() {
+        for i in 0..256 {
+            assert_eq!((U256::ONE << i) >> i, U256::ONE, "i={i}");
+            assert_eq!(U256::MAX >> i, ones(256 - i), "i={i}");
+            assert_eq!((U256::ONE << i).trailing_zeros(), i as u32);
+            assert_eq!((U256::ONE << i).leading_zeros(), 255 - i as u32);
+        }
+
+        assert_eq!(U256::ZERO.trailing_zeros(), 256);
+        assert_eq!(U256::ZERO.leading_zeros(), 256);
+    }
+
+    #[test]
+    #[should_panic(expected = "shift left with overflow")]
+    fn check_u256_shl_too_far() {
+        let _ = U256::ONE << 256;
+    }
+
+    #[test]
+    fn check_u256_add_sub() {
+        let a = U256::from(u128::MAX);
+        assert_eq!(a + U256::ONE, U256([1, 0]));
+        assert_eq!(U256([1, 0]) - U256::ONE, a);
+        assert_eq!(ones(200) + U256::ONE, U256::ONE << 200);
+
+        assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+        assert_eq!(U256::ZERO.overflowing_sub(U256::ONE), (U256::MAX, true));
+        assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+        assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+    }
+
+    #[test]
+    fn check_u256_mul() {
+        let a = U256::from(u128::MAX);
+        // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+        assert_eq!(a * a, U256([u128::MAX - 1, 1]));
+        assert_eq!(U256::from(12_u32) * U256::from(34_u32), U256::from(408_u32));
+        assert_eq!((U256::ONE << 100) * (U256::ONE << 155), U256::ONE << 255);
+
+        assert_eq!((U256::ONE << 128).checked_mul(U256::ONE << 128), None);
+        assert_eq!((U256::ONE << 200).checked_mul(U256::ONE << 56), None);
+        assert_eq!(U256::MAX.checked_mul(U256::from(2_u32)), None);
+        assert_eq!(U256::MAX.wrapping_mul(U256::MAX), U256::ONE);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "add with overflow")]
+    fn check_u256_add_overflow() {
+        let _ = U256::MAX + U256::ONE;
+    }
+
+    #[test]
+    fn check_u256_ord() {
+        let mut nums = [
+            U256::ONE << 200,
+            U256::MAX,
+            U256::ZERO,
+            U256::from(u128::MAX),
+            U256::ONE,
+        ];
+        nums.sort();
+        assert_eq!(
+            nums,
+            [
+                U256::ZERO,
+                U256::ONE,
+                U256::from(u128::MAX),
+                U256::ONE << 200,
+                U256::MAX
+            ]
+        );
+    }
+
+    #[test]
+    fn check_u256_display() {
+        assert_eq!(U256::ZERO.to_string(), "0");
+        assert_eq!(U256::from(1234_u32).to_string(), "1234");
+        assert_eq!(
+            U256::MAX.to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        assert_eq!(
+            (U256::ONE << 128).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+        assert_eq!(format!("{:>6}", U256::from(42_u32)), "    42");
+        assert_eq!(format!("{:06}", U256::from(42_u32)), "000042");
+    }
+
+    #[test]
+    fn check_u256_hex_and_binary() {
+        assert_eq!(format!("{:x}", U256::ZERO), "0");
+        assert_eq!(
+            format!("{:x}", U256::ONE << 128),
+            format!("1{}", "0".repeat(32))
+        );
+        assert_eq!(format!("{:X}", U256::MAX), "F".repeat(64));
+        assert_eq!(format!("{:#x}", U256::from(255_u32)), "0xff");
+        assert_eq!(
+            format!("{:b}", U256::ONE << 130),
+            format!("1{}", "0".repeat(130))
+        );
+    }
+
+    #[test]
+    fn check_u256_div_rem() {
+        let (quot, rem) = U256::MAX.div_rem_u64(10);
+        assert_eq!(rem, 5);
+        assert_eq!(quot * U256::from(10_u32) + U256::from(5_u32), U256::MAX);
+
+        assert_eq!(U256::ZERO.div_rem_u64(7), (U256::ZERO, 0));
+    }
 }
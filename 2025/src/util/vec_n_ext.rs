@@ -4,10 +4,25 @@ pub trait VecExt: Sized + Copy {
     type Neighbors;
     type FullNeighbors;
 
+    /// Neighbors that share a face, which is 2 per axis
     fn neighbors(&self) -> Self::Neighbors;
-    fn full_neighbors(&self) -> Self::FullNeighbors {
-        todo!()
-    }
+
+    /// Neighbors that share anything, even just a corner, which is `3^N - 1` of them
+    fn full_neighbors(&self) -> Self::FullNeighbors;
+}
+
+/// Every offset in -1..=1 on each of `N` axes, except all zeros, with x changing fastest
+fn full_offsets<const N: usize, const LEN: usize>() -> [[i32; N]; LEN] {
+    debug_assert_eq!(LEN + 1, 3_usize.pow(N as u32));
+    std::array::from_fn(|i| {
+        // Skip over the middle one, which is all zeros
+        let mut i = if i < LEN / 2 { i } else { i + 1 };
+        std::array::from_fn(|_| {
+            let d = (i % 3) as i32 - 1;
+            i /= 3;
+            d
+        })
+    })
 }
 
 impl VecExt for IVec2 {
@@ -46,7 +61,7 @@ impl VecExt for IVec2 {
 
 impl VecExt for Vec2 {
     type Neighbors = [Self; 4];
-    type FullNeighbors = ();
+    type FullNeighbors = [Self; 8];
 
     fn neighbors(&self) -> Self::Neighbors {
         let a = *self;
@@ -59,11 +74,16 @@ impl VecExt for Vec2 {
             a - Self::unit_y(),
         ]
     }
+
+    fn full_neighbors(&self) -> Self::FullNeighbors {
+        let a = *self;
+        full_offsets::<2, 8>().map(|[x, y]| a + Self::new(x as f32, y as f32))
+    }
 }
 
 impl VecExt for IVec3 {
     type Neighbors = [Self; 6];
-    type FullNeighbors = ();
+    type FullNeighbors = [Self; 26];
 
     fn neighbors(&self) -> Self::Neighbors {
         let a = *self;
@@ -78,11 +98,40 @@ impl VecExt for IVec3 {
             a - Self::unit_z(),
         ]
     }
+
+    fn full_neighbors(&self) -> Self::FullNeighbors {
+        let a = *self;
+        full_offsets::<3, 26>().map(|d| a + Self::from(d))
+    }
 }
 
 impl VecExt for Vec3 {
     type Neighbors = [Self; 6];
-    type FullNeighbors = ();
+    type FullNeighbors = [Self; 26];
+
+    fn neighbors(&self) -> Self::Neighbors {
+        let a = *self;
+        [
+            // Positive
+            a + Self::unit_x(),
+            a + Self::unit_y(),
+            a + Self::unit_z(),
+            // Negative
+            a - Self::unit_x(),
+            a - Self::unit_y(),
+            a - Self::unit_z(),
+        ]
+    }
+
+    fn full_neighbors(&self) -> Self::FullNeighbors {
+        let a = *self;
+        full_offsets::<3, 26>().map(|[x, y, z]| a + Self::new(x as f32, y as f32, z as f32))
+    }
+}
+
+impl VecExt for IVec4 {
+    type Neighbors = [Self; 8];
+    type FullNeighbors = [Self; 80];
 
     fn neighbors(&self) -> Self::Neighbors {
         let a = *self;
@@ -91,10 +140,17 @@ impl VecExt for Vec3 {
             a + Self::unit_x(),
             a + Self::unit_y(),
             a + Self::unit_z(),
+            a + Self::unit_w(),
             // Negative
             a - Self::unit_x(),
             a - Self::unit_y(),
             a - Self::unit_z(),
+            a - Self::unit_w(),
         ]
     }
+
+    fn full_neighbors(&self) -> Self::FullNeighbors {
+        let a = *self;
+        full_offsets::<4, 80>().map(|d| a + Self::from(d))
+    }
 }
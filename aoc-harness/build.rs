@@ -0,0 +1,15 @@
+//! Remember which `rustc` built us, so benchmarks only get compared with others from the same toolchain
+use std::process::Command;
+
+fn main() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .ok()
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .unwrap_or_else(|| "rustc unknown".to_string());
+
+    println!("cargo:rustc-env=AOC_HARNESS_RUSTC={}", version.trim());
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
@@ -0,0 +1,125 @@
+//! See `aoc_harness::registry!`
+use aoc_runner_internal::DayParts;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, parse_macro_input, Ident, Path, Token};
+
+/// `aoc25`, then any of `setup = [aoc25::init_logging]` and `random_inputs = aoc25::random_input::ALL`
+struct Args {
+    lib: Ident,
+    setup: Vec<Path>,
+    random_inputs: Option<Path>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lib = input.parse()?;
+        let mut setup = vec![];
+        let mut random_inputs = None;
+
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            if key == "setup" {
+                let hooks;
+                bracketed!(hooks in input);
+                setup = Punctuated::<Path, Token![,]>::parse_terminated(&hooks)?
+                    .into_iter()
+                    .collect();
+            } else if key == "random_inputs" {
+                random_inputs = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected `setup = [...]` or `random_inputs = ...`",
+                ));
+            }
+        }
+
+        Ok(Args {
+            lib,
+            setup,
+            random_inputs,
+        })
+    }
+}
+
+/// `registry!(aoc25)` expands to an `aoc_harness::Year` listing every `#[aoc(...)]` solution in the `aoc25` lib.
+///
+/// Like `aoc_main!`, this reads the `target/aoc/completed.json` that `aoc_lib!` writes while building the lib.
+///
+/// Functions to call before running any solutions, like setting up logging, go in `setup`, as in
+/// `registry!(aoc25, setup = [aoc25::init_logging])`.
+///
+/// Generators for `bench --scale` go in `random_inputs`, as a `&[aoc_harness::scaling::RandomInput]`.
+#[proc_macro]
+pub fn registry(input: TokenStream) -> TokenStream {
+    let Args {
+        lib,
+        setup,
+        random_inputs,
+    } = parse_macro_input!(input as Args);
+    let random_inputs = match random_inputs {
+        Some(path) => quote! { #path },
+        None => quote! { &[] },
+    };
+
+    let parts = match DayParts::load() {
+        Ok(parts) => parts,
+        Err(e) => {
+            let msg = format!(
+                "Couldn't read target/aoc/completed.json. Was {lib} built with aoc_lib!? {e}"
+            );
+            return quote! { compile_error!(#msg) }.into();
+        }
+    };
+    let year = parts.year;
+
+    let solutions = parts.iter().map(|dp| {
+        let (day, part) = (dp.day.0 as u32, dp.part.0 as u32);
+
+        // These match the names aoc_lib! generates for its Factory
+        let (method, tr) = match &dp.name {
+            Some(name) => (
+                format_ident!("day{day}_part{part}_{}", name.to_lowercase()),
+                format_ident!("Day{day}Part{part}{}", name.to_uppercase()),
+            ),
+            None => (
+                format_ident!("day{day}_part{part}"),
+                format_ident!("Day{day}Part{part}"),
+            ),
+        };
+        let name = match &dp.name {
+            Some(name) => quote! { Some(#name) },
+            None => quote! { None },
+        };
+
+        quote! {
+            ::aoc_harness::Solution {
+                day: #day,
+                part: #part,
+                name: #name,
+                generator: <#lib::Factory as #lib::#tr>::#method,
+            }
+        }
+    });
+
+    quote! {
+        {
+            const SOLUTIONS: &[::aoc_harness::Solution] = &[#(#solutions),*];
+            const SETUP: &[fn()] = &[#(#setup),*];
+            const RANDOM_INPUTS: &[::aoc_harness::scaling::RandomInput] = #random_inputs;
+
+            ::aoc_harness::Year {
+                year: #year,
+                dir: env!("CARGO_MANIFEST_DIR"),
+                solutions: SOLUTIONS,
+                setup: SETUP,
+                random_inputs: RANDOM_INPUTS,
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,180 @@
+//! Known-good answers, checked in as `<year>/answers.txt`
+//!
+//! One answer per line, keyed by the input's name and the part:
+//! ```text
+//! # Comments and blank lines are ignored
+//! day1 part1: 1234
+//! day1 part2: 5678
+//! ```
+//! Answers that span lines (like letters drawn in a grid) have `\n` and `\\` escaped.
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = "\
+# Known-good answers for every part, checked by `cargo run --release --bin check`
+# Format: <input> part<N>: <answer>
+
+";
+
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    path: PathBuf,
+    /// Keyed by (day, input, part), so day2 sorts before day10
+    entries: BTreeMap<(u32, String, u32), String>,
+}
+
+impl Answers {
+    /// A missing file is fine, and just has no answers yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            // Don't trim: spaces can be part of an answer
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let bad_line = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}:{}: expected \"<input> part<N>: <answer>\", found {line:?}",
+                        path.display(),
+                        i + 1
+                    ),
+                )
+            };
+            let (key, answer) = line.split_once(": ").ok_or_else(bad_line)?;
+            let (input, part) = key.split_once(' ').ok_or_else(bad_line)?;
+            let part = part
+                .strip_prefix("part")
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(bad_line)?;
+            let day = day_of(input).ok_or_else(bad_line)?;
+
+            entries.insert((day, input.to_string(), part), unescape(answer));
+        }
+
+        Ok(Answers { path, entries })
+    }
+
+    pub fn get(&self, input: &str, part: u32) -> Option<&str> {
+        let day = day_of(input)?;
+        self.entries
+            .get(&(day, input.to_string(), part))
+            .map(String::as_str)
+    }
+
+    /// Returns `false` if `input` isn't named like `dayN...`
+    pub fn insert(&mut self, input: &str, part: u32, answer: &str) -> bool {
+        let Some(day) = day_of(input) else {
+            return false;
+        };
+        self.entries
+            .insert((day, input.to_string(), part), answer.to_string());
+
+        true
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut text = HEADER.to_string();
+        let mut last_day = None;
+        for ((day, input, part), answer) in &self.entries {
+            if last_day.is_some_and(|d| d != *day) {
+                text.push('\n');
+            }
+            last_day = Some(*day);
+
+            text += &format!("{input} part{part}: {}\n", escape(answer));
+        }
+
+        fs::write(&self.path, text)
+    }
+}
+
+/// `day12-kyle` -> 12
+pub fn day_of(input: &str) -> Option<u32> {
+    let digits = input.strip_prefix("day")?;
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(digits.len());
+
+    digits[..end].parse().ok()
+}
+
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    let mut s = String::with_capacity(answer.len());
+    let mut chars = answer.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some('n')) => {
+                s.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                s.push('\\');
+                chars.next();
+            }
+            _ => s.push(c),
+        }
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_escaping() {
+        for answer in ["1234", "#..#\n####", r"a\nb", "trailing\\"] {
+            assert_eq!(unescape(&escape(answer)), answer);
+            assert!(!escape(answer).contains('\n'));
+        }
+    }
+
+    #[test]
+    fn check_day_of() {
+        assert_eq!(day_of("day1"), Some(1));
+        assert_eq!(day_of("day12-kyle"), Some(12));
+        assert_eq!(day_of("day22_example"), Some(22));
+        assert_eq!(day_of("input"), None);
+    }
+
+    #[test]
+    fn check_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-harness-answers-{}.txt", std::process::id()));
+        let mut answers = Answers::load(&path).unwrap();
+        answers.insert("day10", 1, "13");
+        answers.insert("day2", 2, "#.\n.#");
+        answers.insert("day2", 1, "7");
+        answers.save().unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(
+            text.ends_with("day2 part1: 7\nday2 part2: #.\\n.#\n\nday10 part1: 13\n"),
+            "{text}"
+        );
+
+        let loaded = Answers::load(&path).unwrap();
+        assert_eq!(loaded.get("day2", 2), Some("#.\n.#"));
+        assert_eq!(loaded.get("day10", 1), Some("13"));
+        assert_eq!(loaded.get("day10", 2), None);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,644 @@
+//! Time every solution, and compare with the last time we did
+//!
+//! ```text
+//! $ cargo run --release --bin bench                   # Everything
+//! $ cargo run --release --bin bench -- --day 7        # Just day 7
+//! $ cargo run --release --bin bench -- --time 5       # Spend longer on each solution, for less noise
+//! $ cargo run --release --bin bench -- --day 9 --scale # Random inputs that keep doubling, instead of mine
+//! ```
+//! Parsing (the `#[aoc_generator]`) and solving are timed separately, each after a warmup. Results are appended to
+//! `<year>/bench-history.tsv`, which isn't checked in: the numbers only mean anything next to others from the same
+//! machine and toolchain, so that's what they're compared against. See [`crate::scaling`] for `--scale`.
+use clap::Parser;
+
+use std::fs;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::check::{find_one, spawn_one, Outcome, ERROR_EXIT};
+use crate::{ArcStr, Solution, Year};
+
+/// Re-run ourselves with this to benchmark a single solution
+const BENCH_ONE: &str = "--bench-one";
+const PARSE_MARKER: &str = "@@aoc-harness parse@@ ";
+const SOLVE_MARKER: &str = "@@aoc-harness solve@@ ";
+/// Keeps the samples for a fast solution from using all of our memory
+const MAX_SAMPLES: usize = 100_000;
+/// `rustc -V` of whatever built us, from `build.rs`
+const TOOLCHAIN: &str = env!("AOC_HARNESS_RUSTC");
+
+const HISTORY_HEADER: &str = "\
+# Benchmark results from `cargo run --release --bin bench`. Times are in ns.
+# run\tmachine\ttoolchain\tsolution\tparse samples\tmin\tmedian\tmean\tstddev\tsolve samples\tmin\tmedian\tmean\tstddev
+";
+
+#[derive(Debug, Parser)]
+#[command(about = "Benchmark every solution, and compare with the last run on this machine")]
+struct Opts {
+    /// Only benchmark this day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Only benchmark this part
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Seconds to spend timing each of parse and solve, after warming up
+    #[arg(short, long, default_value_t = 1.0)]
+    time: f64,
+
+    /// Seconds to spend warming up each of parse and solve
+    #[arg(long, default_value_t = 0.2)]
+    warmup: f64,
+
+    /// Call it a regression when the median gets this many percent slower
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Don't add this run to bench-history.tsv
+    #[arg(long)]
+    no_save: bool,
+
+    /// Time random inputs of bigger and bigger sizes instead of mine, and don't save anything
+    #[arg(long)]
+    scale: bool,
+
+    /// With --scale, stop doubling a solution's input once parsing and solving take this many seconds
+    #[arg(long, default_value_t = 1.0)]
+    limit: f64,
+
+    /// With --scale, the biggest size to ask the generators for
+    #[arg(long, default_value_t = 1 << 20)]
+    max_size: usize,
+
+    /// With --scale, which random inputs to make
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+/// Summary of a set of timings
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &mut [Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let mean = samples.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            samples: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_fields(self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}",
+            self.samples,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+
+    fn from_fields<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
+        let samples = fields.next()?.parse().ok()?;
+        let mut next = || fields.next()?.parse().ok().map(Duration::from_nanos);
+
+        Some(Stats {
+            samples,
+            min: next()?,
+            median: next()?,
+            mean: next()?,
+            stddev: next()?,
+        })
+    }
+}
+
+/// One solution's results from one run of `bench`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// When the run started, in seconds since the Unix epoch. Every entry from the same run shares it.
+    pub run: u64,
+    pub machine: String,
+    pub toolchain: String,
+    /// [`Solution::label()`]
+    pub solution: String,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Entry {
+    /// What gets compared between runs: parsing and solving, together
+    pub fn median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+/// Every past benchmark run, from `<year>/bench-history.tsv`
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// A missing file is fine, and just has no history yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = vec![];
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split('\t');
+            let entry = (|| {
+                Some(Entry {
+                    run: fields.next()?.parse().ok()?,
+                    machine: fields.next()?.to_string(),
+                    toolchain: fields.next()?.to_string(),
+                    solution: fields.next()?.to_string(),
+                    parse: Stats::from_fields(&mut fields)?,
+                    solve: Stats::from_fields(&mut fields)?,
+                })
+            })();
+            let Some(entry) = entry else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: bad entry {line:?}", path.display(), i + 1),
+                ));
+            };
+            entries.push(entry);
+        }
+
+        Ok(History { path, entries })
+    }
+
+    /// The most recent result for `solution` from the same machine and toolchain
+    pub fn previous(&self, machine: &str, toolchain: &str, solution: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .filter(|e| e.machine == machine && e.toolchain == toolchain && e.solution == solution)
+            .max_by_key(|e| e.run)
+    }
+
+    /// Add `entry` to the file, and to what we've loaded
+    pub fn append(&mut self, entry: Entry) -> io::Result<()> {
+        let is_new = !self.path.exists();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if is_new {
+            file.write_all(HISTORY_HEADER.as_bytes())?;
+        }
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            entry.run,
+            entry.machine,
+            entry.toolchain,
+            entry.solution,
+            entry.parse.to_fields(),
+            entry.solve.to_fields()
+        )?;
+        self.entries.push(entry);
+
+        Ok(())
+    }
+}
+
+/// Something to tell this machine apart from others that share the history file
+pub fn machine() -> String {
+    let name = fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    name.trim().replace('\t', " ")
+}
+
+/// `rustc -V` and the build profile, since a debug build is never going to be comparable
+pub fn toolchain() -> String {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    format!("{TOOLCHAIN} {profile}")
+}
+
+/// How much slower `now` is than `before`, as a percentage. Negative is faster.
+pub fn change(before: Duration, now: Duration) -> f64 {
+    if before.is_zero() {
+        return 0.0;
+    }
+
+    (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+}
+
+pub fn main(year: &Year) -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some(BENCH_ONE) {
+        return bench_one(year, &args[2..]);
+    }
+    let opts = Opts::parse();
+    if opts.scale {
+        return scale(year, &opts);
+    }
+
+    let history_path = year.bench_history_path();
+    let mut history = match History::load(&history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let run = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (machine, toolchain) = (machine(), toolchain());
+    let budgets = [opts.time, opts.warmup].map(|s| s.to_string());
+
+    println!("Advent of Code {} on {machine}, {toolchain}", year.year);
+    let (mut timed, mut regressed, mut failed) = (0, 0, 0);
+
+    for sol in year.solutions {
+        if opts.day.is_some_and(|d| d != sol.day) || opts.part.is_some_and(|p| p != sol.part) {
+            continue;
+        }
+        let label = sol.label();
+        let input = year.input_path(sol.day);
+        if !input.exists() {
+            println!("  {label:<36} no input at {}", input.display());
+            continue;
+        }
+
+        let (parse, solve) = match bench_isolated(year, sol, &input, &budgets, None) {
+            Ok(stats) => stats,
+            Err(Outcome::Panic(msg)) => {
+                failed += 1;
+                println!("  {label:<36} {:<8} {msg}", "PANIC");
+                continue;
+            }
+            Err(outcome) => {
+                failed += 1;
+                let msg = match outcome {
+                    Outcome::Error(msg) => msg,
+                    _ => "Exited without timings".to_string(),
+                };
+                println!("  {label:<36} {:<8} {msg}", "ERROR");
+                continue;
+            }
+        };
+        timed += 1;
+
+        let entry = Entry {
+            run,
+            machine: machine.clone(),
+            toolchain: toolchain.clone(),
+            solution: label.clone(),
+            parse,
+            solve,
+        };
+        let note = match history.previous(&machine, &toolchain, &label) {
+            Some(prev) => {
+                let pct = change(prev.median(), entry.median());
+                if pct > opts.threshold {
+                    regressed += 1;
+                    format!("{pct:>+7.1}% REGRESSED (was {:.2?})", prev.median())
+                } else if pct < -opts.threshold {
+                    format!("{pct:>+7.1}% faster (was {:.2?})", prev.median())
+                } else {
+                    format!("{pct:>+7.1}%")
+                }
+            }
+            None => "new".to_string(),
+        };
+        println!(
+            "  {label:<36} parse {:>10.2?} ± {:<10.2?} solve {:>10.2?} ± {:<10.2?} {:>7} runs  {note}",
+            parse.median,
+            parse.stddev,
+            solve.median,
+            solve.stddev,
+            solve.samples
+        );
+
+        if !opts.no_save {
+            if let Err(e) = history.append(entry) {
+                eprintln!("Failed to save to {}: {e}", history_path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    println!(
+        "{timed} timed, {regressed} more than {}% slower than last time, {failed} failed",
+        opts.threshold
+    );
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Time the day's solutions on each of its random inputs, doubling the size until they're too slow
+fn scale(year: &Year, opts: &Opts) -> ExitCode {
+    let budgets = [opts.time, opts.warmup].map(|s| s.to_string());
+    let limit = Duration::from_secs_f64(opts.limit);
+    // Parse and solve each warm up and then get timed, at least once apiece, so a solution that's this far past the
+    // limit isn't going to finish any time soon
+    let timeout = Duration::from_secs_f64(2.0 * (opts.warmup + opts.time) + 10.0 * opts.limit);
+    let dir = std::env::temp_dir().join(format!("aoc-harness-scale-{}", std::process::id()));
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Failed to create {}: {e}", dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "Advent of Code {} on {}, {}, scaling with seed {}",
+        year.year,
+        machine(),
+        toolchain(),
+        opts.seed
+    );
+    let (mut timed, mut timeouts, mut failed) = (0, 0, 0);
+
+    let random_inputs = year
+        .random_inputs
+        .iter()
+        .filter(|r| opts.day.is_none_or(|d| d == r.day));
+    for random in random_inputs {
+        println!("day{} {}:", random.day, random.name);
+
+        // Each solution's (input length, parse + solve) from the last size, until it gets too slow
+        let mut active: Vec<(&Solution, Option<(usize, Duration)>)> = year
+            .solutions
+            .iter()
+            .filter(|s| s.day == random.day && opts.part.is_none_or(|p| p == s.part))
+            .map(|s| (s, None))
+            .collect();
+        let mut last_len = 0;
+        let mut size = 1;
+
+        while !active.is_empty() && size <= opts.max_size {
+            let input = random.generate(opts.seed, size);
+            // Generators stop growing at whatever the puzzle can handle
+            if input.len() <= last_len {
+                println!("  Stopped at size {size}, since its input stopped getting bigger");
+                break;
+            }
+            last_len = input.len();
+
+            let path = dir.join(format!("day{}-{}-{size}.txt", random.day, random.name));
+            if let Err(e) = fs::write(&path, &input) {
+                eprintln!("Failed to write {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+
+            active.retain_mut(|(sol, last)| {
+                let label = sol.label();
+                let (parse, solve) = match bench_isolated(year, sol, &path, &budgets, Some(timeout)) {
+                    Ok(stats) => stats,
+                    Err(Outcome::Timeout(elapsed)) => {
+                        // Too slow is what we're looking for, not a failure
+                        timeouts += 1;
+                        println!("  {label:<36} size {size:>8} {:<8} killed after {elapsed:.2?}", "TIMEOUT");
+                        return false;
+                    }
+                    Err(outcome) => {
+                        failed += 1;
+                        let (status, msg) = match outcome {
+                            Outcome::Panic(msg) => ("PANIC", msg),
+                            Outcome::Error(msg) => ("ERROR", msg),
+                            _ => ("ERROR", "Exited without timings".to_string()),
+                        };
+                        println!("  {label:<36} size {size:>8} {status:<8} {msg}");
+                        return false;
+                    }
+                };
+                timed += 1;
+
+                let now = (input.len(), parse.median + solve.median);
+                let k = match last.and_then(|last| crate::scaling::exponent(last, now)) {
+                    Some(k) => format!("~n^{k:.2}"),
+                    None => String::new(),
+                };
+                println!(
+                    "  {label:<36} size {size:>8} {:>10} bytes  parse {:>10.2?} solve {:>10.2?}  {k}",
+                    input.len(),
+                    parse.median,
+                    solve.median,
+                );
+                *last = Some(now);
+
+                now.1 < limit
+            });
+            size *= 2;
+        }
+    }
+    let _ = fs::remove_dir_all(&dir);
+
+    if timed + timeouts + failed == 0 {
+        eprintln!("No random inputs matched --day and --part. See aoc_harness::scaling.");
+        return ExitCode::FAILURE;
+    }
+    println!("{timed} timed, {timeouts} timed out, {failed} failed");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Benchmark `sol` on `input` in a child process, so they all start from the same clean slate
+fn bench_isolated(
+    year: &Year,
+    sol: &Solution,
+    input: &Path,
+    budgets: &[String],
+    timeout: Option<Duration>,
+) -> Result<(Stats, Stats), Outcome> {
+    let stdout = spawn_one(year, BENCH_ONE, sol, input, budgets, timeout)?;
+    let stats = |marker: &str| {
+        stdout
+            .lines()
+            .rev()
+            .find_map(|l| l.strip_prefix(marker))
+            .and_then(|fields| Stats::from_fields(&mut fields.split('\t')))
+    };
+
+    match (stats(PARSE_MARKER), stats(SOLVE_MARKER)) {
+        (Some(parse), Some(solve)) => Ok((parse, solve)),
+        _ => Err(Outcome::Error("Exited without timings".to_string())),
+    }
+}
+
+/// Call `f` until `budget` runs out, always at least once, and time each call.
+///
+/// Whatever `f` returns is dropped after the clock stops, so that isn't timed.
+fn sample<T>(budget: Duration, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    let mut samples = vec![];
+    let start = Instant::now();
+    loop {
+        let before = Instant::now();
+        let out = black_box(f());
+        samples.push(before.elapsed());
+        drop(out);
+
+        if start.elapsed() >= budget || samples.len() >= MAX_SAMPLES {
+            return samples;
+        }
+    }
+}
+
+/// The child side of [`bench_isolated()`]
+fn bench_one(year: &Year, args: &[String]) -> ExitCode {
+    let (sol, input) = match find_one(year, BENCH_ONE, args) {
+        Ok(found) => found,
+        Err(code) => return code,
+    };
+    year.setup();
+    let [time, warmup] = [4, 5].map(|i| {
+        let secs = args.get(i).and_then(|s| s.parse().ok()).unwrap_or(0.0);
+        Duration::from_secs_f64(secs)
+    });
+    let input = ArcStr::from(&input);
+
+    // Make sure it works at all before timing anything
+    let runner = match (sol.generator)(input.clone()) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Generator failed: {e}");
+            return ExitCode::from(ERROR_EXIT as u8);
+        }
+    };
+    if let Err(e) = runner.try_run() {
+        eprintln!("Solution failed: {e}");
+        return ExitCode::from(ERROR_EXIT as u8);
+    }
+
+    sample(warmup, || (sol.generator)(input.clone()));
+    let parse = Stats::new(&mut sample(time, || (sol.generator)(input.clone())));
+    sample(warmup, || runner.try_run());
+    let solve = Stats::new(&mut sample(time, || runner.try_run()));
+
+    println!("{PARSE_MARKER}{}", parse.to_fields());
+    println!("{SOLVE_MARKER}{}", solve.to_fields());
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(n: u64) -> Duration {
+        Duration::from_micros(n)
+    }
+
+    #[test]
+    fn check_stats() {
+        let stats = Stats::new(&mut [us(4), us(2), us(9), us(5)]);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, us(2));
+        assert_eq!(stats.median, us(4) + us(1) / 2);
+        assert_eq!(stats.mean, us(5));
+        // Population stddev of 2, 4, 5, 9 is sqrt(6.5)
+        assert!((stats.stddev.as_secs_f64() * 1e6 - 6.5f64.sqrt()).abs() < 1e-3);
+
+        assert_eq!(Stats::new(&mut [us(7)]).median, us(7));
+        assert_eq!(Stats::new(&mut []), Stats::default());
+    }
+
+    #[test]
+    fn check_change() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        assert!(close(change(us(100), us(150)), 50.0));
+        assert!(close(change(us(100), us(50)), -50.0));
+        assert_eq!(change(Duration::ZERO, us(50)), 0.0);
+    }
+
+    #[test]
+    fn check_history_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-harness-bench-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let entry = |run, machine: &str, median| Entry {
+            run,
+            machine: machine.to_string(),
+            toolchain: "rustc 1.0.0 release".to_string(),
+            solution: "day7 part1 (grid_view)".to_string(),
+            parse: Stats::new(&mut [us(1), us(3)]),
+            solve: Stats::new(&mut [us(median)]),
+        };
+
+        let mut history = History::load(&path).unwrap();
+        history.append(entry(1, "here", 10)).unwrap();
+        history.append(entry(2, "there", 20)).unwrap();
+        history.append(entry(3, "here", 30)).unwrap();
+
+        let loaded = History::load(&path).unwrap();
+        assert_eq!(loaded.entries, history.entries);
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .starts_with(HISTORY_HEADER));
+
+        let prev = loaded
+            .previous("here", "rustc 1.0.0 release", "day7 part1 (grid_view)")
+            .unwrap();
+        assert_eq!(prev.run, 3);
+        assert_eq!(prev.median(), us(2) + us(30));
+        assert!(loaded
+            .previous("here", "rustc 2.0.0 release", "day7 part1 (grid_view)")
+            .is_none());
+        assert!(loaded
+            .previous("here", "rustc 1.0.0 release", "day7 part2")
+            .is_none());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
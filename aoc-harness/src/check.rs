@@ -0,0 +1,427 @@
+//! Run every solution against the real input, and compare with `answers.txt`
+//!
+//! ```text
+//! $ cargo run --release --bin check                 # Everything
+//! $ cargo run --release --bin check -- --day 7      # Just day 7
+//! $ cargo run --release --bin check -- --input day12-kyle
+//! $ cargo run --release --bin check -- --record     # Save answers for parts that don't have one yet
+//! $ cargo run --release --bin check -- --timeout 60 # Give slow solutions longer than 10s
+//! ```
+//! Every solution runs in its own process, so a panic - even with `panic = "abort"` - only takes out that solution,
+//! and one that's too slow can be killed without waiting on it.
+use clap::Parser;
+
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, ExitCode, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{escape, unescape, Answers};
+use crate::{ArcStr, NamedInput, Solution, Year};
+
+/// Re-run ourselves with this to run a single solution
+const RUN_ONE: &str = "--run-one";
+const ANSWER_MARKER: &str = "@@aoc-harness answer@@ ";
+const TIME_MARKER: &str = "@@aoc-harness time@@ ";
+/// The child exits with this when a solution returns an error, instead of panicking
+pub(crate) const ERROR_EXIT: i32 = 2;
+
+#[derive(Debug, Parser)]
+#[command(about = "Check every solution against answers.txt")]
+struct Opts {
+    /// Only check this day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Only check this input, like `day12-kyle`
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Save answers for parts that don't have one yet, when all of their variants agree
+    #[arg(long)]
+    record: bool,
+
+    /// Seconds to give each solution before killing it, or 0 to wait forever
+    #[arg(short, long, default_value_t = 10.0)]
+    timeout: f64,
+}
+
+/// How running a single solution went
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Answer {
+        answer: String,
+        parse: Duration,
+        solve: Duration,
+    },
+    /// The generator or solution returned an `Err` or `None`
+    Error(String),
+    Panic(String),
+    /// Killed after running this long without finishing
+    Timeout(Duration),
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    ok: usize,
+    wrong: usize,
+    disagree: usize,
+    errors: usize,
+    panics: usize,
+    timeouts: usize,
+    unchecked: usize,
+    no_input: usize,
+}
+
+pub fn main(year: &Year) -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some(RUN_ONE) {
+        return run_one(year, &args[2..]);
+    }
+    let opts = Opts::parse();
+
+    let mut answers = match Answers::load(year.answers_path()) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to load answers: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Advent of Code {}", year.year);
+    let mut tally = Tally::default();
+
+    for day in year.days() {
+        if opts.day.is_some_and(|d| d != day) {
+            continue;
+        }
+
+        let inputs = year.inputs(day);
+        if inputs.is_empty() {
+            println!("  day{day}: no input at {}", year.input_path(day).display());
+            tally.no_input += 1;
+            continue;
+        }
+
+        for input in &inputs {
+            if opts.input.as_ref().is_some_and(|name| *name != input.name) {
+                continue;
+            }
+
+            for part in [1, 2] {
+                let solutions: Vec<&Solution> = year
+                    .solutions
+                    .iter()
+                    .filter(|s| s.day == day && s.part == part)
+                    .collect();
+                if solutions.is_empty() {
+                    continue;
+                }
+
+                let recorded = check_part(
+                    year,
+                    input,
+                    part,
+                    &solutions,
+                    &mut answers,
+                    &mut tally,
+                    &opts,
+                );
+                if recorded {
+                    // Save as we go, so an interrupted run still keeps what it learned
+                    if let Err(e) = answers.save() {
+                        eprintln!("Failed to save answers: {e}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+    }
+
+    let Tally {
+        ok,
+        wrong,
+        disagree,
+        errors,
+        panics,
+        timeouts,
+        unchecked,
+        no_input,
+    } = tally;
+    // run-all.sh adds these up across years, so keep them as "<count> <what>"
+    println!(
+        "{ok} ok, {wrong} wrong, {disagree} disagreeing, {errors} errors, {panics} panicked, {timeouts} timed out, {unchecked} without answers, {no_input} days without input"
+    );
+
+    // Too slow isn't wrong, so timeouts don't fail the check
+    if wrong + disagree + errors + panics > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Run every variant of one part on one input, and print how they did.
+///
+/// With `--record`, saves the answer for inputs that don't have one when all of the variants agree on it, and returns `true`.
+fn check_part(
+    year: &Year,
+    input: &NamedInput,
+    part: u32,
+    solutions: &[&Solution],
+    answers: &mut Answers,
+    tally: &mut Tally,
+    opts: &Opts,
+) -> bool {
+    let timeout = (opts.timeout > 0.0).then(|| Duration::from_secs_f64(opts.timeout));
+    let outcomes: Vec<Outcome> = solutions
+        .iter()
+        .map(|sol| run_isolated(year, sol, &input.path, timeout))
+        .collect();
+    let expected = answers.get(&input.name, part).map(str::to_string);
+
+    let mut distinct: Vec<&str> = vec![];
+    for outcome in &outcomes {
+        if let Outcome::Answer { answer, .. } = outcome {
+            if !distinct.contains(&answer.as_str()) {
+                distinct.push(answer);
+            }
+        }
+    }
+
+    for (sol, outcome) in solutions.iter().zip(&outcomes) {
+        let label = sol.label_on(&input.name);
+        match outcome {
+            Outcome::Answer {
+                answer,
+                parse,
+                solve,
+            } => {
+                let (status, note) = match &expected {
+                    Some(e) if e == answer => {
+                        tally.ok += 1;
+                        ("OK", String::new())
+                    }
+                    Some(e) => {
+                        tally.wrong += 1;
+                        ("WRONG", format!(" (expected {})", escape(e)))
+                    }
+                    None if distinct.len() > 1 => {
+                        tally.disagree += 1;
+                        ("DISAGREE", " (variants disagree)".to_string())
+                    }
+                    None => {
+                        tally.unchecked += 1;
+                        ("NEW", " (no recorded answer)".to_string())
+                    }
+                };
+                println!(
+                    "  {label:<36} {status:<8} {:<20} parse {parse:>10.2?}  solve {solve:>10.2?}{note}",
+                    escape(answer)
+                );
+            }
+            Outcome::Error(msg) => {
+                tally.errors += 1;
+                println!("  {label:<36} {:<8} {msg}", "ERROR");
+            }
+            Outcome::Panic(msg) => {
+                tally.panics += 1;
+                println!("  {label:<36} {:<8} {msg}", "PANIC");
+            }
+            Outcome::Timeout(elapsed) => {
+                tally.timeouts += 1;
+                println!("  {label:<36} {:<8} killed after {elapsed:.2?}", "TIMEOUT");
+            }
+        }
+    }
+
+    let all_answered = outcomes.iter().all(|o| matches!(o, Outcome::Answer { .. }));
+    if opts.record && expected.is_none() && all_answered && distinct.len() == 1 {
+        answers.insert(&input.name, part, distinct[0]);
+        return true;
+    }
+
+    false
+}
+
+/// Re-run ourselves as `<exe> <mode> <day> <part> <name or -> <input> [extra...]`, to run `sol` on its own.
+///
+/// Returns the child's stdout if it exited cleanly, and how it failed if it didn't. If it's still going after
+/// `timeout`, it's killed.
+pub(crate) fn spawn_one(
+    year: &Year,
+    mode: &str,
+    sol: &Solution,
+    input: &Path,
+    extra: &[String],
+    timeout: Option<Duration>,
+) -> Result<String, Outcome> {
+    let exe = std::env::current_exe()
+        .map_err(|e| Outcome::Error(format!("Couldn't find our own exe: {e}")))?;
+    let start = Instant::now();
+    let mut child = Command::new(exe)
+        .arg(mode)
+        .arg(sol.day.to_string())
+        .arg(sol.part.to_string())
+        .arg(sol.name.unwrap_or("-"))
+        .arg(input)
+        .args(extra)
+        .current_dir(year.dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| Outcome::Error(format!("Couldn't start: {e}")))?;
+
+    // Drain the pipes while we wait, so a chatty child can't fill one up and block forever
+    let drain = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut bytes = vec![];
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            String::from_utf8_lossy(&bytes).into_owned()
+        })
+    };
+    let stdout = drain(child.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = drain(child.stderr.take().map(|p| Box::new(p) as _));
+
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {}
+            Err(e) => return Err(Outcome::Error(format!("Couldn't wait on it: {e}"))),
+        }
+        if timeout.is_some_and(|t| start.elapsed() >= t) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Outcome::Timeout(start.elapsed()));
+        }
+        thread::sleep(Duration::from_millis(1));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if !status.success() {
+        return Err(failure(&stderr, status));
+    }
+
+    Ok(stdout)
+}
+
+/// Run `sol` on `input` in a child process, and give up on it after `timeout`
+pub fn run_isolated(
+    year: &Year,
+    sol: &Solution,
+    input: &Path,
+    timeout: Option<Duration>,
+) -> Outcome {
+    let stdout = match spawn_one(year, RUN_ONE, sol, input, &[], timeout) {
+        Ok(stdout) => stdout,
+        Err(outcome) => return outcome,
+    };
+
+    let answer = stdout
+        .lines()
+        .rev()
+        .find_map(|l| l.strip_prefix(ANSWER_MARKER));
+    let times = stdout
+        .lines()
+        .rev()
+        .find_map(|l| l.strip_prefix(TIME_MARKER))
+        .and_then(|t| t.split_once(' '))
+        .and_then(|(p, s)| Some((p.parse().ok()?, s.parse().ok()?)));
+
+    match (answer, times) {
+        (Some(answer), Some((parse, solve))) => Outcome::Answer {
+            answer: unescape(answer),
+            parse: Duration::from_nanos(parse),
+            solve: Duration::from_nanos(solve),
+        },
+        _ => Outcome::Error("Exited without an answer".to_string()),
+    }
+}
+
+fn failure(stderr: &str, status: ExitStatus) -> Outcome {
+    let lines: Vec<&str> = stderr.lines().filter(|l| !l.trim().is_empty()).collect();
+
+    // Looks like "thread 'main' panicked at src/day01.rs:12:5:", then the message
+    if let Some(i) = lines.iter().position(|l| l.contains("panicked at")) {
+        let (_, location) = lines[i].split_once("panicked at ").unwrap();
+        let msg = lines.get(i + 1).copied().unwrap_or_default();
+        return Outcome::Panic(format!("{} {msg}", location.trim_end_matches(':')));
+    }
+
+    let last = lines.last().copied().unwrap_or_default();
+    if status.code() == Some(ERROR_EXIT) {
+        Outcome::Error(last.to_string())
+    } else {
+        Outcome::Panic(format!("{status} {last}"))
+    }
+}
+
+/// The child side of [`spawn_one()`]: find the solution and read the input that `args` ask for
+pub(crate) fn find_one<'y>(
+    year: &'y Year,
+    mode: &str,
+    args: &[String],
+) -> Result<(&'y Solution, String), ExitCode> {
+    let [day, part, name, path, ..] = args else {
+        eprintln!("Expected {mode} <day> <part> <name or -> <input>, but found {args:?}");
+        return Err(ExitCode::from(ERROR_EXIT as u8));
+    };
+    let name = (name != "-").then_some(name.as_str());
+    let Some(sol) = year
+        .solutions
+        .iter()
+        .find(|s| s.day.to_string() == *day && s.part.to_string() == *part && s.name == name)
+    else {
+        eprintln!("No solution for day{day} part{part} {name:?}");
+        return Err(ExitCode::from(ERROR_EXIT as u8));
+    };
+    match fs::read_to_string(path) {
+        Ok(input) => Ok((sol, input)),
+        Err(e) => {
+            eprintln!("Failed to read {path:?}: {e}");
+            Err(ExitCode::from(ERROR_EXIT as u8))
+        }
+    }
+}
+
+/// The child side of [`run_isolated()`]
+fn run_one(year: &Year, args: &[String]) -> ExitCode {
+    let (sol, input) = match find_one(year, RUN_ONE, args) {
+        Ok(found) => found,
+        Err(code) => return code,
+    };
+    year.setup();
+
+    let start = Instant::now();
+    let runner = match (sol.generator)(ArcStr::from(&input)) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("Generator failed: {e}");
+            return ExitCode::from(ERROR_EXIT as u8);
+        }
+    };
+    let parsed = Instant::now();
+    let answer = match runner.try_run() {
+        Ok(answer) => answer.to_string(),
+        Err(e) => {
+            eprintln!("Solution failed: {e}");
+            return ExitCode::from(ERROR_EXIT as u8);
+        }
+    };
+    let solved = Instant::now();
+
+    println!(
+        "{TIME_MARKER}{} {}",
+        (parsed - start).as_nanos(),
+        (solved - parsed).as_nanos()
+    );
+    println!("{ANSWER_MARKER}{}", escape(&answer));
+
+    ExitCode::SUCCESS
+}
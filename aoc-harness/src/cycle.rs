@@ -0,0 +1,199 @@
+//! Finding where a simulation starts repeating itself, so we can skip ahead to step 1,000,000,000 without running it
+//!
+//! This lives here rather than in a year's crate since every year has a puzzle like this.
+//!
+//! ```
+//! use aoc_harness::cycle::{find_cycle, nth_state, Cycle};
+//!
+//! // Doubling the last digit goes 1, 2, 4, 8, 6, and then back to 2
+//! let double = |&x: &u32| x * 2 % 10;
+//! assert_eq!(find_cycle(1, double), Cycle { start: 1, period: 4 });
+//!
+//! // Just the state at some far off step
+//! assert_eq!(nth_state(1, double, 1_000_000_000), 6);
+//! ```
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash, RandomState};
+
+/// Where a sequence of states starts repeating
+///
+/// Steps `start..` go around a loop of `period` states forever, so step `start + period` is the same as step `start`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// How many steps happen before the first state that's on the loop
+    pub start: usize,
+    /// How many steps it takes to get back around the loop
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step that has the same state as step `n`
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle by remembering every state, which steps through each one once
+///
+/// This is the one to use when stepping is expensive. States are kept around until it's done, so they shouldn't be
+/// huge: see [`find_cycle_brent()`] for that.
+pub fn find_cycle<S: Hash + Eq>(start: S, step: impl FnMut(&S) -> S) -> Cycle {
+    let (_states, cycle) = run_until_cycle(start, step, usize::MAX);
+    cycle.expect("Ran usize::MAX steps without repeating")
+}
+
+/// The state after `n` steps, skipping ahead as soon as it starts repeating
+pub fn nth_state<S: Hash + Eq>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    let (mut states, cycle) = run_until_cycle(start, step, n);
+    let i = match cycle {
+        Some(cycle) => cycle.equivalent_step(n),
+        None => n,
+    };
+    states.swap_remove(i)
+}
+
+/// Steps until a state repeats or we've seen step `limit`, and returns every state it saw in order
+fn run_until_cycle<S: Hash + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> (Vec<S>, Option<Cycle>) {
+    // States only live in `states`, and `seen` goes from their hashes to which steps had them
+    let hasher = RandomState::new();
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut states = vec![start];
+
+    loop {
+        let i = states.len() - 1;
+        let curr = &states[i];
+
+        let same_hash = seen.entry(hasher.hash_one(curr)).or_default();
+        if let Some(&prev) = same_hash.iter().find(|&&j| states[j] == *curr) {
+            let cycle = Cycle {
+                start: prev,
+                period: i - prev,
+            };
+            return (states, Some(cycle));
+        }
+        same_hash.push(i);
+
+        if i >= limit {
+            return (states, None);
+        }
+
+        let next = step(curr);
+        states.push(next);
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which only needs to remember two states
+///
+/// This steps through about 3 times as many states as [`find_cycle()`], but doesn't need them to be `Hash`, and
+/// doesn't run out of memory on big ones.
+pub fn find_cycle_brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the period: the hare runs ahead in stretches that double in length, and the tortoise waits at the start of
+    // the current stretch. Once the stretch is longer than the loop, the hare laps the tortoise inside of it.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find where it starts: with the hare a whole period ahead, they meet on the first state that's on the loop
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+    use rstest::*;
+
+    /// 2017 day 6's memory banks, which take 5 steps to repeat and then go around a loop of 4
+    #[allow(clippy::ptr_arg)]
+    fn redistribute(banks: &Vec<u32>) -> Vec<u32> {
+        let mut banks = banks.clone();
+        let n = banks.len();
+        let (i, _) = banks
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, b)| b)
+            .unwrap();
+
+        let blocks = std::mem::take(&mut banks[i]);
+        for j in 1..=blocks as usize {
+            banks[(i + j) % n] += 1;
+        }
+        banks
+    }
+
+    #[rstest]
+    #[case::banks(Cycle { start: 1, period: 4 }, vec![0, 2, 7, 0], redistribute)]
+    #[case::rho(Cycle { start: 2, period: 3 }, vec![0], |s: &Vec<u32>| vec![[1, 2, 3, 4, 2][s[0] as usize]])]
+    #[case::fixed_point(Cycle { start: 0, period: 1 }, vec![7], |s: &Vec<u32>| s.clone())]
+    #[trace]
+    fn check_find_cycle(
+        #[case] expected: Cycle,
+        #[case] start: Vec<u32>,
+        #[notrace]
+        #[case]
+        step: fn(&Vec<u32>) -> Vec<u32>,
+    ) {
+        assert_eq!(find_cycle(start.clone(), step), expected);
+        assert_eq!(find_cycle_brent(start, step), expected);
+    }
+
+    #[test]
+    fn check_nth_state() {
+        let step = |&x: &u64| (x * x + 1) % 255;
+        let mut slow = 3;
+        for n in 0..1_000 {
+            assert_eq!(nth_state(3, step, n), slow, "step {n}");
+            slow = step(&slow);
+        }
+
+        assert_eq!(
+            nth_state(vec![0, 2, 7, 0], redistribute, 1_000_000_000),
+            nth_state(vec![0, 2, 7, 0], redistribute, 1 + (1_000_000_000 - 1) % 4),
+        );
+    }
+
+    #[test]
+    fn check_equivalent_step() {
+        let cycle = Cycle {
+            start: 3,
+            period: 4,
+        };
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(3), 3);
+        assert_eq!(cycle.equivalent_step(7), 3);
+        assert_eq!(cycle.equivalent_step(10), 6);
+    }
+}
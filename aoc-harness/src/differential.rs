@@ -0,0 +1,456 @@
+//! Check that every variant of a solution agrees, on lots of random inputs
+//!
+//! Days with several variants only get compared on the example and my input, which is how a fast path ends up
+//! disagreeing with the slow one on the next input. Instead, describe the input's shape with a generator, and every
+//! variant is run on inputs that get bigger with each case:
+//! ```
+//! use aoc_harness::differential::{Differential, Rng, StdRng};
+//!
+//! fn part1(input: &str) -> i64 {
+//!     input.lines().map(|line| line.parse::<i64>().unwrap()).sum()
+//! }
+//!
+//! fn part1_fast(input: &str) -> i64 {
+//!     input.split('\n').filter_map(|n| n.parse::<i64>().ok()).sum()
+//! }
+//!
+//! fn random_numbers(rng: &mut StdRng, size: usize) -> Vec<i32> {
+//!     (0..=size).map(|_| rng.gen_range(-100..=100)).collect()
+//! }
+//!
+//! fn render_numbers(numbers: &Vec<i32>) -> String {
+//!     numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join("\n")
+//! }
+//!
+//! Differential::new(random_numbers).check_text(
+//!     render_numbers,
+//!     &[("part1", part1), ("part1_fast", part1_fast)],
+//! );
+//! ```
+//! The generator builds a value that implements [`Shrink`], rather than text, so that when the variants disagree it
+//! can be made smaller until it's a counterexample worth reading. Anything built from `Vec`s, tuples, and integers
+//! shrinks by itself, and rendering it to text can clamp or fix up whatever needs to stay valid.
+//!
+//! Every run tries the same cases, so a test that passes keeps passing. Set `AOC_SEED` to another seed to try
+//! different ones, or to `random` for a new seed every run. Failures print the seed they used, to run them again.
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+pub use rand::rngs::StdRng;
+pub use rand::seq::SliceRandom;
+pub use rand::{Rng, SeedableRng};
+
+/// Used unless `AOC_SEED` says otherwise
+const DEFAULT_SEED: u64 = 2015;
+
+/// Stops shrinking eventually, even if every step only takes off a little
+const MAX_SHRINKS: usize = 10_000;
+
+/// Smaller versions of a value, to look for a simpler input that still fails
+pub trait Shrink: Clone {
+    /// Candidates to try, roughly from most to least simplified. Shouldn't include `self`.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_int {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                // Toward 0: all the way, then halfway, then by 1
+                let x = *self;
+                let toward = match x {
+                    0 => 0,
+                    _ if x > 0 => x - 1,
+                    _ => x + 1,
+                };
+                let mut out = vec![];
+                for smaller in [0, x / 2, toward] {
+                    if smaller != x && !out.contains(&smaller) {
+                        out.push(smaller);
+                    }
+                }
+                out
+            }
+        }
+    )*};
+}
+
+impl_shrink_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = vec![];
+
+        // Lose half of it, then one element, then shrink an element
+        if self.len() > 1 {
+            let mid = self.len() / 2;
+            out.push(self[..mid].to_vec());
+            out.push(self[mid..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut smaller = self.clone();
+            smaller.remove(i);
+            out.push(smaller);
+        }
+        for (i, x) in self.iter().enumerate() {
+            for x in x.shrink() {
+                let mut smaller = self.clone();
+                smaller[i] = x;
+                out.push(smaller);
+            }
+        }
+
+        out
+    }
+}
+
+impl<T: Shrink, const N: usize> Shrink for [T; N] {
+    fn shrink(&self) -> Vec<Self> {
+        let mut out = vec![];
+        for (i, x) in self.iter().enumerate() {
+            for x in x.shrink() {
+                let mut smaller = self.clone();
+                smaller[i] = x;
+                out.push(smaller);
+            }
+        }
+
+        out
+    }
+}
+
+macro_rules! impl_shrink_tuple {
+    ($($name:ident: $i:tt),*) => {
+        impl<$($name: Shrink),*> Shrink for ($($name,)*) {
+            fn shrink(&self) -> Vec<Self> {
+                let mut out = vec![];
+                $(
+                    for x in self.$i.shrink() {
+                        let mut smaller = self.clone();
+                        smaller.$i = x;
+                        out.push(smaller);
+                    }
+                )*
+                out
+            }
+        }
+    };
+}
+
+impl_shrink_tuple!(A: 0);
+impl_shrink_tuple!(A: 0, B: 1);
+impl_shrink_tuple!(A: 0, B: 1, C: 2);
+impl_shrink_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+/// What one variant did with an input
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Outcome<R> {
+    Answer(R),
+    Panic(String),
+}
+
+/// A variant that can be compared with the others, named for the failure message
+pub type Variant<'a, T, R> = (&'a str, &'a dyn Fn(&T) -> R);
+
+/// A variant that takes puzzle text, like `#[aoc]` functions do
+pub type TextVariant<'a, R> = (&'a str, fn(&str) -> R);
+
+/// The seed to use for an `AOC_SEED` of `var`: a number, `random`, or unset for the default
+fn seed_from(var: Option<&str>) -> u64 {
+    match var {
+        None => DEFAULT_SEED,
+        Some("random") => rand::random(),
+        Some(seed) => seed
+            .parse()
+            .unwrap_or_else(|e| panic!("AOC_SEED={seed:?} isn't a u64 or \"random\": {e}")),
+    }
+}
+
+type Generate<T> = Box<dyn Fn(&mut StdRng, usize) -> T>;
+type Show<T> = Box<dyn Fn(&T) -> String>;
+
+/// Random inputs from a generator, for comparing variants on. See the [module docs](self).
+pub struct Differential<T> {
+    generate: Generate<T>,
+    show: Option<Show<T>>,
+    cases: usize,
+    max_size: usize,
+    seed: u64,
+}
+
+impl<T: Shrink + Debug + 'static> Differential<T> {
+    /// `generate` makes an input from an rng and a size, which goes from 1 up to [`Differential::max_size()`]
+    pub fn new(generate: impl Fn(&mut StdRng, usize) -> T + 'static) -> Self {
+        Self {
+            generate: Box::new(generate),
+            show: None,
+            cases: 100,
+            max_size: 100,
+            seed: seed_from(std::env::var("AOC_SEED").ok().as_deref()),
+        }
+    }
+
+    /// How many inputs to try. Defaults to 100.
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// The size passed to the generator for the last case. Defaults to 100.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Run these cases instead of `AOC_SEED`'s, or the default seed's
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// How to print a counterexample, instead of its `Debug`
+    pub fn show_with(mut self, show: impl Fn(&T) -> String + 'static) -> Self {
+        self.show = Some(Box::new(show));
+        self
+    }
+
+    fn show(&self, input: &T) -> String {
+        match &self.show {
+            Some(show) => show(input),
+            None => format!("{input:#?}"),
+        }
+    }
+
+    /// For variants that take puzzle text, like `#[aoc]` functions do
+    #[track_caller]
+    pub fn check_text<R: PartialEq + Debug>(
+        self,
+        render: fn(&T) -> String,
+        variants: &[TextVariant<R>],
+    ) {
+        let wrapped: Vec<_> = variants
+            .iter()
+            .map(|&(_, f)| move |t: &T| f(&render(t)))
+            .collect();
+        let variants: Vec<Variant<T, R>> = variants
+            .iter()
+            .zip(&wrapped)
+            .map(|(&(name, _), f)| (name, f as &dyn Fn(&T) -> R))
+            .collect();
+
+        let this = match self.show {
+            Some(_) => self,
+            None => self.show_with(render),
+        };
+        this.check(&variants);
+    }
+
+    /// Panics with the smallest input that the variants disagree on, if there is one
+    #[track_caller]
+    pub fn check<R: PartialEq + Debug>(self, variants: &[Variant<T, R>]) {
+        assert!(variants.len() > 1, "Need at least two variants to compare");
+
+        for case in 0..self.cases {
+            let size =
+                1 + case * self.max_size.saturating_sub(1) / self.cases.max(2).saturating_sub(1);
+            let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(case as u64));
+            let input = (self.generate)(&mut rng, size);
+
+            match agree(variants, &input) {
+                Ok(Outcome::Answer(_)) => continue,
+                // Panicking the same way is fine for a shrunk input, but not for one straight from the generator
+                Ok(Outcome::Panic(e)) => panic!(
+                    "Every variant panicked on case {case} (size {size}) of AOC_SEED={}, so is the generator right?\n{}\n{e}",
+                    self.seed,
+                    self.show(&input)
+                ),
+                Err(_) => {}
+            }
+            let (input, shrinks) = shrink(variants, input);
+            let outcomes = agree(variants, &input).unwrap_err();
+
+            let shown = self.show(&input);
+            let mut msg = format!(
+                "Variants disagree on case {case} (size {size}) of AOC_SEED={}, shrunk {shrinks} times to:\n{shown}\n",
+                self.seed
+            );
+            for ((name, _), outcome) in variants.iter().zip(outcomes) {
+                match outcome {
+                    Outcome::Answer(answer) => msg += &format!("  {name}: {answer:?}\n"),
+                    Outcome::Panic(e) => msg += &format!("  {name}: panicked: {e}\n"),
+                }
+            }
+            panic!("{msg}");
+        }
+    }
+}
+
+/// Keep taking the first smaller input that still fails, until none do
+fn shrink<T: Shrink, R: PartialEq>(variants: &[Variant<T, R>], mut input: T) -> (T, usize) {
+    let mut shrinks = 0;
+    'outer: while shrinks < MAX_SHRINKS {
+        for smaller in input.shrink() {
+            if agree(variants, &smaller).is_err() {
+                input = smaller;
+                shrinks += 1;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+
+    (input, shrinks)
+}
+
+/// What every variant did, or each variant's outcome if they aren't all the same. Panicking the same way counts as
+/// agreeing.
+fn agree<T, R: PartialEq>(
+    variants: &[Variant<T, R>],
+    input: &T,
+) -> Result<Outcome<R>, Vec<Outcome<R>>> {
+    let mut outcomes: Vec<Outcome<R>> = variants
+        .iter()
+        .map(|(_, f)| run_quietly(|| f(input)))
+        .collect();
+
+    if outcomes.windows(2).all(|w| w[0] == w[1]) {
+        Ok(outcomes.swap_remove(0))
+    } else {
+        Err(outcomes)
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Catch a panic without printing it, since shrinking can cause hundreds of them
+fn run_quietly<R>(f: impl FnOnce() -> R) -> Outcome<R> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                default(info);
+            }
+        }));
+    });
+
+    QUIET.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(false);
+
+    match result {
+        Ok(answer) => Outcome::Answer(answer),
+        Err(e) => Outcome::Panic(panic_message(&*e)),
+    }
+}
+
+/// What a caught panic said
+pub(crate) fn panic_message(e: &(dyn Any + Send)) -> String {
+    e.downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| e.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "(not a string)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_shrink_ints() {
+        assert_eq!(10_i32.shrink(), [0, 5, 9]);
+        assert_eq!((-3_i64).shrink(), [0, -1, -2]);
+        assert_eq!(1_u8.shrink(), [0]);
+        assert!(0_u32.shrink().is_empty());
+    }
+
+    #[test]
+    fn check_seed_from() {
+        assert_eq!(seed_from(None), DEFAULT_SEED);
+        assert_eq!(seed_from(Some("7")), 7);
+        assert_ne!(seed_from(Some("random")), seed_from(Some("random")));
+    }
+
+    #[test]
+    fn check_agreeing_variants() {
+        let sum = |xs: &Vec<i64>| xs.iter().sum::<i64>();
+        let pairs = |xs: &Vec<i64>| xs.chunks(2).map(|c| c.iter().sum::<i64>()).sum();
+
+        Differential::new(|rng, size| (0..size).map(|_| rng.gen_range(-100..100)).collect())
+            .seed(7)
+            .check(&[("sum", &sum), ("pairs", &pairs)]);
+    }
+
+    #[test]
+    fn check_shrinks_to_counterexample() {
+        // Wrong once there's an element over 50, so the smallest counterexample is [51]
+        let max = |xs: &Vec<u32>| xs.iter().copied().max().unwrap_or(0);
+        let max_buggy = |xs: &Vec<u32>| xs.iter().copied().filter(|&x| x <= 50).max().unwrap_or(0);
+
+        let failed = panic::catch_unwind(|| {
+            Differential::new(|rng, size| (0..size).map(|_| rng.gen_range(0..1000)).collect())
+                .seed(7)
+                .check(&[("max", &max), ("max_buggy", &max_buggy)]);
+        })
+        .unwrap_err();
+        let msg = failed.downcast_ref::<String>().unwrap();
+
+        assert!(msg.contains("AOC_SEED=7"), "{msg}");
+        assert!(msg.contains("[\n    51,\n]"), "{msg}");
+        assert!(msg.contains("max: 51\n  max_buggy: 0\n"), "{msg}");
+    }
+
+    #[test]
+    fn check_panics_are_outcomes() {
+        fn checked(x: &u8) -> u8 {
+            x.checked_mul(2).unwrap_or(0)
+        }
+        fn overflows(x: &u8) -> u8 {
+            assert!(*x < 128, "overflow");
+            x * 2
+        }
+
+        let failed = panic::catch_unwind(|| {
+            Differential::new(|rng, _| rng.gen())
+                .seed(1)
+                .check(&[("checked", &checked), ("overflows", &overflows)]);
+        })
+        .unwrap_err();
+        let msg = failed.downcast_ref::<String>().unwrap();
+
+        assert!(msg.contains("shrunk"), "{msg}");
+        assert!(msg.contains("\n128\n"), "{msg}");
+        assert!(msg.contains("overflows: panicked: overflow"), "{msg}");
+    }
+
+    #[test]
+    fn check_invalid_generator() {
+        let first = |xs: &Vec<u8>| *xs.first().expect("empty");
+        let last = |xs: &Vec<u8>| *xs.last().expect("empty");
+
+        let failed = panic::catch_unwind(|| {
+            Differential::new(|_, _| vec![])
+                .seed(3)
+                .check(&[("first", &first), ("last", &last)]);
+        })
+        .unwrap_err();
+        let msg = failed.downcast_ref::<String>().unwrap();
+
+        assert!(msg.contains("Every variant panicked on case 0"), "{msg}");
+    }
+}
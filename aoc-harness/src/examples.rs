@@ -0,0 +1,79 @@
+//! Example inputs for tests, as files instead of strings pasted into each day
+//!
+//! Examples live in `input/<year>/examples/`, named like `day7-given.txt` or `day7-smol.txt`, next to an `answers.txt`
+//! that's in the same format as the year's real one:
+//! ```text
+//! day7-given part1: 21
+//! day7-given part2: 40
+//! ```
+//! Each day's tests get a case per file from rstest's `#[files("input/2025/examples/day7-*.txt")]`, and pass each
+//! path to [`check()`], so adding an example doesn't need any Rust. 2025's day 7 is one to copy from.
+//! An example that's only for one part is left out of the other part's test with `#[exclude("day7-smol2")]` after
+//! the `#[files]`, since a missing answer fails the test. `#[files]` looks for examples when the tests are compiled,
+//! so each year's `build.rs` asks for a rebuild whenever the folder changes.
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+use crate::answers::Answers;
+
+/// Run `solution` on the example at `path`, and compare with its answer for `part` in `answers.txt`.
+///
+/// The input is passed along like the real one is: without its trailing newline, but with any leading whitespace.
+/// Panics if the example has no answer for `part`, so a typo in `answers.txt` can't quietly pass.
+#[track_caller]
+pub fn check<T: Display>(path: &Path, part: u32, solution: impl FnOnce(&str) -> T) {
+    let name = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_else(|| panic!("{} isn't named like dayN-something.txt", path.display()));
+    let manifest = path.with_file_name("answers.txt");
+    let answers = Answers::load(&manifest)
+        .unwrap_or_else(|e| panic!("Couldn't load {}: {e}", manifest.display()));
+
+    let expected = answers.get(name, part).unwrap_or_else(|| {
+        panic!(
+            "{name} has no part{part} answer in {}. Add one, or #[exclude] it from this part's test",
+            manifest.display()
+        )
+    });
+    let input = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Couldn't read {}: {e}", path.display()));
+
+    let answer = solution(input.trim_end_matches('\n')).to_string();
+    assert_eq!(answer, expected, "{name} part{part}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc-harness-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("answers.txt"),
+            "day3-given part1: 2\nday3-given part2: #.\\n.#\n",
+        )
+        .unwrap();
+        fs::write(dir.join("day3-given.txt"), "  ragged\ngrid\n\n").unwrap();
+
+        let path = dir.join("day3-given.txt");
+        check(&path, 1, |input| {
+            // Leading whitespace is kept, but not the trailing newlines
+            assert_eq!(input, "  ragged\ngrid");
+            input.lines().count()
+        });
+        check(&path, 2, |_| "#.\n.#");
+
+        let wrong = std::panic::catch_unwind(|| check(&path, 1, |_| 3));
+        assert!(wrong.is_err());
+
+        fs::write(dir.join("day3-smol.txt"), "#").unwrap();
+        let unanswered = std::panic::catch_unwind(|| check(&dir.join("day3-smol.txt"), 1, |_| 1));
+        assert!(unanswered.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,387 @@
+//! Turning names into small dense ids, so everything else can be a `Vec` instead of a `HashMap`
+//!
+//! ```
+//! use aoc_harness::interner::{IdVec, Interner, Symbol};
+//!
+//! let mut names = Interner::new();
+//! let mut weights: IdVec<Symbol, i64> = IdVec::new();
+//! for (name, weight) in [("pbga", 66), ("xhth", 57)] {
+//!     let id = names.intern(name);
+//!     weights.insert(id, weight);
+//! }
+//! let id = names.get("xhth").unwrap();
+//! assert_eq!((names.resolve(id), weights[id]), ("xhth", 57));
+//! ```
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+/// Something that's really just an index, so it can index an [`IdVec`]
+pub trait Id: Copy + Eq + Hash + Debug {
+    fn from_index(i: usize) -> Self;
+    fn index(self) -> usize;
+}
+
+/// What an [`Interner`] or a [`FixedInterner`] hands out for each name: the order it was first seen in
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(pub u32);
+
+impl Id for Symbol {
+    fn from_index(i: usize) -> Self {
+        Self(u32::try_from(i).expect("More than u32::MAX symbols"))
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({})", self.0)
+    }
+}
+
+/// Hands out a [`Symbol`] for every different name it sees, borrowing the names instead of copying them
+///
+/// Looking a name up is a hash, and looking a symbol up is an index.
+#[derive(Clone, Debug, Default)]
+pub struct Interner<'a> {
+    ids: HashMap<&'a [u8], Symbol>,
+    names: Vec<&'a [u8]>,
+}
+
+impl<'a> Interner<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: HashMap::with_capacity(capacity),
+            names: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The symbol for `name`, which is a new one if we haven't seen it before
+    pub fn intern(&mut self, name: &'a (impl AsRef<[u8]> + ?Sized)) -> Symbol {
+        let name = name.as_ref();
+        let next = Symbol::from_index(self.names.len());
+        *self.ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            next
+        })
+    }
+
+    /// The symbol for `name`, if we've seen it before
+    pub fn get(&self, name: &(impl AsRef<[u8]> + ?Sized)) -> Option<Symbol> {
+        self.ids.get(name.as_ref()).copied()
+    }
+
+    /// The name that `id` was handed out for
+    #[track_caller]
+    pub fn resolve_bytes(&self, id: Symbol) -> &'a [u8] {
+        self.names[id.index()]
+    }
+
+    /// The name that `id` was handed out for, which must be UTF-8
+    #[track_caller]
+    pub fn resolve(&self, id: Symbol) -> &'a str {
+        let name = self.resolve_bytes(id);
+        std::str::from_utf8(name).unwrap_or_else(|_| panic!("{id} isn't UTF-8: {name:?}"))
+    }
+
+    /// Every symbol handed out so far, in order
+    pub fn ids(&self) -> impl ExactSizeIterator<Item = Symbol> {
+        (0..self.len()).map(Symbol::from_index)
+    }
+}
+
+/// An [`Interner`] for names that are always `W` letters or digits, like `"AA"` or `"11Z"`
+///
+/// Names are read as base 36 numbers, which is a perfect hash into a table of `36^W` slots, so nothing is ever hashed
+/// or compared. Letters are case-insensitive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedInterner<const W: usize> {
+    /// Indexed by a name's base 36 value, and holding its symbol plus one (so `0` means none)
+    table: Vec<u32>,
+    names: Vec<[u8; W]>,
+}
+
+impl<const W: usize> Default for FixedInterner<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize> FixedInterner<W> {
+    pub fn new() -> Self {
+        Self {
+            table: vec![0; 36_usize.pow(W as u32)],
+            names: vec![],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    #[track_caller]
+    fn slot(name: &[u8]) -> usize {
+        assert_eq!(
+            name.len(),
+            W,
+            "{:?} should be {W} bytes long",
+            String::from_utf8_lossy(name)
+        );
+
+        name.iter().fold(0, |slot, &c| {
+            let digit = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'z' => c - b'a' + 10,
+                b'A'..=b'Z' => c - b'A' + 10,
+                _ => panic!(
+                    "{:?} should only have letters and digits",
+                    String::from_utf8_lossy(name)
+                ),
+            };
+            36 * slot + digit as usize
+        })
+    }
+
+    /// The symbol for `name`, which is a new one if we haven't seen it before
+    #[track_caller]
+    pub fn intern(&mut self, name: &(impl AsRef<[u8]> + ?Sized)) -> Symbol {
+        let name = name.as_ref();
+        let slot = Self::slot(name);
+        if self.table[slot] == 0 {
+            self.names.push(name.try_into().unwrap());
+            self.table[slot] = self.names.len() as u32;
+        }
+        Symbol(self.table[slot] - 1)
+    }
+
+    /// The symbol for `name`, if we've seen it before
+    #[track_caller]
+    pub fn get(&self, name: &(impl AsRef<[u8]> + ?Sized)) -> Option<Symbol> {
+        let id = self.table[Self::slot(name.as_ref())];
+        id.checked_sub(1).map(Symbol)
+    }
+
+    /// The name that `id` was handed out for, as it was first seen
+    #[track_caller]
+    pub fn resolve(&self, id: Symbol) -> &str {
+        // Everything in here was checked to be ASCII when it was interned
+        std::str::from_utf8(&self.names[id.index()]).unwrap()
+    }
+
+    /// Every symbol handed out so far, in order
+    pub fn ids(&self) -> impl ExactSizeIterator<Item = Symbol> {
+        (0..self.len()).map(Symbol::from_index)
+    }
+}
+
+/// A `Vec` that's indexed by ids instead of `usize`s, so they can't be mixed up with other numbers
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IdVec<I, T> {
+    items: Vec<T>,
+    _id: PhantomData<fn(I) -> I>,
+}
+
+impl<I: Id, T: Debug> Debug for IdVec<I, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<I, T> Default for IdVec<I, T> {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            _id: PhantomData,
+        }
+    }
+}
+
+impl<I: Id, T> IdVec<I, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `len` copies of `value`, for every id below `len`
+    pub fn from_elem(value: T, len: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            items: vec![value; len],
+            _id: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Adds `value` at the next id, and returns it
+    pub fn push(&mut self, value: T) -> I {
+        let id = I::from_index(self.items.len());
+        self.items.push(value);
+        id
+    }
+
+    pub fn get(&self, id: I) -> Option<&T> {
+        self.items.get(id.index())
+    }
+
+    pub fn get_mut(&mut self, id: I) -> Option<&mut T> {
+        self.items.get_mut(id.index())
+    }
+
+    /// Sets the value for `id`, filling any ids before it with defaults
+    pub fn insert(&mut self, id: I, value: T)
+    where
+        T: Default,
+    {
+        if id.index() >= self.items.len() {
+            self.items.resize_with(id.index() + 1, T::default);
+        }
+        self.items[id.index()] = value;
+    }
+
+    /// Every id that has a value, in order
+    pub fn ids(&self) -> impl ExactSizeIterator<Item = I> {
+        (0..self.items.len()).map(I::from_index)
+    }
+
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.items.iter_mut()
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (I, &T)> {
+        self.items
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (I::from_index(i), t))
+    }
+}
+
+impl<I: Id, T> Index<I> for IdVec<I, T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, id: I) -> &T {
+        &self.items[id.index()]
+    }
+}
+
+impl<I: Id, T> IndexMut<I> for IdVec<I, T> {
+    #[track_caller]
+    fn index_mut(&mut self, id: I) -> &mut T {
+        &mut self.items[id.index()]
+    }
+}
+
+impl<I, T> FromIterator<T> for IdVec<I, T> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+            _id: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[allow(unused_imports)]
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn check_interner() {
+        let mut names = Interner::new();
+        assert!(names.is_empty());
+
+        let a = names.intern("pbga");
+        let b = names.intern(b"xhth");
+        assert_eq!(names.intern("pbga"), a);
+        assert_ne!(a, b);
+
+        assert_eq!(names.len(), 2);
+        assert_eq!(names.get("xhth"), Some(b));
+        assert_eq!(names.get("nope"), None);
+        assert_eq!(names.resolve(a), "pbga");
+        assert_eq!(names.resolve_bytes(b), b"xhth");
+        assert_eq!(names.ids().collect::<Vec<_>>(), [Symbol(0), Symbol(1)]);
+    }
+
+    #[test]
+    fn check_fixed_interner() {
+        let mut names: FixedInterner<3> = FixedInterner::new();
+
+        let aaa = names.intern("AAA");
+        let zzz = names.intern("ZZZ");
+        let digits = names.intern("11z");
+        assert_eq!([aaa, zzz, digits], [Symbol(0), Symbol(1), Symbol(2)]);
+
+        assert_eq!(names.intern("AAA"), aaa);
+        assert_eq!(names.get("zzz"), Some(zzz));
+        assert_eq!(names.get("BBB"), None);
+        assert_eq!(names.resolve(digits), "11z");
+        assert_eq!(names.len(), 3);
+    }
+
+    #[test]
+    #[should_panic = "should be 2 bytes long"]
+    fn check_fixed_interner_wrong_width() {
+        FixedInterner::<2>::new().intern("AAA");
+    }
+
+    #[test]
+    fn check_id_vec() {
+        let mut names = Interner::new();
+        let mut weights: IdVec<Symbol, i64> = IdVec::new();
+
+        weights.insert(names.intern("a"), 10);
+        let c = names.intern("c");
+        weights.insert(names.intern("b"), 20);
+
+        assert_eq!(weights.len(), 3);
+        assert_eq!(weights[c], 0);
+        weights[c] += 5;
+        assert_eq!(weights.values().sum::<i64>(), 35);
+        assert_eq!(
+            weights
+                .iter()
+                .map(|(id, &w)| (names.resolve(id), w))
+                .collect::<Vec<_>>(),
+            [("a", 10), ("c", 5), ("b", 20)]
+        );
+
+        let id = weights.push(7);
+        assert_eq!(id, Symbol(3));
+        assert_eq!(weights.get(id), Some(&7));
+    }
+}
@@ -0,0 +1,199 @@
+//! Shared tooling for running every year's solutions, without going through `cargo aoc`.
+//!
+//! Each year's `main.rs` just hands `registry!(aoc25, setup = [aoc25::init_logging])` to [`run::main()`], and its
+//! `check` and `bench` binaries are the same, but with [`check::main()`] and [`bench::main()`].
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+pub use aoc_harness_derive::registry;
+pub use aoc_runner::{ArcStr, Runner};
+
+pub mod answers;
+pub mod bench;
+pub mod check;
+pub mod cycle;
+pub mod differential;
+pub mod examples;
+pub mod interner;
+pub mod run;
+pub mod scaffold;
+pub mod scaling;
+pub mod status;
+#[cfg(feature = "viz")]
+pub mod viz;
+
+/// What `aoc_lib!` generates for each solution: parse the input, and hand back something to run
+pub type Generator = fn(ArcStr) -> Result<Box<dyn Runner>, Box<dyn Error>>;
+
+/// One `#[aoc(dayN, partM)]` or `#[aoc(dayN, partM, name)]` function
+pub struct Solution {
+    pub day: u32,
+    pub part: u32,
+    /// The variant name, if there is one
+    pub name: Option<&'static str>,
+    pub generator: Generator,
+}
+
+impl Solution {
+    /// e.g. `day7 part1 (grid_view)`
+    pub fn label(&self) -> String {
+        self.label_on(&format!("day{}", self.day))
+    }
+
+    /// e.g. `day12-kyle part1 (grid_view)`
+    pub fn label_on(&self, input: &str) -> String {
+        match self.name {
+            Some(name) => format!("{input} part{} ({name})", self.part),
+            None => format!("{input} part{}", self.part),
+        }
+    }
+}
+
+/// A puzzle input, named after its file in `input/<year>/`
+///
+/// `day12.txt` is mine, and anything else that starts with the day is another input for that day, like `day12-kyle.txt`
+/// for someone else's. Examples aren't inputs: they live in `input/<year>/examples/`, named like `day22-given.txt`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedInput {
+    /// The file's stem, like `day12-kyle`
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Everything [`registry!`] knows about a year's crate
+pub struct Year {
+    pub year: u32,
+    /// The crate's directory, which has `input/` and `answers.txt`
+    pub dir: &'static str,
+    pub solutions: &'static [Solution],
+    /// Called once before running anything, from `registry!(aocNN, setup = [...])`
+    pub setup: &'static [fn()],
+    /// For `bench --scale`, from `registry!(aocNN, random_inputs = ...)`
+    pub random_inputs: &'static [scaling::RandomInput],
+}
+
+impl Year {
+    /// Run the `setup` hooks
+    pub fn setup(&self) {
+        for hook in self.setup {
+            hook();
+        }
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        PathBuf::from(self.dir)
+            .join("input")
+            .join(self.year.to_string())
+    }
+
+    /// My input for `day`
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        self.input_dir().join(format!("day{day}.txt"))
+    }
+
+    /// Every input for `day`, starting with mine
+    pub fn inputs(&self, day: u32) -> Vec<NamedInput> {
+        let Ok(entries) = fs::read_dir(self.input_dir()) else {
+            return vec![];
+        };
+
+        let mine = format!("day{day}");
+        let mut inputs: Vec<NamedInput> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "txt" {
+                    return None;
+                }
+                // `day_of()` reads every digit, so this doesn't mix up day1 and day12
+                let name = path.file_stem()?.to_str()?.to_string();
+                (answers::day_of(&name) == Some(day)).then_some(NamedInput { name, path })
+            })
+            .collect();
+        inputs.sort_by_key(|input| (input.name != mine, input.name.clone()));
+
+        inputs
+    }
+
+    /// An `--input` argument: a path, or the name of a file in `input/<year>/` like `day12-kyle`
+    pub fn find_input(&self, arg: &str) -> NamedInput {
+        let path = PathBuf::from(arg);
+        if path.exists() {
+            let name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(|| arg.to_string());
+            return NamedInput { name, path };
+        }
+
+        NamedInput {
+            name: arg.to_string(),
+            path: self.input_dir().join(format!("{arg}.txt")),
+        }
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        PathBuf::from(self.dir).join("answers.txt")
+    }
+
+    /// Not checked in, since timings from someone else's machine aren't worth comparing with
+    pub fn bench_history_path(&self) -> PathBuf {
+        PathBuf::from(self.dir).join("bench-history.tsv")
+    }
+
+    /// Every day with at least one solution, in order
+    pub fn days(&self) -> Vec<u32> {
+        let mut days: Vec<u32> = self.solutions.iter().map(|s| s.day).collect();
+        days.dedup();
+
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_inputs() {
+        let dir = std::env::temp_dir().join(format!("aoc-harness-inputs-{}", std::process::id()));
+        let inputs = dir.join("input").join("2022");
+        fs::create_dir_all(&inputs).unwrap();
+        for name in [
+            "day1.txt",
+            "day12.txt",
+            "day12-kyle.txt",
+            "day12-big.txt",
+            "day12.png",
+            "notes.txt",
+        ] {
+            fs::write(inputs.join(name), "").unwrap();
+        }
+        fs::create_dir_all(inputs.join("examples")).unwrap();
+        fs::write(inputs.join("examples").join("day12-given.txt"), "").unwrap();
+
+        let year = Year {
+            year: 2022,
+            dir: dir.to_str().unwrap().to_string().leak(),
+            solutions: &[],
+            setup: &[],
+            random_inputs: &[],
+        };
+        let names = |day| {
+            year.inputs(day)
+                .into_iter()
+                .map(|i| i.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(12), ["day12", "day12-big", "day12-kyle"]);
+        assert_eq!(names(1), ["day1"]);
+        assert!(names(2).is_empty());
+
+        let kyle = year.find_input("day12-kyle");
+        assert_eq!(kyle.path, inputs.join("day12-kyle.txt"));
+        let by_path = year.find_input(inputs.join("day12-big.txt").to_str().unwrap());
+        assert_eq!(by_path.name, "day12-big");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
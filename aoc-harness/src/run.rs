@@ -0,0 +1,137 @@
+//! Run solutions and print their answers, like `cargo aoc` does
+//!
+//! ```text
+//! $ cargo run --release                              # Everything
+//! $ cargo run --release -- --day 7 --part 1          # Just day 7 part 1
+//! $ cargo run --release -- --day 7 --name grid_view  # Just one variant
+//! $ cargo run --release -- --input day12-kyle        # Someone else's input, or any path to one
+//! $ cargo run --release -- --time                    # Also show how long each one took
+//! ```
+//! Inputs are read from `input/<year>/` when we run, so nothing needs downloading or embedding at build time.
+use clap::Parser;
+
+use std::fs;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use crate::answers::day_of;
+use crate::{ArcStr, Year};
+
+#[derive(Debug, Parser)]
+#[command(about = "Run solutions on my input, or someone else's")]
+struct Opts {
+    /// Only run this day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// Only run this part
+    #[arg(short, long)]
+    part: Option<u32>,
+
+    /// Only run the variant with this name, like `grid_view` from `#[aoc(day7, part1, grid_view)]`
+    #[arg(short, long)]
+    name: Option<String>,
+
+    /// Input to use instead of mine: a path, or a name from `input/<year>/` like `day12-kyle`
+    #[arg(short, long)]
+    input: Option<String>,
+
+    /// Show how long parsing and solving took
+    #[arg(short, long)]
+    time: bool,
+}
+
+pub fn main(year: &Year) -> ExitCode {
+    let opts = Opts::parse();
+
+    let input = opts.input.as_deref().map(|arg| year.find_input(arg));
+    // `--input day12-kyle` can only be for day 12
+    let only_day = opts
+        .day
+        .or_else(|| input.as_ref().and_then(|i| day_of(&i.name)));
+    if let (Some(input), None) = (&input, only_day) {
+        eprintln!("Which day is {} for? Pass --day too.", input.path.display());
+        return ExitCode::FAILURE;
+    }
+
+    year.setup();
+    println!("Advent of Code {}", year.year);
+    let (mut ran, mut failed) = (0, 0);
+
+    for day in year.days() {
+        if only_day.is_some_and(|d| d != day) {
+            continue;
+        }
+        let solutions = year.solutions.iter().filter(|s| {
+            s.day == day
+                && opts.part.is_none_or(|p| p == s.part)
+                && opts
+                    .name
+                    .as_ref()
+                    .is_none_or(|n| Some(n.as_str()) == s.name)
+        });
+
+        let (name, path) = match &input {
+            Some(input) => (input.name.clone(), input.path.clone()),
+            None => (format!("day{day}"), year.input_path(day)),
+        };
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => ArcStr::from(&text),
+            Err(e) => {
+                println!("  day{day}: couldn't read {}: {e}", path.display());
+                // Missing some of my inputs is fine, but not the one we were asked for
+                if input.is_some() {
+                    failed += 1;
+                }
+                continue;
+            }
+        };
+
+        for sol in solutions {
+            ran += 1;
+            let label = sol.label_on(&name);
+
+            let start = Instant::now();
+            let runner = match (sol.generator)(text.clone()) {
+                Ok(runner) => runner,
+                Err(e) => {
+                    failed += 1;
+                    eprintln!("  {label}: FAILED while generating: {e}");
+                    continue;
+                }
+            };
+            let parsed = Instant::now();
+            let answer = match runner.try_run() {
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed += 1;
+                    eprintln!("  {label}: FAILED while running: {e}");
+                    continue;
+                }
+            };
+            let solved = Instant::now();
+
+            // Some answers are drawn across several lines
+            let answer = answer.to_string().replace('\n', "\n    ");
+            println!("  {label}: {answer}");
+            if opts.time {
+                println!(
+                    "    parse {:.2?}, solve {:.2?}",
+                    parsed - start,
+                    solved - parsed
+                );
+            }
+        }
+    }
+
+    if ran == 0 {
+        eprintln!("No solutions matched --day, --part, and --name");
+        return ExitCode::FAILURE;
+    }
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,314 @@
+//! Start a new day from the year's `src/day00.rs` template
+//!
+//! ```text
+//! $ cd 2025
+//! $ ../new_day.sh 14       # Or leave off the day to use today's
+//! ```
+//! This creates `src/day14.rs` with its `dayN`s filled in, adds `pub mod day14;` to `src/lib.rs`, and creates an empty
+//! `input/2025/examples/day14-given.txt` for the template's tests to find. It won't touch a day that's already started.
+//! See [`crate::examples`] for how examples and their answers are laid out.
+use clap::Parser;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::SystemTime;
+
+#[derive(Debug, Parser)]
+#[command(about = "Start a new day from src/day00.rs")]
+struct Opts {
+    /// Which day to start. Defaults to today, if it's December.
+    day: Option<u32>,
+
+    /// The year's directory, like `2025/`
+    #[arg(long, default_value = ".")]
+    dir: PathBuf,
+}
+
+pub fn main() -> ExitCode {
+    let opts = Opts::parse();
+
+    match new_day(&opts) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(opts: &Opts) -> Result<(), String> {
+    let dir = fs::canonicalize(&opts.dir)
+        .map_err(|e| format!("Couldn't find {}: {e}", opts.dir.display()))?;
+    let year: u32 = dir
+        .file_name()
+        .and_then(|name| name.to_str()?.parse().ok())
+        .ok_or_else(|| format!("Run this from a year's directory, not {}", dir.display()))?;
+    let last = last_day(year);
+    let day = match opts.day {
+        Some(day) => day,
+        None => puzzle_day_today(last)
+            .ok_or("It's not Advent of Code right now, so say which day to start")?,
+    };
+    if !(1..=last).contains(&day) {
+        return Err(format!("There's no day {day} in {year}"));
+    }
+
+    let module = format!("day{day:02}");
+    let src = dir.join("src");
+    let day_rs = src.join(format!("{module}.rs"));
+    let lib_rs = src.join("lib.rs");
+    let example = dir
+        .join("input")
+        .join(year.to_string())
+        .join("examples")
+        .join(format!("day{day}-given.txt"));
+
+    for path in [&day_rs, &example] {
+        if path.exists() {
+            return Err(format!(
+                "{} already exists, so day {day} is already started",
+                path.display()
+            ));
+        }
+    }
+    let template = read(&src.join("day00.rs"))?;
+    let lib = register(&read(&lib_rs)?, &module)?;
+
+    fs::create_dir_all(example.parent().unwrap()).map_err(|e| e.to_string())?;
+    write(&example, "")?;
+    write(&day_rs, &instantiate(&template, day))?;
+    write(&lib_rs, &lib)?;
+
+    println!("Started day {day}:");
+    println!("    {}", day_rs.display());
+    println!(
+        "    {} (paste the example here, and its answers in answers.txt)",
+        example.display()
+    );
+    let input = dir
+        .join("input")
+        .join(year.to_string())
+        .join(format!("day{day}.txt"));
+    if !input.exists() {
+        println!("Save your input to {}", input.display());
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Couldn't write {}: {e}", path.display()))
+}
+
+/// Fill in `template`'s `dayN`s for `day`
+pub fn instantiate(template: &str, day: u32) -> String {
+    let mut text = String::new();
+    let mut lines = template.lines().peekable();
+    while let Some(line) = lines.next() {
+        // The template's own instructions are for copying it by hand
+        if line.trim() == "// Each day:" {
+            while lines
+                .peek()
+                .is_some_and(|l| l.trim_start().starts_with("//  -"))
+            {
+                lines.next();
+            }
+            if lines.peek().is_some_and(|l| l.trim().is_empty()) {
+                lines.next();
+            }
+            continue;
+        }
+        text += line;
+        text.push('\n');
+    }
+
+    text.replace("dayN", &format!("day{day}"))
+}
+
+/// Add `mod <module>;` to `lib_rs`, either by uncommenting it or next to the other days
+pub fn register(lib_rs: &str, module: &str) -> Result<String, String> {
+    let decl = |line: &str| -> Option<(String, String)> {
+        // "pub mod day01;" -> ("pub mod ", "day01")
+        let line = line.trim();
+        let at = line.find("mod day")?;
+        let name = line[at + 4..].strip_suffix(';')?;
+        Some((line[..at + 4].to_string(), name.to_string()))
+    };
+
+    let mut lines: Vec<String> = lib_rs.lines().map(str::to_string).collect();
+    if lines
+        .iter()
+        .any(|l| !l.trim_start().starts_with("//") && decl(l).is_some_and(|(_, m)| m == module))
+    {
+        return Err(format!("{module} is already in lib.rs"));
+    }
+
+    // Days that were stubbed out, like `// pub mod day24;`
+    for line in &mut lines {
+        if let Some(rest) = line.trim_start().strip_prefix("//") {
+            if let Some((prefix, name)) = decl(rest) {
+                if name == module {
+                    *line = format!("{prefix}{module};");
+                    return Ok(lines.join("\n") + "\n");
+                }
+            }
+        }
+    }
+
+    // Otherwise it goes after the last day before it, stubbed out or not, and looks like the other days
+    let days: Vec<(usize, String, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let (prefix, name) = decl(l.trim_start().trim_start_matches("//"))?;
+            (name != "day00").then_some((i, prefix, name))
+        })
+        .collect();
+    let Some((_, prefix, _)) = days.first() else {
+        return Err("Couldn't find any `mod dayNN;` in lib.rs to go next to".to_string());
+    };
+    let at = match days
+        .iter()
+        .rev()
+        .find(|(_, _, name)| name.as_str() < module)
+    {
+        Some((i, _, _)) => i + 1,
+        None => days[0].0,
+    };
+    lines.insert(at, format!("{prefix}{module};"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The last puzzle of `year`'s event: there were 25 until 2025, which only had 12
+fn last_day(year: u32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Which puzzle unlocked most recently, if it's December and the event that ends on `last_day` isn't over.
+/// They unlock at midnight in UTC-5.
+fn puzzle_day_today(last_day: u32) -> Option<u32> {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()?
+        .as_secs() as i64
+        - 5 * 60 * 60;
+    let (_year, month, day) = civil_from_days(secs.div_euclid(24 * 60 * 60));
+
+    (month == 12 && day <= last_day).then_some(day)
+}
+
+/// Days since 1970-01-01 -> (year, month, day), from http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "\
+use aoc_runner_derive::aoc_lib;
+
+pub mod day01;
+pub mod day02;
+// pub mod day03;
+pub mod day05;
+#[cfg(feature = \"broken\")]
+pub mod day21;
+
+aoc_lib! { year = 2025 }
+";
+
+    #[test]
+    fn check_last_day() {
+        assert_eq!(last_day(2015), 25);
+        assert_eq!(last_day(2024), 25);
+        assert_eq!(last_day(2025), 12);
+    }
+
+    #[test]
+    fn check_register_uncomments() {
+        let lib = register(LIB_RS, "day03").unwrap();
+        assert!(
+            lib.contains("pub mod day02;\npub mod day03;\npub mod day05;"),
+            "{lib}"
+        );
+    }
+
+    #[test]
+    fn check_register_inserts_in_order() {
+        let lib = register(LIB_RS, "day04").unwrap();
+        assert!(
+            lib.contains("// pub mod day03;\npub mod day04;\npub mod day05;"),
+            "{lib}"
+        );
+
+        let lib = register(LIB_RS, "day25").unwrap();
+        assert!(lib.contains("pub mod day21;\npub mod day25;\n"), "{lib}");
+
+        let lib = register("mod day02;\nmod day03;\n", "day01").unwrap();
+        assert_eq!(lib, "mod day01;\nmod day02;\nmod day03;\n");
+    }
+
+    #[test]
+    fn check_register_refuses_existing() {
+        assert!(register(LIB_RS, "day02").is_err());
+        assert!(register(LIB_RS, "day21").is_err());
+        assert!(register("fn main() {}\n", "day01").is_err());
+    }
+
+    #[test]
+    fn check_instantiate() {
+        let template = "\
+// Each day:
+//  - Ctrl + F on dayN below
+//  - Uncomment this module in lib.rs
+
+#[aoc(dayN, part1)]
+pub fn part1(input: &str) -> i64 {
+    0
+}
+
+        #[files(\"input/2025/examples/dayN-*.txt\")] path: PathBuf,
+";
+        let day = instantiate(template, 7);
+        assert_eq!(
+            day,
+            "\
+#[aoc(day7, part1)]
+pub fn part1(input: &str) -> i64 {
+    0
+}
+
+        #[files(\"input/2025/examples/day7-*.txt\")] path: PathBuf,
+"
+        );
+    }
+
+    #[test]
+    fn check_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(20_423), (2025, 12, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+}
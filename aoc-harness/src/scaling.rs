@@ -0,0 +1,114 @@
+//! Random puzzle inputs that get bigger, for seeing how solutions scale past my input
+//!
+//! Each year lists its generators in a `random_input` module, and hands them to `registry!` with
+//! `random_inputs = aoc24::random_input::ALL`. Then `bench --scale` times every solution for a day on bigger and
+//! bigger inputs, doubling the size each time:
+//! ```text
+//! $ cargo run --release --bin bench -- --day 9 --scale
+//! ```
+//! Each row has how much slower it got for how much bigger the input got, as the `k` in `O(n^k)`. A solution that's
+//! fine on my input but goes quadratic after that stands out, since its `k` heads toward 2.
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use crate::differential::{panic_message, SeedableRng, StdRng};
+
+/// A generator for one day's inputs
+pub struct RandomInput {
+    pub day: u32,
+    /// Which shape of input it makes, for when a day has more than one
+    pub name: &'static str,
+    /// Makes an input that grows with `size`. What `size` counts is up to the generator, like rows or bricks.
+    pub generate: fn(&mut StdRng, usize) -> String,
+}
+
+impl RandomInput {
+    /// The same `seed` and `size` always make the same input
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+/// For each year's tests: check that `solve` can handle what every generator in `inputs` makes at each of `sizes`
+///
+/// `solve` gets the day and the input, and runs that day's solutions on it. If one panics, this panics with which
+/// generator made the input, so a generator that makes invalid inputs is easy to tell from a solution that's wrong.
+#[track_caller]
+pub fn check_solvable(inputs: &[RandomInput], sizes: &[usize], solve: impl Fn(u32, &str)) {
+    for random in inputs {
+        for &size in sizes {
+            let input = random.generate(size as u64, size);
+            if let Err(e) = panic::catch_unwind(AssertUnwindSafe(|| solve(random.day, &input))) {
+                panic!(
+                    "day{} {} at size {size} made an input that didn't solve: {}\n{input}",
+                    random.day,
+                    random.name,
+                    panic_message(&*e)
+                );
+            }
+        }
+    }
+}
+
+/// The `k` in `O(n^k)` from two timings, where `n` is the input's length. `None` if either is too small to say.
+pub fn exponent((n1, t1): (usize, Duration), (n2, t2): (usize, Duration)) -> Option<f64> {
+    if n1 == 0 || n2 <= n1 || t1.is_zero() || t2.is_zero() {
+        return None;
+    }
+
+    Some((t2.as_secs_f64() / t1.as_secs_f64()).ln() / (n2 as f64 / n1 as f64).ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Rng;
+
+    #[test]
+    fn check_exponent() {
+        let close = |a: Option<f64>, b: f64| (a.unwrap() - b).abs() < 1e-9;
+        let ms = Duration::from_millis;
+
+        assert!(close(exponent((100, ms(10)), (200, ms(20))), 1.0));
+        assert!(close(exponent((100, ms(10)), (200, ms(40))), 2.0));
+        assert!(close(exponent((100, ms(10)), (1000, ms(10))), 0.0));
+        assert_eq!(exponent((100, ms(10)), (100, ms(20))), None);
+        assert_eq!(exponent((100, Duration::ZERO), (200, ms(20))), None);
+    }
+
+    #[test]
+    fn check_seeded() {
+        let input = RandomInput {
+            day: 1,
+            name: "digits",
+            generate: |rng, size| (0..size).map(|_| rng.gen_range('0'..='9')).collect(),
+        };
+
+        assert_eq!(input.generate(3, 50), input.generate(3, 50));
+        assert_ne!(input.generate(3, 50), input.generate(4, 50));
+        assert_eq!(input.generate(3, 50).len(), 50);
+    }
+
+    #[test]
+    fn check_check_solvable() {
+        let inputs = [RandomInput {
+            day: 1,
+            name: "digits",
+            generate: |rng, size| (0..size).map(|_| rng.gen_range('0'..='9')).collect(),
+        }];
+        let sum = |day: u32, input: &str| {
+            assert_eq!(day, 1);
+            let digits = input.chars().map(|c| c.to_digit(10).unwrap());
+            assert!(digits.sum::<u32>() < 30, "too big");
+        };
+
+        check_solvable(&inputs, &[1, 2, 3], sum);
+        let failed = panic::catch_unwind(|| check_solvable(&inputs, &[1, 10], sum)).unwrap_err();
+        let msg = panic_message(&*failed);
+        assert!(
+            msg.starts_with("day1 digits at size 10 made an input that didn't solve: too big\n"),
+            "{msg}"
+        );
+    }
+}
@@ -0,0 +1,450 @@
+//! How many stars every year has, from its solutions and `answers.txt`
+//!
+//! ```text
+//! $ cargo run --manifest-path aoc-harness/Cargo.toml --bin stars
+//! $ cargo run --manifest-path aoc-harness/Cargo.toml --bin stars -- --markdown README.md
+//! ```
+//! This reads the source instead of building every year: a part has a solution if there's an `#[aoc(dayN, partM)]` in a
+//! module that `lib.rs` includes, and it's a stub if that solution is still the template's `0` or ends in a `todo!()`.
+//! Parts that have an answer in `answers.txt` have been checked by `check`, which is what earns the star.
+use clap::Parser;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::answers::Answers;
+
+/// Markdown goes between these, if the file has them
+const BEGIN: &str = "<!-- stars -->";
+const END: &str = "<!-- /stars -->";
+
+#[derive(Debug, Parser)]
+#[command(about = "Show which parts of every year are solved")]
+struct Opts {
+    /// The repo, with a directory for each year
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+
+    /// Also write a markdown table here, between its `<!-- stars -->` and `<!-- /stars -->`
+    #[arg(long)]
+    markdown: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    #[default]
+    Missing,
+    /// Only has solutions that haven't been written yet, like the template's `0`
+    Stubbed,
+    /// Solved, but with no answer in `answers.txt` to check it against
+    Unverified,
+    /// Solved, and checked against `answers.txt`
+    Verified,
+}
+
+impl Status {
+    pub const ALL: [Status; 4] = [
+        Status::Verified,
+        Status::Unverified,
+        Status::Stubbed,
+        Status::Missing,
+    ];
+
+    pub fn symbol(self) -> char {
+        match self {
+            Status::Missing => '·',
+            Status::Stubbed => '○',
+            Status::Unverified => '☆',
+            Status::Verified => '★',
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Missing => "missing",
+            Status::Stubbed => "stubbed",
+            Status::Unverified => "unverified",
+            Status::Verified => "verified",
+        }
+    }
+}
+
+/// One `#[aoc(...)]` found in the source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found {
+    pub day: u32,
+    pub part: u32,
+    pub stub: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct YearStars {
+    pub year: u32,
+    /// `parts[day - 1][part - 1]`
+    pub parts: [[Status; 2]; 25],
+}
+
+impl YearStars {
+    pub fn count(&self, status: Status) -> usize {
+        self.parts
+            .iter()
+            .flatten()
+            .filter(|&&s| s == status)
+            .count()
+    }
+}
+
+pub fn main() -> ExitCode {
+    let opts = Opts::parse();
+
+    let years = match scan_all(&opts.root) {
+        Ok(years) => years,
+        Err(e) => {
+            eprintln!("Failed to scan {}: {e}", opts.root.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    print!("{}", render_terminal(&years));
+
+    if let Some(path) = &opts.markdown {
+        if let Err(e) = write_markdown(path, &render_markdown(&years)) {
+            eprintln!("Failed to write {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Wrote {}", path.display());
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Every year under `root`, oldest first
+pub fn scan_all(root: &Path) -> io::Result<Vec<YearStars>> {
+    let mut years = vec![];
+    for entry in fs::read_dir(root)? {
+        let dir = entry?.path();
+        let Some(year) = dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if dir.join("src").join("lib.rs").exists() {
+            years.push(scan_year(&dir, year)?);
+        }
+    }
+    years.sort_by_key(|y| y.year);
+
+    Ok(years)
+}
+
+pub fn scan_year(dir: &Path, year: u32) -> io::Result<YearStars> {
+    let src = dir.join("src");
+    let answers = Answers::load(dir.join("answers.txt"))?;
+
+    let mut found = vec![];
+    for module in day_modules(&fs::read_to_string(src.join("lib.rs"))?) {
+        let file = src.join(format!("{module}.rs"));
+        let file = if file.exists() {
+            file
+        } else {
+            src.join(&module).join("mod.rs")
+        };
+        found.extend(scan_solutions(&fs::read_to_string(file)?));
+    }
+
+    let mut parts = [[Status::Missing; 2]; 25];
+    for (day, statuses) in (1..).zip(&mut parts) {
+        for (part, status) in (1..).zip(statuses) {
+            let solutions: Vec<&Found> = found
+                .iter()
+                .filter(|f| f.day == day && f.part == part)
+                .collect();
+            *status = if solutions.is_empty() {
+                Status::Missing
+            } else if solutions.iter().all(|f| f.stub) {
+                Status::Stubbed
+            } else if answers.get(&format!("day{day}"), part).is_some() {
+                Status::Verified
+            } else {
+                Status::Unverified
+            };
+        }
+    }
+
+    Ok(YearStars { year, parts })
+}
+
+/// The `dayNN` modules that `lib.rs` builds by default: not commented out, and not behind a `#[cfg(...)]`
+pub fn day_modules(lib_rs: &str) -> Vec<String> {
+    let mut modules = vec![];
+    let mut cfg = false;
+    for line in lib_rs.lines().map(str::trim) {
+        if line.starts_with("#[cfg") {
+            cfg = true;
+            continue;
+        }
+        let name = line
+            .strip_prefix("pub ")
+            .unwrap_or(line)
+            .strip_prefix("mod ")
+            .and_then(|rest| rest.strip_suffix(';'));
+        if let Some(name) = name {
+            if name.starts_with("day") && name != "day00" && !cfg {
+                modules.push(name.to_string());
+            }
+        }
+        cfg = false;
+    }
+
+    modules
+}
+
+/// Every `#[aoc(dayN, partM...)]` in a module, and whether the function after it is a stub
+pub fn scan_solutions(text: &str) -> Vec<Found> {
+    let mut found = vec![];
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let Some(args) = line
+            .trim()
+            .strip_prefix("#[aoc(")
+            .and_then(|rest| rest.strip_suffix(")]"))
+        else {
+            continue;
+        };
+        let mut args = args.split(',').map(str::trim);
+        let day = args
+            .next()
+            .and_then(|d| d.strip_prefix("day")?.parse().ok());
+        let part = args
+            .next()
+            .and_then(|p| p.strip_prefix("part")?.parse().ok());
+        let (Some(day), Some(part)) = (day, part) else {
+            continue;
+        };
+
+        let stub = fn_body(&text[start..]).is_none_or(is_stub);
+        found.push(Found { day, part, stub });
+    }
+
+    found
+}
+
+/// What's between the braces of the first `fn` in `text`
+fn fn_body(text: &str) -> Option<&str> {
+    let sig = text.find("fn ")?;
+    let open = sig + text[sig..].find('{')?;
+
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[open + 1..i]);
+                }
+            }
+            // Skip over strings and comments, which can have unmatched braces
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            b'\'' if bytes.get(i + 2) == Some(&b'\'') => i += 2,
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// The template's `0`, or anything that ends by giving up
+fn is_stub(body: &str) -> bool {
+    let code: Vec<&str> = body
+        .lines()
+        .map(|l| l.split("//").next().unwrap_or_default().trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    let Some(last) = code.last() else {
+        return true;
+    };
+
+    (code.len() == 1 && *last == "0")
+        || last.starts_with("todo!(")
+        || last.starts_with("unimplemented!(")
+}
+
+fn legend() -> String {
+    Status::ALL
+        .map(|s| format!("{} {}", s.symbol(), s.name()))
+        .join("  ")
+}
+
+fn totals(year: &YearStars) -> String {
+    Status::ALL
+        .map(|s| format!("{}{}", year.count(s), s.symbol()))
+        .join(" ")
+}
+
+/// A row per year, with part 1 and part 2 side by side for each day
+pub fn render_terminal(years: &[YearStars]) -> String {
+    let mut out = format!("{}\n\n     ", legend());
+    for day in 1..=25 {
+        out += &format!(" {day:>2}");
+    }
+    out.push('\n');
+
+    for year in years {
+        out += &format!("{}:", year.year);
+        for [p1, p2] in year.parts {
+            out += &format!(" {}{}", p1.symbol(), p2.symbol());
+        }
+        out += &format!("  {}\n", totals(year));
+    }
+
+    out
+}
+
+pub fn render_markdown(years: &[YearStars]) -> String {
+    let mut out = format!("{}\n\n| Year |", legend());
+    for day in 1..=25 {
+        out += &format!(" {day} |");
+    }
+    out += " Total |\n|---|";
+    out += &"---|".repeat(26);
+    out.push('\n');
+
+    for year in years {
+        out += &format!("| [{0}](https://adventofcode.com/{0}) |", year.year);
+        for [p1, p2] in year.parts {
+            out += &format!(" {}{} |", p1.symbol(), p2.symbol());
+        }
+        out += &format!(" {} |\n", totals(year));
+    }
+
+    out
+}
+
+/// Replace what's between the markers in `path`, leaving the rest of it alone
+fn write_markdown(path: &Path, markdown: &str) -> io::Result<()> {
+    let existing = fs::read_to_string(path)?;
+    let text = match (existing.find(BEGIN), existing.find(END)) {
+        (Some(begin), Some(end)) if begin < end => format!(
+            "{}{BEGIN}\n{markdown}{}",
+            &existing[..begin],
+            &existing[end..]
+        ),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{} has no {BEGIN} ... {END} to put the table between",
+                    path.display()
+                ),
+            ))
+        }
+    };
+
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day_modules() {
+        let lib_rs = "\
+pub mod day01;
+mod day02;
+// pub mod day03;
+#[cfg(feature = \"broken\")]
+pub mod day04;
+pub mod day08_buf;
+pub mod framebuffer;
+";
+        assert_eq!(day_modules(lib_rs), ["day01", "day02", "day08_buf"]);
+    }
+
+    #[test]
+    fn check_scan_solutions() {
+        let text = r#"
+#[aoc(day3, part1)]
+pub fn part1(input: &str) -> i64 {
+    let s = "{ unmatched";
+    input.len() as i64
+}
+
+#[aoc(day3, part1, fast)]
+pub fn part1_fast(input: &str) -> i64 {
+    0
+}
+
+// #[aoc(day3, part2)]
+// pub fn part2(input: &str) -> i64 {
+
+#[aoc(day3, part2, wip)]
+#[inline(never)]
+pub fn part2(input: &str) -> u64 {
+    let things = parse(input);
+    dbg!(things.len());
+
+    todo!()
+}
+"#;
+        assert_eq!(
+            scan_solutions(text),
+            [
+                Found {
+                    day: 3,
+                    part: 1,
+                    stub: false
+                },
+                Found {
+                    day: 3,
+                    part: 1,
+                    stub: true
+                },
+                Found {
+                    day: 3,
+                    part: 2,
+                    stub: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_write_markdown() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-harness-stars-{}.md", std::process::id()));
+
+        fs::write(&path, format!("# Title\n{BEGIN}\nold\n{END}\nmore\n")).unwrap();
+        write_markdown(&path, "new\n").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("# Title\n{BEGIN}\nnew\n{END}\nmore\n")
+        );
+
+        // Without the markers, there's nowhere to put it, so the file is left alone
+        fs::write(&path, "# Title\n").unwrap();
+        assert!(write_markdown(&path, "new\n").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "# Title\n");
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,291 @@
+//! The driver behind each year's `viz` binary, which has one subcommand per day
+//!
+//! A year's `src/viz/main.rs` lists a [`Viz`] for each day it can draw, and hands them to [`main()`]. Every day
+//! becomes a subcommand and gets the common [`Opts`] for free:
+//! ```text
+//! $ cargo run --release --bin viz -- day20 --example --solve --format svg
+//! ```
+use clap::{ArgMatches, Args, Command, FromArgMatches, ValueEnum};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{imageops, Delay, Frame, RgbImage};
+
+use crate::Year;
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Flags shared by every visualization
+#[derive(Clone, Debug, Args)]
+pub struct Opts {
+    /// Input to visualize: a path, or a name from `input/<year>/` like `day12-kyle`. Defaults to `day<N>`.
+    #[arg(short, long, global = true)]
+    pub input: Option<String>,
+
+    /// Use the day's built-in example input instead of `--input`
+    #[arg(long, global = true)]
+    pub example: bool,
+
+    /// Where to save the output. Defaults to `target/day<N>.<format>`
+    #[arg(short, long, global = true)]
+    pub output: Option<String>,
+
+    /// Pixel scale factor. Defaults to something that fits in ~1024px
+    #[arg(short, long, global = true)]
+    pub scale: Option<u32>,
+
+    /// Output format. Animations become numbered files, unless they're a gif
+    #[arg(short, long, global = true, value_enum)]
+    pub format: Option<Format>,
+
+    /// Stop after rendering this many frames
+    #[arg(long, global = true)]
+    pub frames: Option<usize>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Png,
+    Gif,
+    Svg,
+}
+
+impl Format {
+    fn ext(self) -> &'static str {
+        match self {
+            Format::Png => "png",
+            Format::Gif => "gif",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+/// A visualization for a single day
+///
+/// Renderers produce unscaled images - one pixel per cell - and push them into [`Frames`].
+/// Scaling and encoding are handled for everyone in [`main()`].
+pub trait Viz {
+    fn day(&self) -> u32;
+
+    fn about(&self) -> &'static str;
+
+    /// Input to use with `--example`
+    fn example(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn default_format(&self) -> Format {
+        Format::Png
+    }
+
+    /// Add any day-specific flags. Usually `SomeArgs::augment_args(cmd)`.
+    fn args(&self, cmd: Command) -> Command {
+        cmd
+    }
+
+    fn render(&self, input: &str, ctx: &Ctx, frames: &mut Frames);
+}
+
+/// Everything a [`Viz`] might want to know about how it was invoked
+pub struct Ctx<'a> {
+    pub opts: &'a Opts,
+    pub matches: &'a ArgMatches,
+    /// Where the frames will be saved, for renderers that write extra files next to them
+    pub output: &'a str,
+}
+
+impl Ctx<'_> {
+    /// Parse the day-specific flags registered in [`Viz::args()`]
+    pub fn args<A: FromArgMatches>(&self) -> A {
+        A::from_arg_matches(self.matches).unwrap_or_else(|e| e.exit())
+    }
+}
+
+/// Collects the frames a [`Viz`] renders, and knows when to stop
+#[derive(Default)]
+pub struct Frames {
+    images: Vec<RgbImage>,
+    limit: Option<usize>,
+}
+
+impl Frames {
+    /// Returns `false` once `--frames` worth of images have been collected, so animations can stop simulating early.
+    pub fn push(&mut self, img: RgbImage) -> bool {
+        if !self.wants_more() {
+            return false;
+        }
+        self.images.push(img);
+
+        self.wants_more()
+    }
+
+    pub fn wants_more(&self) -> bool {
+        self.limit.is_none_or(|limit| self.images.len() < limit)
+    }
+}
+
+/// Run whichever of `vizzes` the command line asks for, on `year`'s input
+pub fn main(year: &Year, vizzes: &[&dyn Viz]) -> Result<(), Box<dyn Error>> {
+    let mut cmd = Opts::augment_args(Command::new("viz"))
+        .about(format!("Advent of Code {} visualizations", year.year))
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    for viz in vizzes {
+        // clap wants a 'static name here, and we only build this once
+        let name: &'static str = format!("day{}", viz.day()).leak();
+        let sub = Command::new(name).about(viz.about());
+        cmd = cmd.subcommand(viz.args(sub));
+    }
+
+    let matches = cmd.get_matches();
+    let (name, matches) = matches.subcommand().unwrap();
+    let viz = vizzes
+        .iter()
+        .find(|viz| format!("day{}", viz.day()) == name)
+        .unwrap();
+    let opts = Opts::from_arg_matches(matches).unwrap_or_else(|e| e.exit());
+    let day = viz.day();
+
+    let input: String = if opts.example {
+        let Some(example) = viz.example() else {
+            return Err(format!("day{day} doesn't have a built-in example").into());
+        };
+        println!("Using built-in example");
+        // Only trim newlines: leading spaces are meaningful in some grids
+        example.trim_start_matches('\n').to_string()
+    } else {
+        let path = match &opts.input {
+            Some(arg) => year.find_input(arg).path,
+            None => year.input_path(day),
+        };
+        println!("Reading input from {}", path.display());
+        std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?
+    };
+
+    let format = opts.format.unwrap_or(viz.default_format());
+    let output = opts.output.clone().unwrap_or_else(|| {
+        let suffix = if opts.example { "-example" } else { "" };
+        format!("target/day{day}{suffix}.{}", format.ext())
+    });
+
+    if let Some(dir) = Path::new(&output).parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let ctx = Ctx {
+        opts: &opts,
+        matches,
+        output: &output,
+    };
+    let mut frames = Frames {
+        images: vec![],
+        limit: opts.frames,
+    };
+    viz.render(&input, &ctx, &mut frames);
+
+    let Some(first) = frames.images.first() else {
+        return Err(format!("day{day} didn't render anything").into());
+    };
+    let scale = opts.scale.unwrap_or_else(|| {
+        let actual = first.width().max(first.height());
+        (1024 / actual).max(1)
+    });
+
+    println!(
+        "Saving {} frame(s) at {scale}x to {output}",
+        frames.images.len()
+    );
+    save(&frames.images, scale, format, &output)
+}
+
+fn save(
+    images: &[RgbImage],
+    scale: u32,
+    format: Format,
+    output: &str,
+) -> Result<(), Box<dyn Error>> {
+    // Single images go exactly where we're told, but animations get numbered
+    let frame_path = |i: usize| -> String {
+        if images.len() == 1 {
+            output.to_string()
+        } else {
+            let (stem, ext) = output.rsplit_once('.').unwrap_or((output, format.ext()));
+            format!("{stem}-{i:04}.{ext}")
+        }
+    };
+
+    match format {
+        Format::Png => {
+            for (i, img) in images.iter().enumerate() {
+                upscale(img, scale).save(frame_path(i))?;
+            }
+        }
+        Format::Svg => {
+            for (i, img) in images.iter().enumerate() {
+                write_svg(img, scale, &frame_path(i))?;
+            }
+        }
+        Format::Gif => {
+            let mut gif = GifEncoder::new(File::create(output)?);
+            gif.set_repeat(Repeat::Infinite)?;
+
+            for (i, img) in images.iter().enumerate() {
+                // Hold on the last frame so it's clear where the animation ends
+                let ms = if i + 1 < images.len() { 120 } else { 10_000 };
+                let rgba = image::DynamicImage::ImageRgb8(upscale(img, scale)).into_rgba8();
+                gif.encode_frame(Frame::from_parts(
+                    rgba,
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(ms, 1),
+                ))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn upscale(img: &RgbImage, scale: u32) -> RgbImage {
+    imageops::resize(
+        img,
+        img.width() * scale,
+        img.height() * scale,
+        imageops::FilterType::Nearest,
+    )
+}
+
+/// Writes one `<rect>` per horizontal run of same-colored pixels, which keeps grid-y images small
+fn write_svg(img: &RgbImage, scale: u32, path: &str) -> std::io::Result<()> {
+    let mut f = BufWriter::new(File::create(path)?);
+    let (w, h) = img.dimensions();
+
+    writeln!(
+        f,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+        w * scale,
+        h * scale,
+    )?;
+    for y in 0..h {
+        let mut x = 0;
+        while x < w {
+            let color = img.get_pixel(x, y);
+            let mut run = 1;
+            while x + run < w && img.get_pixel(x + run, y) == color {
+                run += 1;
+            }
+
+            let [r, g, b] = color.0;
+            writeln!(
+                f,
+                r##"<rect x="{x}" y="{y}" width="{run}" height="1" fill="#{r:02x}{g:02x}{b:02x}"/>"##
+            )?;
+            x += run;
+        }
+    }
+    writeln!(f, "</svg>")?;
+
+    f.flush()
+}
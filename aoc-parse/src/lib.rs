@@ -0,0 +1,279 @@
+//! `#[derive(AocParse)]` generates a `FromStr` from a pattern that looks like the puzzle text:
+//! ```
+//! use aoc_parse::AocParse;
+//!
+//! #[derive(AocParse, Debug, PartialEq)]
+//! #[aoc_parse("{name}: {a}-{b} or {c}-{d}")]
+//! struct Rule {
+//!     name: String,
+//!     a: u32,
+//!     b: u32,
+//!     c: u32,
+//!     d: u32,
+//! }
+//!
+//! let rule: Rule = "row: 6-11 or 33-44".parse().unwrap();
+//! assert_eq!(rule.name, "row");
+//! assert_eq!((rule.a, rule.b, rule.c, rule.d), (6, 11, 33, 44));
+//!
+//! let err = "row: 6-1x or 33-44".parse::<Rule>().unwrap_err();
+//! assert_eq!(err, r#"Rule.b: couldn't parse "1x" as u32 at col 8: invalid digit found in string"#);
+//! ```
+//!
+//! Each `{field}` is parsed with its type's `FromStr` (whose `Err` must be `Display`), and takes everything up to the next bit of literal text.
+//! Use `{{` and `}}` for literal braces. Fields that aren't in the pattern are left as `Default::default()`.
+//!
+//! Errors are `String`s that name the field and the 1-based column where things went wrong.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+#[proc_macro_derive(AocParse, attributes(aoc_parse))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Debug, PartialEq)]
+enum Piece {
+    Lit(String),
+    Field(String),
+}
+
+/// Split a pattern like `"{a}-{b} {{x}}"` into literals and field names
+fn split_pattern(pattern: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = vec![];
+    let mut lit = String::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                lit.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                lit.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("Unclosed '{{{name}' in pattern")),
+                    }
+                }
+
+                let name = name.trim().to_string();
+                if name.is_empty() {
+                    return Err("Empty '{}' in pattern".to_string());
+                }
+                if matches!(pieces.last(), Some(Piece::Field(_))) && lit.is_empty() {
+                    return Err(format!(
+                        "'{{{name}}}' follows another field directly, so there's no way to tell where one ends"
+                    ));
+                }
+
+                if !lit.is_empty() {
+                    pieces.push(Piece::Lit(std::mem::take(&mut lit)));
+                }
+                pieces.push(Piece::Field(name));
+            }
+            '}' => return Err("Unmatched '}' in pattern. Use '}}' for a literal '}'".to_string()),
+            c => lit.push(c),
+        }
+    }
+    if !lit.is_empty() {
+        pieces.push(Piece::Lit(lit));
+    }
+
+    Ok(pieces)
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let ty = &input.ident;
+    let ty_name = ty.to_string();
+
+    let attr = input
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("aoc_parse"))
+        .ok_or_else(|| Error::new_spanned(ty, "Missing #[aoc_parse(\"...\")] pattern"))?;
+    let pattern: LitStr = attr.parse_args()?;
+    let pieces = split_pattern(&pattern.value()).map_err(|e| Error::new_spanned(&pattern, e))?;
+
+    let fields = match &input.data {
+        Data::Struct(s) => match &s.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    ty,
+                    "AocParse needs a struct with named fields",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(ty, "AocParse only works on structs")),
+    };
+
+    // Make sure every field in the pattern exists, and exists once
+    let mut seen: Vec<&str> = vec![];
+    for piece in &pieces {
+        if let Piece::Field(name) = piece {
+            if !fields.iter().any(|f| f.ident.as_ref().unwrap() == name) {
+                return Err(Error::new_spanned(
+                    &pattern,
+                    format!("{ty_name} has no field '{name}'"),
+                ));
+            }
+            if seen.contains(&name.as_str()) {
+                return Err(Error::new_spanned(
+                    &pattern,
+                    format!("'{{{name}}}' is used twice"),
+                ));
+            }
+            seen.push(name);
+        }
+    }
+
+    let mut steps = vec![];
+    for (i, piece) in pieces.iter().enumerate() {
+        match piece {
+            Piece::Lit(lit) => steps.push(quote! {
+                rest = match rest.strip_prefix(#lit) {
+                    Some(r) => r,
+                    None => {
+                        return Err(format!(
+                            "{}: expected {:?} at col {}, found {:?}",
+                            #ty_name,
+                            #lit,
+                            col(rest),
+                            rest,
+                        ))
+                    }
+                };
+            }),
+            Piece::Field(name) => {
+                let field = fields
+                    .iter()
+                    .find(|f| f.ident.as_ref().unwrap() == name)
+                    .unwrap();
+                let ident = field.ident.as_ref().unwrap();
+                let field_ty = &field.ty;
+                let what = format!("{ty_name}.{name}");
+                let field_ty_name = field_ty.to_token_stream().to_string().replace(' ', "");
+
+                // A field runs until the next literal, or to the end of the line
+                let split = match pieces.get(i + 1) {
+                    Some(Piece::Lit(next)) => quote! {
+                        let end = match rest.find(#next) {
+                            Some(end) => end,
+                            None => {
+                                return Err(format!(
+                                    "{}: expected {:?} after col {}, found {:?}",
+                                    #what,
+                                    #next,
+                                    col(rest),
+                                    rest,
+                                ))
+                            }
+                        };
+                    },
+                    _ => quote! { let end = rest.len(); },
+                };
+
+                steps.push(quote! {
+                    #split
+                    let text = &rest[..end];
+                    let #ident: #field_ty = match text.parse() {
+                        Ok(v) => v,
+                        Err(e) => {
+                            return Err(format!(
+                                "{}: couldn't parse {:?} as {} at col {}: {}",
+                                #what,
+                                text,
+                                #field_ty_name,
+                                col(rest),
+                                e,
+                            ))
+                        }
+                    };
+                    rest = &rest[end..];
+                });
+            }
+        }
+    }
+
+    let inits = fields.iter().map(|f| {
+        let ident: &Ident = f.ident.as_ref().unwrap();
+        if seen.iter().any(|name| ident == name) {
+            quote! { #ident }
+        } else {
+            quote! { #ident: ::core::default::Default::default() }
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #ty #ty_generics #where_clause {
+            type Err = ::std::string::String;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                // 1-based column of the start of `rest`
+                let col = |rest: &str| s.len() - rest.len() + 1;
+                #[allow(unused_mut)]
+                let mut rest: &str = s;
+
+                #(#steps)*
+
+                if !rest.is_empty() {
+                    return Err(format!(
+                        "{}: unexpected {:?} at col {}",
+                        #ty_name,
+                        rest,
+                        col(rest),
+                    ));
+                }
+
+                Ok(Self { #(#inits),* })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_split_pattern() {
+        use Piece::*;
+
+        assert_eq!(
+            split_pattern("{name}: {a}-{b}").unwrap(),
+            [
+                Field("name".into()),
+                Lit(": ".into()),
+                Field("a".into()),
+                Lit("-".into()),
+                Field("b".into()),
+            ]
+        );
+        assert_eq!(
+            split_pattern("{{x={x}}}").unwrap(),
+            [Lit("{x=".into()), Field("x".into()), Lit("}".into())]
+        );
+    }
+
+    #[test]
+    fn check_split_pattern_errors() {
+        assert!(split_pattern("{a}{b}").is_err());
+        assert!(split_pattern("{a").is_err());
+        assert!(split_pattern("a}").is_err());
+        assert!(split_pattern("{}").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_parse::AocParse;
+
+#[derive(AocParse, Debug, Default, PartialEq)]
+#[aoc_parse("{opcode} {a} {b} {c}")]
+struct Instr {
+    opcode: u32,
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc_parse("{{x={x},m={m}}}")]
+struct Part {
+    x: i64,
+    m: i64,
+    // Not in the pattern
+    label: Option<String>,
+}
+
+#[derive(AocParse, Debug, PartialEq)]
+#[aoc_parse("Valve {name} has flow rate={rate}; tunnels lead to valves {tunnels}")]
+struct Valve<T>
+where
+    // Parse errors are reported with Display
+    T: std::str::FromStr<Err: std::fmt::Display>,
+{
+    name: String,
+    rate: T,
+    tunnels: String,
+}
+
+#[test]
+fn check_instr() {
+    let instr: Instr = "9 2 1 0".parse().unwrap();
+    assert_eq!(
+        instr,
+        Instr {
+            opcode: 9,
+            a: 2,
+            b: 1,
+            c: 0
+        }
+    );
+}
+
+#[test]
+fn check_braces_and_defaults() {
+    let part: Part = "{x=787,m=-2655}".parse().unwrap();
+    assert_eq!(
+        part,
+        Part {
+            x: 787,
+            m: -2655,
+            label: None
+        }
+    );
+}
+
+#[test]
+fn check_generics() {
+    let valve: Valve<u8> = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB"
+        .parse()
+        .unwrap();
+    assert_eq!(valve.name, "AA");
+    assert_eq!(valve.rate, 0);
+    assert_eq!(valve.tunnels, "DD, II, BB");
+}
+
+#[test]
+fn check_errors() {
+    assert_eq!(
+        "9 2 1".parse::<Instr>().unwrap_err(),
+        r#"Instr.b: expected " " after col 5, found "1""#
+    );
+    assert_eq!(
+        "9 2 1 zero".parse::<Instr>().unwrap_err(),
+        r#"Instr.c: couldn't parse "zero" as u32 at col 7: invalid digit found in string"#
+    );
+    assert_eq!(
+        "[x=1,m=2}".parse::<Part>().unwrap_err(),
+        r#"Part: expected "{x=" at col 1, found "[x=1,m=2}""#
+    );
+    assert_eq!(
+        "{x=1,m=2}}".parse::<Part>().unwrap_err(),
+        r#"Part: unexpected "}" at col 10"#
+    );
+}
@@ -16,20 +16,52 @@ do
     popd     > /dev/null
 done
 
-# Run everything
-echo "Running!"
+# Check everything against each year's answers.txt
+echo "Checking!"
+failed=()
 for y in $(find * -maxdepth 1 -type d -name "20*")
 do
     pushd $y > /dev/null
 
     echo "    Advent of Code $y https://adventofcode.com/$y/"
-    RUST_BACKTRACE=1 timeout 3s cargo run --release > target/output_$y.txt
+    cargo run --release --bin check > target/output_$y.txt
     ret=$?
-    if [[ "$ret" -eq "124" ]]; then
-        echo "[!!!] AOC $y timed out"
+    if [[ "$ret" -ne "0" ]]; then
+        echo "[!!!] AOC $y has wrong, disagreeing, erroring, or panicking solutions"
+        failed+=($y)
     fi
     echo "    Run logs in $y/target/output_$y.txt"
     echo ""
 
     popd     > /dev/null
 done
+
+# Each check ends with a line like "120 ok, 1 wrong, ..., 2 timed out, ...", so add those up across years
+echo "Summary!"
+summaries=()
+for y in $(find * -maxdepth 1 -type d -name "20*")
+do
+    summary=$(tail -n 1 $y/target/output_$y.txt)
+    echo "    $y: $summary"
+    summaries+=("$summary")
+done
+printf "%s\n" "${summaries[@]}" | awk -F', ' '
+    {
+        for (i = 1; i <= NF; i++) {
+            n = $i + 0
+            total[i] += n
+            what[i] = substr($i, index($i, " ") + 1)
+        }
+        fields = NF
+    }
+    END {
+        line = ""
+        for (i = 1; i <= fields; i++) line = line (i > 1 ? ", " : "") total[i] " " what[i]
+        print "    All: " line
+    }'
+echo ""
+
+if [[ ${#failed[@]} -ne 0 ]]; then
+    echo "Failed: ${failed[*]}"
+    exit 1
+fi
@@ -11,8 +11,8 @@ if [ "$#" -eq 2 ] && [ "$2" == "looping" ]; then
     cargo clippy --tests
     cargo fmt
     cargo test --lib day$day
-    cargo aoc --day $day
-    # cargo aoc bench --day $day
+    cargo run --release -- --day $day --time
+    cargo run --release --bin bench -- --day $day
 
     # cargo doc --document-private-items
 